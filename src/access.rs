@@ -45,7 +45,7 @@ where <Self as Radium>::Item: BitRegister
 	/// [`BitMask`]: crate::index::BitMask
 	#[inline]
 	fn clear_bits(&self, mask: BitMask<Self::Item>) -> Self::Item {
-		self.fetch_and(!mask.into_inner(), Ordering::Relaxed)
+		self.clear_bits_ordered(mask, Ordering::Relaxed)
 	}
 
 	/// Sets bits within a memory element to `1`.
@@ -70,7 +70,7 @@ where <Self as Radium>::Item: BitRegister
 	/// `1`; all others retain their original value.
 	#[inline]
 	fn set_bits(&self, mask: BitMask<Self::Item>) -> Self::Item {
-		self.fetch_or(mask.into_inner(), Ordering::Relaxed)
+		self.set_bits_ordered(mask, Ordering::Relaxed)
 	}
 
 	/// Inverts bits within a memory element.
@@ -95,7 +95,7 @@ where <Self as Radium>::Item: BitRegister
 	/// inverted; all others retain their original value.
 	#[inline]
 	fn invert_bits(&self, mask: BitMask<Self::Item>) -> Self::Item {
-		self.fetch_xor(mask.into_inner(), Ordering::Relaxed)
+		self.invert_bits_ordered(mask, Ordering::Relaxed)
 	}
 
 	/// Writes a value to one bit in a memory element, returning the previous
@@ -125,13 +125,81 @@ where <Self as Radium>::Item: BitRegister
 	#[inline]
 	fn write_bit<O>(&self, index: BitIdx<Self::Item>, value: bool) -> bool
 	where O: BitOrder {
+		self.write_bit_ordered::<O>(index, value, Ordering::Relaxed)
+	}
+
+	/// Clears bits within a memory element to `0`, using a caller-specified
+	/// memory ordering.
+	///
+	/// This is [`.clear_bits()`] with an explicit `order`. Implementors that
+	/// are not atomic ignore the ordering.
+	///
+	/// [`.clear_bits()`]: Self::clear_bits
+	#[inline]
+	fn clear_bits_ordered(
+		&self,
+		mask: BitMask<Self::Item>,
+		order: Ordering,
+	) -> Self::Item {
+		self.fetch_and(!mask.into_inner(), order)
+	}
+
+	/// Sets bits within a memory element to `1`, using a caller-specified
+	/// memory ordering.
+	///
+	/// This is [`.set_bits()`] with an explicit `order`. Implementors that are
+	/// not atomic ignore the ordering.
+	///
+	/// [`.set_bits()`]: Self::set_bits
+	#[inline]
+	fn set_bits_ordered(
+		&self,
+		mask: BitMask<Self::Item>,
+		order: Ordering,
+	) -> Self::Item {
+		self.fetch_or(mask.into_inner(), order)
+	}
+
+	/// Inverts bits within a memory element, using a caller-specified memory
+	/// ordering.
+	///
+	/// This is [`.invert_bits()`] with an explicit `order`. Implementors that
+	/// are not atomic ignore the ordering.
+	///
+	/// [`.invert_bits()`]: Self::invert_bits
+	#[inline]
+	fn invert_bits_ordered(
+		&self,
+		mask: BitMask<Self::Item>,
+		order: Ordering,
+	) -> Self::Item {
+		self.fetch_xor(mask.into_inner(), order)
+	}
+
+	/// Writes a value to one bit in a memory element, using a caller-specified
+	/// memory ordering, and returns the previous value.
+	///
+	/// This is [`.write_bit()`] with an explicit `order`. Implementors that
+	/// are not atomic ignore the ordering.
+	///
+	/// [`.write_bit()`]: Self::write_bit
+	#[inline]
+	fn write_bit_ordered<O>(
+		&self,
+		index: BitIdx<Self::Item>,
+		value: bool,
+		order: Ordering,
+	) -> bool
+	where
+		O: BitOrder,
+	{
 		let select = index.select::<O>().into_inner();
 		select
 			& if value {
-				self.fetch_or(select, Ordering::Relaxed)
+				self.fetch_or(select, order)
 			}
 			else {
-				self.fetch_and(!select, Ordering::Relaxed)
+				self.fetch_and(!select, order)
 			} != <Self::Item>::ZERO
 	}
 
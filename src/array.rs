@@ -13,12 +13,23 @@ use crate::{
 };
 
 mod api;
+mod arrayvec;
+mod exact;
 mod iter;
 mod ops;
 mod tests;
 mod traits;
 
-pub use self::iter::IntoIter;
+pub use self::{
+	arrayvec::{
+		BitArrayVec,
+		CapacityError,
+		Drain,
+	},
+	exact::ExactBitArray,
+	iter::IntoIter,
+	traits::TryFromBitSliceError,
+};
 
 #[repr(transparent)]
 #[doc = include_str!("../doc/array/BitArray.md")]
@@ -0,0 +1,847 @@
+//! Bit-vectors with a fixed, inline, capacity.
+
+use core::{
+	borrow::{
+		Borrow,
+		BorrowMut,
+	},
+	cmp,
+	convert::TryFrom,
+	fmt::{
+		self,
+		Debug,
+		Display,
+		Formatter,
+	},
+	hash::{
+		Hash,
+		Hasher,
+	},
+	iter::FusedIterator,
+	ops::{
+		Deref,
+		DerefMut,
+		Range,
+		RangeBounds,
+	},
+};
+
+use wyz::range::RangeExt;
+
+use super::BitArray;
+use crate::{
+	mem::bits_of,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+	view::BitViewSized,
+};
+
+#[doc = include_str!("../../doc/array/BitArrayVec.md")]
+pub struct BitArrayVec<A = [usize; 1], O = Lsb0>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	/// The storage buffer. Only the first `len` bits are live.
+	bits: BitArray<A, O>,
+	/// The number of live bits at the front of `bits`.
+	len:  usize,
+}
+
+/// Constructors.
+impl<A, O> BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	/// The number of bits that the bit-vector can hold.
+	pub const CAPACITY: usize = bits_of::<A>();
+	/// An empty bit-vector.
+	///
+	/// This is usable in `const` and `static` initializers.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::array::BitArrayVec;
+	///
+	/// static EMPTY: BitArrayVec<[u8; 4], Msb0> = BitArrayVec::EMPTY;
+	/// assert!(EMPTY.is_empty());
+	/// assert_eq!(EMPTY.capacity(), 32);
+	/// ```
+	pub const EMPTY: Self = Self {
+		bits: BitArray::ZERO,
+		len:  0,
+	};
+
+	/// Constructs a new, empty, bit-vector.
+	///
+	/// ## Original
+	///
+	/// [`Vec::new`](https://doc.rust-lang.org/alloc/vec/struct.Vec.html#method.new)
+	#[inline]
+	pub fn new() -> Self {
+		Self::EMPTY
+	}
+
+	/// Copies the contents of a bit-slice into a new bit-vector.
+	///
+	/// ## Returns
+	///
+	/// The new bit-vector, or an error if the bit-slice is longer than
+	/// [`CAPACITY`].
+	///
+	/// [`CAPACITY`]: Self::CAPACITY
+	#[inline]
+	pub fn from_bitslice<T, O2>(
+		slice: &BitSlice<T, O2>,
+	) -> Result<Self, CapacityError>
+	where
+		T: BitStore,
+		O2: BitOrder,
+	{
+		let mut out = Self::EMPTY;
+		out.extend_from_bitslice(slice)?;
+		Ok(out)
+	}
+
+	/// Wraps a bit-array, marking all of its bits as live.
+	#[inline]
+	pub fn from_bitarray(bits: BitArray<A, O>) -> Self {
+		Self {
+			bits,
+			len: Self::CAPACITY,
+		}
+	}
+}
+
+/// Inspectors.
+impl<A, O> BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	/// Gets the number of bits that the bit-vector can hold.
+	///
+	/// This is always [`CAPACITY`].
+	///
+	/// ## Original
+	///
+	/// [`Vec::capacity`](https://doc.rust-lang.org/alloc/vec/struct.Vec.html#method.capacity)
+	///
+	/// [`CAPACITY`]: Self::CAPACITY
+	#[inline]
+	pub fn capacity(&self) -> usize {
+		Self::CAPACITY
+	}
+
+	/// Gets the number of bits that can still be pushed.
+	#[inline]
+	pub fn remaining_capacity(&self) -> usize {
+		Self::CAPACITY - self.len
+	}
+
+	/// Tests whether the bit-vector is full.
+	#[inline]
+	pub fn is_full(&self) -> bool {
+		self.len == Self::CAPACITY
+	}
+
+	/// Explicitly views the bit-vector as a bit-slice.
+	#[inline]
+	pub fn as_bitslice(&self) -> &BitSlice<A::Store, O> {
+		unsafe { self.bits.as_bitslice().get_unchecked(.. self.len) }
+	}
+
+	/// Explicitly views the bit-vector as a mutable bit-slice.
+	#[inline]
+	pub fn as_mut_bitslice(&mut self) -> &mut BitSlice<A::Store, O> {
+		unsafe { self.bits.as_mut_bitslice().get_unchecked_mut(.. self.len) }
+	}
+
+	/// Removes the bit-vector wrapper, returning the full storage bit-array.
+	///
+	/// The bits after the bit-vector’s length are unspecified.
+	#[inline]
+	pub fn into_bitarray(self) -> BitArray<A, O> {
+		self.bits
+	}
+}
+
+/// Port of the `Vec<bool>` inherent API.
+impl<A, O> BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	/// Shortens the bit-vector, keeping the first `new_len` bits and
+	/// discarding the rest.
+	///
+	/// If `new_len` is greater than the bit-vector’s current length, this has
+	/// no effect.
+	///
+	/// ## Original
+	///
+	/// [`Vec::truncate`](https://doc.rust-lang.org/alloc/vec/struct.Vec.html#method.truncate)
+	#[inline]
+	pub fn truncate(&mut self, new_len: usize) {
+		self.len = cmp::min(self.len, new_len);
+	}
+
+	/// Inserts a bit at a given position, shifting all bits after it one spot
+	/// to the right.
+	///
+	/// `index` may be any value up to *and including* `self.len()`.
+	///
+	/// ## Original
+	///
+	/// [`Vec::insert`](https://doc.rust-lang.org/alloc/vec/struct.Vec.html#method.insert)
+	///
+	/// ## Returns
+	///
+	/// An error, leaving the bit-vector unchanged, if it is full.
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is out of bounds (including `self.len()`).
+	#[inline]
+	pub fn insert(
+		&mut self,
+		index: usize,
+		value: bool,
+	) -> Result<(), CapacityError> {
+		self.assert_in_bounds(index, 0 ..= self.len);
+		self.push(value)?;
+		unsafe { self.get_unchecked_mut(index ..) }.rotate_right(1);
+		Ok(())
+	}
+
+	/// Removes a bit at a given position, shifting all bits after it one spot
+	/// to the left.
+	///
+	/// `index` may be any value up to, but **not** including, `self.len()`.
+	///
+	/// ## Original
+	///
+	/// [`Vec::remove`](https://doc.rust-lang.org/alloc/vec/struct.Vec.html#method.remove)
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is out of bounds (excluding `self.len()`).
+	#[inline]
+	pub fn remove(&mut self, index: usize) -> bool {
+		self.assert_in_bounds(index, 0 .. self.len);
+		unsafe { self.get_unchecked_mut(index ..) }.rotate_left(1);
+		self.len -= 1;
+		unsafe { *self.bits.get_unchecked(self.len) }
+	}
+
+	/// Retains only the bits that the predicate allows.
+	///
+	/// Bits are deleted from the vector when the predicate function returns
+	/// false. This function is linear in `self.len()`.
+	///
+	/// ## Original
+	///
+	/// [`Vec::retain`](https://doc.rust-lang.org/alloc/vec/struct.Vec.html#method.retain)
+	///
+	/// ## API Differences
+	///
+	/// The predicate receives the index of each bit as well as its value, as
+	/// in [`BitVec::retain`].
+	///
+	/// [`BitVec::retain`]: crate::vec::BitVec::retain
+	#[inline]
+	pub fn retain<F>(&mut self, mut func: F)
+	where F: FnMut(usize, &bool) -> bool {
+		let mut kept = 0;
+		for idx in 0 .. self.len {
+			let bit = unsafe { *self.bits.get_unchecked(idx) };
+			if func(idx, &bit) {
+				unsafe {
+					self.bits.set_unchecked(kept, bit);
+				}
+				kept += 1;
+			}
+		}
+		self.len = kept;
+	}
+
+	/// Appends a single bit to the bit-vector.
+	///
+	/// ## Original
+	///
+	/// [`Vec::push`](https://doc.rust-lang.org/alloc/vec/struct.Vec.html#method.push)
+	///
+	/// ## Returns
+	///
+	/// An error, leaving the bit-vector unchanged, if it is full.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::array::BitArrayVec;
+	///
+	/// let mut bav = BitArrayVec::<u8, Lsb0>::new();
+	/// for _ in 0 .. 8 {
+	///   bav.push(true).unwrap();
+	/// }
+	/// assert!(bav.push(false).is_err());
+	/// assert_eq!(bav.count_ones(), 8);
+	/// ```
+	#[inline]
+	pub fn push(&mut self, value: bool) -> Result<(), CapacityError> {
+		if self.is_full() {
+			return Err(CapacityError::new(self.len + 1, Self::CAPACITY));
+		}
+		unsafe {
+			self.bits.set_unchecked(self.len, value);
+		}
+		self.len += 1;
+		Ok(())
+	}
+
+	/// Attempts to remove the trailing bit from the bit-vector.
+	///
+	/// This returns `None` if the bit-vector is empty.
+	///
+	/// ## Original
+	///
+	/// [`Vec::pop`](https://doc.rust-lang.org/alloc/vec/struct.Vec.html#method.pop)
+	#[inline]
+	pub fn pop(&mut self) -> Option<bool> {
+		if self.len == 0 {
+			return None;
+		}
+		self.len -= 1;
+		Some(unsafe { *self.bits.get_unchecked(self.len) })
+	}
+
+	/// Removes a range of bits from the bit-vector, yielding them by value.
+	///
+	/// The bits after the range are moved down to close the gap when the
+	/// iterator is dropped, whether or not it was exhausted. If the iterator
+	/// is leaked, the bit-vector is left truncated to the start of the range.
+	///
+	/// ## Original
+	///
+	/// [`Vec::drain`](https://doc.rust-lang.org/alloc/vec/struct.Vec.html#method.drain)
+	///
+	/// ## Panics
+	///
+	/// This panics if the range extends past the end of the bit-vector.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::array::BitArrayVec;
+	///
+	/// let mut bav =
+	///   BitArrayVec::<u16, Msb0>::from_bitslice(bits![0, 1, 1, 0, 1]).unwrap();
+	/// assert!(bav.drain(1 .. 3).all(|bit| bit));
+	/// assert_eq!(bav, bits![0, 0, 1]);
+	/// ```
+	#[inline]
+	pub fn drain<R>(&mut self, range: R) -> Drain<A, O>
+	where R: RangeBounds<usize> {
+		Drain::new(self, range)
+	}
+
+	/// Empties the bit-vector.
+	///
+	/// ## Original
+	///
+	/// [`Vec::clear`](https://doc.rust-lang.org/alloc/vec/struct.Vec.html#method.clear)
+	#[inline]
+	pub fn clear(&mut self) {
+		self.len = 0;
+	}
+
+	/// Copies all bits in a bit-slice into the end of the bit-vector.
+	///
+	/// ## Original
+	///
+	/// [`Vec::extend_from_slice`](https://doc.rust-lang.org/alloc/vec/struct.Vec.html#method.extend_from_slice)
+	///
+	/// ## Returns
+	///
+	/// An error, leaving the bit-vector unchanged, if it does not have room
+	/// for all of `other`.
+	#[inline]
+	pub fn extend_from_bitslice<T, O2>(
+		&mut self,
+		other: &BitSlice<T, O2>,
+	) -> Result<(), CapacityError>
+	where
+		T: BitStore,
+		O2: BitOrder,
+	{
+		let new_len = self.len + other.len();
+		if new_len > Self::CAPACITY {
+			return Err(CapacityError::new(new_len, Self::CAPACITY));
+		}
+		unsafe { self.bits.get_unchecked_mut(self.len .. new_len) }
+			.clone_from_bitslice(other);
+		self.len = new_len;
+		Ok(())
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> Borrow<BitSlice<A::Store, O>> for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn borrow(&self) -> &BitSlice<A::Store, O> {
+		self.as_bitslice()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> BorrowMut<BitSlice<A::Store, O>> for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn borrow_mut(&mut self) -> &mut BitSlice<A::Store, O> {
+		self.as_mut_bitslice()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> Clone for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		Self {
+			bits: self.bits.clone(),
+			len:  self.len,
+		}
+	}
+}
+
+impl<A, O> Copy for BitArrayVec<A, O>
+where
+	O: BitOrder,
+	A: BitViewSized + Copy,
+{
+}
+
+impl<A, O> Eq for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> Ord for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn cmp(&self, other: &Self) -> cmp::Ordering {
+		self.as_bitslice().cmp(other.as_bitslice())
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O1, A, O2> PartialEq<BitArrayVec<A, O2>> for BitSlice<T, O1>
+where
+	T: BitStore,
+	O1: BitOrder,
+	A: BitViewSized,
+	O2: BitOrder,
+{
+	#[inline]
+	fn eq(&self, other: &BitArrayVec<A, O2>) -> bool {
+		self == other.as_bitslice()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O, Rhs> PartialEq<Rhs> for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+	Rhs: ?Sized,
+	BitSlice<A::Store, O>: PartialEq<Rhs>,
+{
+	#[inline]
+	fn eq(&self, other: &Rhs) -> bool {
+		self.as_bitslice() == other
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, A, O> PartialOrd<BitArrayVec<A, O>> for BitSlice<T, O>
+where
+	T: BitStore,
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn partial_cmp(&self, other: &BitArrayVec<A, O>) -> Option<cmp::Ordering> {
+		self.partial_cmp(other.as_bitslice())
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O, Rhs> PartialOrd<Rhs> for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+	Rhs: ?Sized,
+	BitSlice<A::Store, O>: PartialOrd<Rhs>,
+{
+	#[inline]
+	fn partial_cmp(&self, other: &Rhs) -> Option<cmp::Ordering> {
+		self.as_bitslice().partial_cmp(other)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> AsRef<BitSlice<A::Store, O>> for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn as_ref(&self) -> &BitSlice<A::Store, O> {
+		self.as_bitslice()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> AsMut<BitSlice<A::Store, O>> for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn as_mut(&mut self) -> &mut BitSlice<A::Store, O> {
+		self.as_mut_bitslice()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> From<BitArray<A, O>> for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn from(bits: BitArray<A, O>) -> Self {
+		Self::from_bitarray(bits)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O, T> TryFrom<&BitSlice<T, O>> for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+	T: BitStore,
+{
+	type Error = CapacityError;
+
+	#[inline]
+	fn try_from(slice: &BitSlice<T, O>) -> Result<Self, Self::Error> {
+		Self::from_bitslice(slice)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> Default for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn default() -> Self {
+		Self::EMPTY
+	}
+}
+
+impl<A, O> Debug for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		self.as_bitspan().render(fmt, "BitArrayVec", &[(
+			"capacity",
+			&self.capacity() as &dyn Debug,
+		)])?;
+		fmt.write_str(" ")?;
+		Display::fmt(self, fmt)
+	}
+}
+
+easy_fmt! {
+	impl Binary
+	impl Display
+	impl LowerHex
+	impl Octal
+	impl UpperHex
+	for BitArrayVec<A, O>
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> Hash for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn hash<H>(&self, hasher: &mut H)
+	where H: Hasher {
+		self.as_bitslice().hash(hasher);
+	}
+}
+
+impl<A, O> Deref for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	type Target = BitSlice<A::Store, O>;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		self.as_bitslice()
+	}
+}
+
+impl<A, O> DerefMut for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.as_mut_bitslice()
+	}
+}
+
+/** Draining iterator over a region of a fixed-capacity bit-vector.
+
+This is produced by [`BitArrayVec::drain`]. The drained bits stay in the
+bit-vector’s buffer until the iterator is dropped, at which point the bits after
+the drained region are moved down to close the gap.
+
+## Original
+
+[`vec::Drain`](https://doc.rust-lang.org/alloc/vec/struct.Drain.html)
+
+[`BitArrayVec::drain`]: crate::array::BitArrayVec::drain
+**/
+pub struct Drain<'a, A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	/// Exclusive reference to the bit-vector being drained. Its length is set
+	/// to the start of the drained region until the drain is dropped.
+	source: &'a mut BitArrayVec<A, O>,
+	/// The indices of the bits that have not yet been yielded.
+	drain:  Range<usize>,
+	/// The indices of the bits after the drained region.
+	tail:   Range<usize>,
+}
+
+impl<'a, A, O> Drain<'a, A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	/// Produces a new drain over a region of a bit-vector.
+	#[inline]
+	fn new<R>(source: &'a mut BitArrayVec<A, O>, range: R) -> Self
+	where R: RangeExt<usize> {
+		let len = source.len;
+		let drain = range.normalize(None, len);
+		assert!(
+			drain.end <= len,
+			"drains cannot extend past the length of their source bit-vector",
+		);
+		let tail = drain.end .. len;
+		source.len = drain.start;
+		Self {
+			source,
+			drain,
+			tail,
+		}
+	}
+
+	/// Views the unyielded bits remaining in the drain.
+	///
+	/// ## Original
+	///
+	/// [`Drain::as_slice`](https://doc.rust-lang.org/alloc/vec/struct.Drain.html#method.as_slice)
+	#[inline]
+	pub fn as_bitslice(&self) -> &BitSlice<A::Store, O> {
+		unsafe { self.source.bits.get_unchecked(self.drain.clone()) }
+	}
+
+	/// Reads the bit at `index` in the source buffer.
+	#[inline]
+	fn read(&self, index: usize) -> bool {
+		unsafe { *self.source.bits.get_unchecked(index) }
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> Debug for Drain<'_, A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_tuple("Drain").field(&self.as_bitslice()).finish()
+	}
+}
+
+impl<A, O> Iterator for Drain<'_, A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	type Item = bool;
+
+	easy_iter!();
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.drain.next().map(|idx| self.read(idx))
+	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.drain.nth(n).map(|idx| self.read(idx))
+	}
+}
+
+impl<A, O> DoubleEndedIterator for Drain<'_, A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.drain.next_back().map(|idx| self.read(idx))
+	}
+
+	#[inline]
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		self.drain.nth_back(n).map(|idx| self.read(idx))
+	}
+}
+
+impl<A, O> ExactSizeIterator for Drain<'_, A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn len(&self) -> usize {
+		self.drain.len()
+	}
+}
+
+impl<A, O> FusedIterator for Drain<'_, A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+}
+
+impl<A, O> Drop for Drain<'_, A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn drop(&mut self) {
+		let start = self.source.len;
+		let tail = self.tail.clone();
+		self.source.bits.copy_within(tail.clone(), start);
+		self.source.len = start + tail.len();
+	}
+}
+
+/** A fixed-capacity bit-vector did not have room for a request.
+
+This is produced by the [`BitArrayVec`] methods that add bits, in place of the
+reallocation that [`BitVec`] would perform. The bit-vector is always left
+unchanged.
+
+[`BitArrayVec`]: crate::array::BitArrayVec
+[`BitVec`]: crate::vec::BitVec
+**/
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct CapacityError {
+	/// The length that the request would have produced.
+	required: usize,
+	/// The capacity of the bit-vector.
+	capacity: usize,
+}
+
+impl CapacityError {
+	/// Records a failed request.
+	#[inline]
+	fn new(required: usize, capacity: usize) -> Self {
+		Self { required, capacity }
+	}
+
+	/// Gets the length, in bits, that the failed request needed.
+	#[inline]
+	pub fn required(&self) -> usize {
+		self.required
+	}
+
+	/// Gets the capacity, in bits, of the bit-vector that refused it.
+	#[inline]
+	pub fn capacity(&self) -> usize {
+		self.capacity
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Debug for CapacityError {
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(fmt, "CapacityError({} > {})", self.required, self.capacity)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Display for CapacityError {
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(
+			fmt,
+			"a bit-vector with capacity {} cannot hold {} bits",
+			self.capacity, self.required,
+		)
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CapacityError {}
@@ -0,0 +1,527 @@
+//! Bit-arrays with an exact, rather than rounded-up, bit length.
+
+use core::{
+	borrow::{
+		Borrow,
+		BorrowMut,
+	},
+	cmp,
+	convert::TryFrom,
+	fmt::{
+		self,
+		Debug,
+		Display,
+		Formatter,
+	},
+	hash::{
+		Hash,
+		Hasher,
+	},
+	marker::PhantomData,
+	ops::{
+		BitAndAssign,
+		BitOrAssign,
+		BitXorAssign,
+		Deref,
+		DerefMut,
+		Index,
+		IndexMut,
+		Not,
+	},
+};
+
+use super::{
+	BitArray,
+	TryFromBitSliceError,
+};
+use crate::{
+	mem::{
+		self,
+		BitRegister,
+	},
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+	view::BitViewSized,
+};
+
+#[repr(transparent)]
+#[doc = include_str!("../../doc/array/ExactBitArray.md")]
+pub struct ExactBitArray<A, O, const N: usize>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	/// The underlying bit-array. Only its first `N` bits are part of the
+	/// value; the rest are padding.
+	pub(crate) inner: BitArray<A, O>,
+}
+
+impl<A, O, const N: usize> ExactBitArray<A, O, N>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	/// Fails to compile when `A` is too small to hold `N` bits.
+	const FITS: () = [()][(N > mem::bits_of::<A>()) as usize];
+	/// An exact-length bit-array with all bits initialized to zero.
+	pub const ZERO: Self = {
+		let _: () = Self::FITS;
+		Self {
+			inner: BitArray::ZERO,
+		}
+	};
+
+	/// Wraps an existing buffer as an exact-length bit-array.
+	///
+	/// Only the first `N` bits of the buffer are part of the bit-array. Any
+	/// bits after them are retained in memory, but are not observable through
+	/// the bit-array and do not participate in comparison or hashing.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = ExactBitArray::<_, Lsb0, 10>::new([0xFFu8, 0xFF]);
+	/// assert_eq!(bits.len(), 10);
+	/// assert_eq!(bits.count_ones(), 10);
+	/// ```
+	#[inline]
+	pub fn new(data: A) -> Self {
+		let _: () = Self::FITS;
+		Self {
+			inner: BitArray::new(data),
+		}
+	}
+
+	/// Removes the bit-array wrapper, returning the contained buffer.
+	///
+	/// The padding bits after `N` are returned unchanged.
+	#[inline]
+	pub fn into_inner(self) -> A {
+		self.inner.into_inner()
+	}
+
+	/// Converts into an ordinary [`BitArray`] covering the whole buffer,
+	/// including its padding bits.
+	///
+	/// [`BitArray`]: crate::array::BitArray
+	#[inline]
+	pub fn into_bitarray(self) -> BitArray<A, O> {
+		self.inner
+	}
+
+	/// Explicitly views the bit-array as a bit-slice of exactly `N` bits.
+	#[inline]
+	pub fn as_bitslice(&self) -> &BitSlice<A::Store, O> {
+		unsafe { self.inner.as_bitslice().get_unchecked(.. N) }
+	}
+
+	/// Explicitly views the bit-array as a mutable bit-slice of exactly `N`
+	/// bits.
+	#[inline]
+	pub fn as_mut_bitslice(&mut self) -> &mut BitSlice<A::Store, O> {
+		unsafe { self.inner.as_mut_bitslice().get_unchecked_mut(.. N) }
+	}
+
+	/// Views the bit-array as a slice of its underlying memory elements.
+	///
+	/// The last element may contain padding bits that are not part of the
+	/// bit-array.
+	#[inline]
+	pub fn as_raw_slice(&self) -> &[A::Store] {
+		self.inner.as_raw_slice()
+	}
+
+	/// Views the bit-array as a mutable slice of its underlying memory
+	/// elements.
+	///
+	/// The last element may contain padding bits that are not part of the
+	/// bit-array.
+	#[inline]
+	pub fn as_raw_mut_slice(&mut self) -> &mut [A::Store] {
+		self.inner.as_raw_mut_slice()
+	}
+
+	/// Gets the length (in bits) of the bit-array.
+	///
+	/// This is always `N`.
+	#[inline]
+	pub fn len(&self) -> usize {
+		N
+	}
+
+	/// Tests whether the array is empty.
+	///
+	/// This is only true when `N` is zero.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		N == 0
+	}
+}
+
+impl<T, O, const K: usize, const N: usize> ExactBitArray<[T; K], O, N>
+where
+	T: BitStore + BitRegister,
+	O: BitOrder,
+{
+	/// An exact-length bit-array with all bits initialized to one.
+	///
+	/// The padding bits after `N` are also set, but are not observable.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// type Bits = BitArr!(exact 12, in u8, Msb0);
+	/// assert!(Bits::ONES.all());
+	/// assert_eq!(Bits::ONES.count_ones(), 12);
+	/// ```
+	pub const ONES: Self = {
+		let _: () = Self::FITS;
+		Self {
+			inner: BitArray {
+				_ord: PhantomData,
+				data: [T::ALL; K],
+			},
+		}
+	};
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O, const N: usize> Borrow<BitSlice<A::Store, O>>
+	for ExactBitArray<A, O, N>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn borrow(&self) -> &BitSlice<A::Store, O> {
+		self.as_bitslice()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O, const N: usize> BorrowMut<BitSlice<A::Store, O>>
+	for ExactBitArray<A, O, N>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn borrow_mut(&mut self) -> &mut BitSlice<A::Store, O> {
+		self.as_mut_bitslice()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O, const N: usize> Clone for ExactBitArray<A, O, N>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		Self {
+			inner: self.inner.clone(),
+		}
+	}
+}
+
+impl<A, O, const N: usize> Copy for ExactBitArray<A, O, N>
+where
+	O: BitOrder,
+	A: BitViewSized + Copy,
+{
+}
+
+impl<A, O, const N: usize> Eq for ExactBitArray<A, O, N>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O, const N: usize> Ord for ExactBitArray<A, O, N>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn cmp(&self, other: &Self) -> cmp::Ordering {
+		self.as_bitslice().cmp(other.as_bitslice())
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<O1, A, O2, T, const N: usize> PartialEq<ExactBitArray<A, O2, N>>
+	for BitSlice<T, O1>
+where
+	O1: BitOrder,
+	O2: BitOrder,
+	A: BitViewSized,
+	T: BitStore,
+{
+	#[inline]
+	fn eq(&self, other: &ExactBitArray<A, O2, N>) -> bool {
+		self == other.as_bitslice()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O, Rhs, const N: usize> PartialEq<Rhs> for ExactBitArray<A, O, N>
+where
+	A: BitViewSized,
+	O: BitOrder,
+	Rhs: ?Sized,
+	BitSlice<A::Store, O>: PartialEq<Rhs>,
+{
+	#[inline]
+	fn eq(&self, other: &Rhs) -> bool {
+		self.as_bitslice() == other
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, T, O, const N: usize> PartialOrd<ExactBitArray<A, O, N>>
+	for BitSlice<T, O>
+where
+	A: BitViewSized,
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn partial_cmp(
+		&self,
+		other: &ExactBitArray<A, O, N>,
+	) -> Option<cmp::Ordering> {
+		self.partial_cmp(other.as_bitslice())
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O, Rhs, const N: usize> PartialOrd<Rhs> for ExactBitArray<A, O, N>
+where
+	A: BitViewSized,
+	O: BitOrder,
+	Rhs: ?Sized,
+	BitSlice<A::Store, O>: PartialOrd<Rhs>,
+{
+	#[inline]
+	fn partial_cmp(&self, other: &Rhs) -> Option<cmp::Ordering> {
+		self.as_bitslice().partial_cmp(other)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O, const N: usize> AsRef<BitSlice<A::Store, O>>
+	for ExactBitArray<A, O, N>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn as_ref(&self) -> &BitSlice<A::Store, O> {
+		self.as_bitslice()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O, const N: usize> AsMut<BitSlice<A::Store, O>>
+	for ExactBitArray<A, O, N>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn as_mut(&mut self) -> &mut BitSlice<A::Store, O> {
+		self.as_mut_bitslice()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O, const N: usize> From<A> for ExactBitArray<A, O, N>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn from(data: A) -> Self {
+		Self::new(data)
+	}
+}
+
+/** Copies a bit-slice into an exact-length bit-array.
+
+The bit-slice must be exactly `N` bits long, but may begin at any bit within its
+first memory element.
+**/
+impl<A, O, const N: usize> TryFrom<&BitSlice<A::Store, O>>
+	for ExactBitArray<A, O, N>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	type Error = TryFromBitSliceError;
+
+	#[inline]
+	fn try_from(src: &BitSlice<A::Store, O>) -> Result<Self, Self::Error> {
+		if src.len() != N {
+			return Err(TryFromBitSliceError::unequal_len(src.len(), N));
+		}
+		let mut out = Self::ZERO;
+		out.as_mut_bitslice().clone_from_bitslice(src);
+		Ok(out)
+	}
+}
+
+impl<A, O, const N: usize> Default for ExactBitArray<A, O, N>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn default() -> Self {
+		Self::ZERO
+	}
+}
+
+impl<A, O, const N: usize> Debug for ExactBitArray<A, O, N>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		self.as_bitslice().as_bitspan().render(fmt, "ExactBitArray", &[(
+			"N",
+			&N as &dyn Debug,
+		)])?;
+		fmt.write_str(" ")?;
+		Display::fmt(self, fmt)
+	}
+}
+
+easy_fmt! {
+	impl Binary
+	impl Display
+	impl LowerHex
+	impl Octal
+	impl UpperHex
+	for ExactBitArray
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O, const N: usize> Hash for ExactBitArray<A, O, N>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn hash<H>(&self, hasher: &mut H)
+	where H: Hasher {
+		self.as_bitslice().hash(hasher);
+	}
+}
+
+impl<A, O, const N: usize> Deref for ExactBitArray<A, O, N>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	type Target = BitSlice<A::Store, O>;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		self.as_bitslice()
+	}
+}
+
+impl<A, O, const N: usize> DerefMut for ExactBitArray<A, O, N>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.as_mut_bitslice()
+	}
+}
+
+impl<A, O, Idx, const N: usize> Index<Idx> for ExactBitArray<A, O, N>
+where
+	A: BitViewSized,
+	O: BitOrder,
+	BitSlice<A::Store, O>: Index<Idx>,
+{
+	type Output = <BitSlice<A::Store, O> as Index<Idx>>::Output;
+
+	#[inline]
+	fn index(&self, index: Idx) -> &Self::Output {
+		&self.as_bitslice()[index]
+	}
+}
+
+impl<A, O, Idx, const N: usize> IndexMut<Idx> for ExactBitArray<A, O, N>
+where
+	A: BitViewSized,
+	O: BitOrder,
+	BitSlice<A::Store, O>: IndexMut<Idx>,
+{
+	#[inline]
+	fn index_mut(&mut self, index: Idx) -> &mut Self::Output {
+		&mut self.as_mut_bitslice()[index]
+	}
+}
+
+impl<A, O, Rhs, const N: usize> BitAndAssign<Rhs> for ExactBitArray<A, O, N>
+where
+	A: BitViewSized,
+	O: BitOrder,
+	BitSlice<A::Store, O>: BitAndAssign<Rhs>,
+{
+	#[inline]
+	fn bitand_assign(&mut self, rhs: Rhs) {
+		*self.as_mut_bitslice() &= rhs;
+	}
+}
+
+impl<A, O, Rhs, const N: usize> BitOrAssign<Rhs> for ExactBitArray<A, O, N>
+where
+	A: BitViewSized,
+	O: BitOrder,
+	BitSlice<A::Store, O>: BitOrAssign<Rhs>,
+{
+	#[inline]
+	fn bitor_assign(&mut self, rhs: Rhs) {
+		*self.as_mut_bitslice() |= rhs;
+	}
+}
+
+impl<A, O, Rhs, const N: usize> BitXorAssign<Rhs> for ExactBitArray<A, O, N>
+where
+	A: BitViewSized,
+	O: BitOrder,
+	BitSlice<A::Store, O>: BitXorAssign<Rhs>,
+{
+	#[inline]
+	fn bitxor_assign(&mut self, rhs: Rhs) {
+		*self.as_mut_bitslice() ^= rhs;
+	}
+}
+
+impl<A, O, const N: usize> Not for ExactBitArray<A, O, N>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	type Output = Self;
+
+	#[inline]
+	fn not(self) -> Self::Output {
+		Self { inner: !self.inner }
+	}
+}
@@ -133,6 +133,152 @@ fn iter() {
 	assert_eq!(iter.as_mut_bitslice(), &view[8 .. 120]);
 }
 
+#[test]
+fn shifts() {
+	let mut arr = bitarr![u16, Lsb0; 1; 16];
+	arr <<= 4;
+	assert_eq!(arr.into_inner(), [0x0FFF]);
+	arr >>= 8;
+	assert_eq!(arr.into_inner(), [0xFF00]);
+	assert_eq!((arr << 20).into_inner(), [0]);
+	assert_eq!((arr >> 1).into_inner(), [0xFE00]);
+}
+
+#[test]
+fn exact() {
+	type Exact = BitArr!(exact 10, in u8, Msb0);
+	assert_impl_all!(
+		Exact: AsRef<BitSlice<u8, Msb0>>,
+		Copy,
+		Debug,
+		Default,
+		Eq,
+		Hash,
+		IndexMut<Range<usize>>,
+		Ord,
+		PartialEq<&'static BitSlice<u8, Msb0>>,
+		TryFrom<&'static BitSlice<u8, Msb0>>,
+	);
+
+	let a = Exact::new([0xFF, 0xFF]);
+	assert_eq!(a.len(), 10);
+	assert!(!a.is_empty());
+	assert_eq!(a.as_bitslice().len(), 10);
+	assert_eq!(a.count_ones(), 10);
+	assert_eq!(a, Exact::ONES);
+	assert_eq!(a.as_raw_slice(), &[0xFF, 0xFF]);
+
+	let b = Exact::new([0xFF, 0xC0]);
+	assert_eq!(a, b);
+	assert_eq!(!b, Exact::ZERO);
+	assert_eq!(Exact::default(), Exact::ZERO);
+	assert_eq!(b.into_inner(), [0xFF, 0xC0]);
+	assert_eq!(b.into_bitarray().count_ones(), 10);
+
+	let mut c = Exact::ZERO;
+	c.set(9, true);
+	c |= bits![u8, Msb0; 1, 1];
+	assert!(c.iter_ones().eq([0, 1, 9]));
+	assert!(c > Exact::ZERO);
+
+	let data = [0b1011_0110u8, 0b0000_1111];
+	let bits = data.view_bits::<Msb0>();
+	let d = Exact::try_from(&bits[3 .. 13]).unwrap();
+	assert_eq!(d, bits[3 .. 13]);
+	assert!(Exact::try_from(&bits[.. 9]).is_err());
+	assert!(Exact::try_from(bits).is_err());
+
+	let e = ExactBitArray::<u32, Lsb0, 0>::ZERO;
+	assert!(e.is_empty());
+	assert_eq!(e.len(), 0);
+
+	//  Lengths need not be literals.
+	const W: usize = 5;
+	type Wide = BitArr!(exact W + 2, in u8);
+	let w = Wide::ONES;
+	assert_eq!(w.len(), 7);
+	assert_eq!(w.count_ones(), 7);
+	assert_eq!(w.as_raw_slice().len(), 1);
+}
+
+#[test]
+fn arrayvec() {
+	use crate::array::BitArrayVec;
+
+	type Bav = BitArrayVec<[u8; 2], Lsb0>;
+	assert_impl_all!(
+		Bav: AsMut<BitSlice<u8, Lsb0>>,
+		Copy,
+		Debug,
+		Default,
+		Eq,
+		Hash,
+		Ord,
+		PartialEq<&'static BitSlice<u8, Lsb0>>,
+		TryFrom<&'static BitSlice<u8, Lsb0>>,
+	);
+
+	static EMPTY: Bav = Bav::EMPTY;
+	assert!(EMPTY.is_empty());
+	assert_eq!(EMPTY.capacity(), 16);
+
+	let mut bav = Bav::new();
+	for n in 0 .. 16 {
+		bav.push(n % 4 == 0).unwrap();
+	}
+	assert!(bav.is_full());
+	assert_eq!(bav.remaining_capacity(), 0);
+	let err = bav.push(true).unwrap_err();
+	assert_eq!((err.required(), err.capacity()), (17, 16));
+	assert!(bav.insert(0, true).is_err());
+	assert_eq!(bav.count_ones(), 4);
+
+	assert_eq!(bav.pop(), Some(false));
+	bav.insert(1, true).unwrap();
+	assert_eq!(bav[.. 6], bits![1, 1, 0, 0, 0, 1]);
+	assert!(bav.remove(1));
+	assert_eq!(bav.len(), 15);
+
+	bav.truncate(9);
+	bav.retain(|idx, bit| idx == 1 || *bit);
+	assert_eq!(bav, bits![1, 0, 1, 1]);
+
+	assert!(bav.extend_from_bitslice(bits![u16, Msb0; 1; 13]).is_err());
+	assert_eq!(bav.len(), 4);
+	bav.extend_from_bitslice(bits![u16, Msb0; 0; 12]).unwrap();
+	assert!(bav.is_full());
+
+	bav.clear();
+	assert_eq!(bav.pop(), None);
+	assert!(Bav::try_from(bits![u8, Lsb0; 0; 17]).is_err());
+	assert_eq!(Bav::from(BitArray::new([1, 0])).count_ones(), 1);
+}
+
+#[test]
+fn arrayvec_drain() {
+	use crate::array::BitArrayVec;
+
+	let src = bits![u16, Msb0; 0, 1, 1, 0, 1, 0, 0, 1, 1];
+	let mut bav = BitArrayVec::<u16, Msb0>::try_from(src).unwrap();
+
+	let mut drain = bav.drain(2 .. 6);
+	assert_eq!(drain.len(), 4);
+	assert_eq!(drain.as_bitslice(), bits![1, 0, 1, 0]);
+	assert_eq!(drain.next(), Some(true));
+	assert_eq!(drain.next_back(), Some(false));
+	drop(drain);
+	assert_eq!(bav, bits![0, 1, 0, 1, 1]);
+
+	assert!(bav.drain(.. 2).eq([false, true]));
+	assert_eq!(bav, bits![0, 1, 1]);
+
+	bav.drain(1 ..);
+	assert_eq!(bav, bits![0]);
+
+	core::mem::forget(bav.drain(.. 1));
+	assert!(bav.is_empty());
+}
+
 #[cfg(feature = "alloc")]
 mod format {
 	#[cfg(not(feature = "std"))]
@@ -146,6 +292,10 @@ mod format {
 		BitArray,
 		Lsb0,
 	};
+	use crate::{
+		parse::ParseBitsErrorKind,
+		prelude::*,
+	};
 
 	#[test]
 	fn render() {
@@ -156,6 +306,15 @@ mod format {
 		)));
 		assert!(render.ends_with("[0, 0, 0, 0, 0, 0, 0, 0]"));
 
+		let exact = <BitArr!(exact 3, in u8, Lsb0)>::ONES;
+		assert_eq!(format!("{}", exact), "[1, 1, 1]");
+		let render = format!("{:?}", exact);
+		assert!(render.starts_with(&format!(
+			"ExactBitArray<u8, {}>",
+			any::type_name::<Lsb0>(),
+		)));
+		assert!(render.ends_with("bits: 3, N: 3 } [1, 1, 1]"));
+
 		assert_eq!(
 			format!(
 				"{:?}",
@@ -173,4 +332,28 @@ mod format {
 			"TryFromBitSliceError::Misaligned",
 		);
 	}
+
+	#[test]
+	fn parse() {
+		let arr = rand::random::<[u16; 3]>().into_bitarray::<Msb0>();
+		for text in &[
+			format!("{}", arr),
+			format!("{:b}", arr),
+			format!("{:#x}", arr),
+			format!("{:#X}", arr),
+		] {
+			assert_eq!(text.parse::<BitArray<[u16; 3], Msb0>>().unwrap(), arr);
+		}
+
+		let err = "0x123".parse::<BitArray<u8, Lsb0>>().unwrap_err();
+		assert_eq!(err.position(), 4);
+		assert_eq!(err.kind(), ParseBitsErrorKind::TooLong(8));
+
+		let err = "[1, 0]".parse::<BitArray<u8, Lsb0>>().unwrap_err();
+		assert_eq!(err.position(), 6);
+		assert_eq!(err.kind(), ParseBitsErrorKind::TooShort {
+			actual:   2,
+			expected: 8,
+		});
+	}
 }
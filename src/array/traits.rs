@@ -18,6 +18,7 @@ use core::{
 		Hasher,
 	},
 	marker::Unpin,
+	str::FromStr,
 };
 
 use tap::TryConv;
@@ -27,6 +28,11 @@ use crate::{
 	index::BitIdx,
 	mem,
 	order::BitOrder,
+	parse::{
+		self,
+		ParseBitsError,
+		ParseBitsErrorKind,
+	},
 	slice::BitSlice,
 	store::BitStore,
 	view::BitViewSized,
@@ -252,7 +258,7 @@ where
 {
 	#[inline]
 	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
-		self.as_bitspan().render(fmt, "Array", None)?;
+		self.as_bitspan().render(fmt, "BitArray", None)?;
 		fmt.write_str(" ")?;
 		Display::fmt(self, fmt)
 	}
@@ -267,6 +273,62 @@ easy_fmt! {
 	for BitArray
 }
 
+/** Parses a bit-array from text produced by its formatters.
+
+This uses the same grammar as [`BitVec`](crate::vec::BitVec#impl-FromStr), and
+additionally requires that the text produce exactly as many bits as the
+bit-array holds.
+
+## Examples
+
+```rust
+use bitvec::{
+  parse::ParseBitsErrorKind,
+  prelude::*,
+};
+
+let arr = "0xa5".parse::<BitArray<u8, Msb0>>().unwrap();
+assert_eq!(arr, bitarr![u8, Msb0; 1, 0, 1, 0, 0, 1, 0, 1]);
+
+let err = "0xa".parse::<BitArray<u8, Msb0>>().unwrap_err();
+assert_eq!(err.kind(), ParseBitsErrorKind::TooShort {
+  actual: 4,
+  expected: 8,
+});
+```
+**/
+impl<A, O> FromStr for BitArray<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	type Err = ParseBitsError;
+
+	#[inline]
+	fn from_str(src: &str) -> Result<Self, Self::Err> {
+		let mut out = Self::ZERO;
+		let mut bits = out.as_mut_bitslice().iter_mut();
+		let len = parse::parse(src, None, |bit| match bits.next() {
+			Some(mut slot) => {
+				*slot = bit;
+				true
+			},
+			None => false,
+		})?;
+		let expected = out.len();
+		if len != expected {
+			return Err(ParseBitsError::new(
+				src.len(),
+				ParseBitsErrorKind::TooShort {
+					actual: len,
+					expected,
+				},
+			));
+		}
+		Ok(out)
+	}
+}
+
 #[cfg(not(tarpaulin_include))]
 impl<A, O> Hash for BitArray<A, O>
 where
@@ -309,6 +371,12 @@ impl TryFromBitSliceError {
 	{
 		InnerError::new::<A, O>(bits).map_err(Self)
 	}
+
+	/// Produces an error for a bit-slice whose length is not `expected`.
+	#[inline]
+	pub(crate) fn unequal_len(actual: usize, expected: usize) -> Self {
+		Self(InnerError::UnequalLen { actual, expected })
+	}
 }
 
 impl Debug for TryFromBitSliceError {
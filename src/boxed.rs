@@ -14,11 +14,13 @@ use tap::{
 use wyz::comu::Mut;
 
 use crate::{
+	devel as dvl,
 	index::BitIdx,
 	mem,
 	order::{
 		BitOrder,
 		Lsb0,
+		Msb0,
 	},
 	ptr::{
 		BitPtr,
@@ -26,7 +28,10 @@ use crate::{
 	},
 	slice::BitSlice,
 	store::BitStore,
-	vec::BitVec,
+	vec::{
+		BitVec,
+		TryReserveError,
+	},
 	view::BitView,
 };
 
@@ -89,6 +94,30 @@ where
 		BitVec::from_bitslice(slice).into_boxed_bitslice()
 	}
 
+	/// Copies a bit-slice region into a new bit-box allocation, or reports why
+	/// the allocation could not be made.
+	///
+	/// This is the fallible version of [`from_bitslice`]. The new bit-box
+	/// always begins at the front edge of its first element.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bb = BitBox::try_from_bitslice(bits![0, 1, 1]).unwrap();
+	/// assert_eq!(bb, bits![0, 1, 1]);
+	/// ```
+	///
+	/// [`from_bitslice`]: Self::from_bitslice
+	#[inline]
+	pub fn try_from_bitslice(
+		slice: &BitSlice<T, O>,
+	) -> Result<Self, TryReserveError> {
+		//  The vector’s capacity is exact, so boxing it does not reallocate.
+		BitVec::try_from_bitslice(slice).map(BitVec::into_boxed_bitslice)
+	}
+
 	/// Converts a `Box<[T]>` into a `BitBox<T, O>`, in place.
 	///
 	/// This does not affect the referent buffer, and only transforms the
@@ -348,6 +377,18 @@ where
 		}
 	}
 
+	/// Reverses the bits in each element of the buffer, and changes the
+	/// bit-ordering type parameter to match.
+	///
+	/// This preserves the sequence of bits only when `O` and `O2` number the
+	/// bits of an element in opposite directions.
+	#[inline]
+	fn flip_order<O2>(mut self) -> BitBox<T, O2>
+	where O2: BitOrder {
+		dvl::reverse_element_bits(self.as_raw_mut_slice());
+		unsafe { BitBox::from_raw(Self::into_raw(self) as *mut BitSlice<T, O2>) }
+	}
+
 	/// Permits a function to modify the `Box` backing storage of a `BitBox`
 	/// handle.
 	///
@@ -363,3 +404,53 @@ where
 			.pipe_ref_mut(func)
 	}
 }
+
+/// Bit-ordering conversion.
+impl<T> BitBox<T, Lsb0>
+where T: BitStore
+{
+	/// Converts the bit-box to the `Msb0` ordering in place, preserving its
+	/// sequence of bits.
+	///
+	/// Each element of the buffer has its bits reversed, so that every index
+	/// refers to the same bit as before. This does not reällocate.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bb = bitbox![u16, Lsb0; 0, 1, 1];
+	/// let bb: BitBox<u16, Msb0> = bb.reinterpret_order();
+	/// assert_eq!(bb, bits![0, 1, 1]);
+	/// ```
+	#[inline]
+	pub fn reinterpret_order(self) -> BitBox<T, Msb0> {
+		self.flip_order()
+	}
+}
+
+/// Bit-ordering conversion.
+impl<T> BitBox<T, Msb0>
+where T: BitStore
+{
+	/// Converts the bit-box to the `Lsb0` ordering in place, preserving its
+	/// sequence of bits.
+	///
+	/// Each element of the buffer has its bits reversed, so that every index
+	/// refers to the same bit as before. This does not reällocate.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bb = bitbox![u16, Msb0; 0, 1, 1];
+	/// let bb: BitBox<u16, Lsb0> = bb.reinterpret_order();
+	/// assert_eq!(bb, bits![0, 1, 1]);
+	/// ```
+	#[inline]
+	pub fn reinterpret_order(self) -> BitBox<T, Lsb0> {
+		self.flip_order()
+	}
+}
@@ -14,6 +14,10 @@ use core::{
 		Index,
 		IndexMut,
 		Not,
+		Shl,
+		ShlAssign,
+		Shr,
+		ShrAssign,
 	},
 };
 
@@ -177,6 +181,60 @@ where
 	}
 }
 
+impl<T, O, Rhs> Shl<Rhs> for BitBox<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: ShlAssign<Rhs>,
+{
+	type Output = Self;
+
+	#[inline]
+	fn shl(mut self, rhs: Rhs) -> Self::Output {
+		self <<= rhs;
+		self
+	}
+}
+
+impl<T, O, Rhs> ShlAssign<Rhs> for BitBox<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: ShlAssign<Rhs>,
+{
+	#[inline]
+	fn shl_assign(&mut self, rhs: Rhs) {
+		*self.as_mut_bitslice() <<= rhs;
+	}
+}
+
+impl<T, O, Rhs> Shr<Rhs> for BitBox<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: ShrAssign<Rhs>,
+{
+	type Output = Self;
+
+	#[inline]
+	fn shr(mut self, rhs: Rhs) -> Self::Output {
+		self >>= rhs;
+		self
+	}
+}
+
+impl<T, O, Rhs> ShrAssign<Rhs> for BitBox<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: ShrAssign<Rhs>,
+{
+	#[inline]
+	fn shr_assign(&mut self, rhs: Rhs) {
+		*self.as_mut_bitslice() >>= rhs;
+	}
+}
+
 impl<T, O> Deref for BitBox<T, O>
 where
 	T: BitStore,
@@ -29,6 +29,7 @@ use core::{
 		Deref,
 		DerefMut,
 	},
+	str::FromStr,
 };
 
 use static_assertions::*;
@@ -103,6 +104,7 @@ fn traits() {
 		From<Cow<'static, BitSlice>>,
 		From<BitVec>,
 		FromIterator<bool>,
+		FromStr,
 		Hash,
 		Ord,
 		PartialEq<BitSlice>,
@@ -167,6 +169,9 @@ fn ops() {
 	let mut f = !e;
 	assert_eq!(f, bitbox![1, 0, 0, 1]);
 
+	assert_eq!(f.clone() << 1, bitbox![0, 0, 1, 0]);
+	assert_eq!(f.clone() >> 3, bitbox![0, 0, 0, 1]);
+
 	let _: &BitSlice = &a;
 	let _: &mut BitSlice = &mut f;
 }
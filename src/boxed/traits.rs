@@ -22,6 +22,7 @@ use core::{
 		Hasher,
 	},
 	iter::FromIterator,
+	str::FromStr,
 };
 
 use tap::Pipe;
@@ -30,6 +31,7 @@ use super::BitBox;
 use crate::{
 	array::BitArray,
 	order::BitOrder,
+	parse::ParseBitsError,
 	slice::BitSlice,
 	store::BitStore,
 	vec::BitVec,
@@ -326,7 +328,7 @@ where
 {
 	#[inline]
 	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
-		self.bitspan.render(fmt, "Box", None)?;
+		self.bitspan.render(fmt, "BitBox", None)?;
 		fmt.write_str(" ")?;
 		Display::fmt(self, fmt)
 	}
@@ -342,6 +344,22 @@ easy_fmt! {
 	for BitBox
 }
 
+/// Parses a boxed bit-slice from text, using the same grammar as
+/// [`BitVec`](crate::vec::BitVec#impl-FromStr).
+#[cfg(not(tarpaulin_include))]
+impl<T, O> FromStr for BitBox<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Err = ParseBitsError;
+
+	#[inline]
+	fn from_str(src: &str) -> Result<Self, Self::Err> {
+		src.parse::<BitVec<T, O>>().map(BitVec::into_boxed_bitslice)
+	}
+}
+
 #[cfg(not(tarpaulin_include))]
 impl<T, O, I> FromIterator<I> for BitBox<T, O>
 where
@@ -0,0 +1,785 @@
+#![doc = include_str!("../doc/codec.md")]
+
+use core::{
+	cmp,
+	fmt::{
+		self,
+		Display,
+		Formatter,
+	},
+};
+
+#[cfg(feature = "alloc")]
+use crate::vec::BitVec;
+use crate::{
+	field::BitField,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+#[doc = include_str!("../doc/codec/Codec.md")]
+pub trait Codec {
+	/// Computes the number of bits in the code for `value`, without encoding
+	/// it.
+	///
+	/// ## Panics
+	///
+	/// This panics if the code cannot represent `value`, under the same
+	/// conditions as [`.encode()`].
+	///
+	/// [`.encode()`]: Self::encode
+	fn encoded_len(&self, value: u64) -> usize;
+
+	/// Appends the code for `value` to the end of a bit-vector.
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `value`: The integer to encode.
+	/// - `bits`: The bit-vector onto which the code is pushed.
+	///
+	/// ## Panics
+	///
+	/// This panics if the code cannot represent `value`. Each implementor
+	/// documents its domain.
+	#[cfg(feature = "alloc")]
+	fn encode<T, O>(&self, value: u64, bits: &mut BitVec<T, O>)
+	where
+		T: BitStore,
+		O: BitOrder,
+		BitSlice<T, O>: BitField;
+
+	/// Parses one code from the front of a bit-slice.
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `bits`: A bit-slice that begins with an encoded value.
+	///
+	/// ## Returns
+	///
+	/// The decoded value, and the rest of `bits` after its code.
+	fn decode<'a, T, O>(
+		&self,
+		bits: &'a BitSlice<T, O>,
+	) -> Result<(u64, &'a BitSlice<T, O>), DecodeError>
+	where
+		T: BitStore,
+		O: BitOrder,
+		BitSlice<T, O>: BitField;
+}
+
+/// The unary code.
+///
+/// The value `n` is encoded as `n` `1` bits, followed by a single `0` bit. This
+/// can represent every `u64`, but the code for `n` is `n + 1` bits long, so it
+/// is only suitable for values known to be small.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Unary;
+
+impl Codec for Unary {
+	#[inline]
+	fn encoded_len(&self, value: u64) -> usize {
+		unary_len(value) + 1
+	}
+
+	#[inline]
+	#[cfg(feature = "alloc")]
+	fn encode<T, O>(&self, value: u64, bits: &mut BitVec<T, O>)
+	where
+		T: BitStore,
+		O: BitOrder,
+		BitSlice<T, O>: BitField,
+	{
+		let len = bits.len();
+		bits.resize(len + unary_len(value), true);
+		bits.push(false);
+	}
+
+	#[inline]
+	fn decode<'a, T, O>(
+		&self,
+		bits: &'a BitSlice<T, O>,
+	) -> Result<(u64, &'a BitSlice<T, O>), DecodeError>
+	where
+		T: BitStore,
+		O: BitOrder,
+		BitSlice<T, O>: BitField,
+	{
+		let count = bits.first_zero().ok_or(DecodeError::UnexpectedEnd)?;
+		Ok((count as u64, unsafe { bits.get_unchecked(count + 1 ..) }))
+	}
+}
+
+/// The Elias gamma code.
+///
+/// A positive value `n` with `N + 1` significant bits is encoded as `N` `0`
+/// bits, followed by the `N + 1` significant bits of `n`, most significant
+/// first. The code for `n` is `2N + 1` bits long.
+///
+/// ## Panics
+///
+/// Encoding `0` panics, as the gamma code only represents positive integers.
+/// Use [`ExpGolomb::new(0)`], which is the gamma code of `n + 1`, to encode
+/// values that may be zero.
+///
+/// [`ExpGolomb::new(0)`]: ExpGolomb::new
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Gamma;
+
+impl Codec for Gamma {
+	#[inline]
+	fn encoded_len(&self, value: u64) -> usize {
+		2 * significant_bits(value) as usize - 1
+	}
+
+	#[inline]
+	#[cfg(feature = "alloc")]
+	fn encode<T, O>(&self, value: u64, bits: &mut BitVec<T, O>)
+	where
+		T: BitStore,
+		O: BitOrder,
+		BitSlice<T, O>: BitField,
+	{
+		let width = significant_bits(value) as usize;
+		let len = bits.len();
+		bits.resize(len + width - 1, false);
+		bits.push(true);
+		push_field(bits, width - 1, value);
+	}
+
+	#[inline]
+	fn decode<'a, T, O>(
+		&self,
+		bits: &'a BitSlice<T, O>,
+	) -> Result<(u64, &'a BitSlice<T, O>), DecodeError>
+	where
+		T: BitStore,
+		O: BitOrder,
+		BitSlice<T, O>: BitField,
+	{
+		let zeros = count_zeros(bits, 63)?;
+		let (value, rest) =
+			pop_field(unsafe { bits.get_unchecked(zeros + 1 ..) }, zeros)?;
+		Ok((1 << zeros | value, rest))
+	}
+}
+
+/// The Elias delta code.
+///
+/// A positive value `n` with `N + 1` significant bits is encoded as the
+/// [`Gamma`] code of `N + 1`, followed by the low `N` bits of `n`, most
+/// significant first. This is shorter than the gamma code for all but the
+/// smallest values.
+///
+/// ## Panics
+///
+/// Encoding `0` panics, as the delta code only represents positive integers.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Delta;
+
+impl Codec for Delta {
+	#[inline]
+	fn encoded_len(&self, value: u64) -> usize {
+		let width = significant_bits(value);
+		Gamma.encoded_len(width as u64) + width as usize - 1
+	}
+
+	#[inline]
+	#[cfg(feature = "alloc")]
+	fn encode<T, O>(&self, value: u64, bits: &mut BitVec<T, O>)
+	where
+		T: BitStore,
+		O: BitOrder,
+		BitSlice<T, O>: BitField,
+	{
+		let width = significant_bits(value);
+		Gamma.encode(width as u64, bits);
+		push_field(bits, width as usize - 1, value);
+	}
+
+	#[inline]
+	fn decode<'a, T, O>(
+		&self,
+		bits: &'a BitSlice<T, O>,
+	) -> Result<(u64, &'a BitSlice<T, O>), DecodeError>
+	where
+		T: BitStore,
+		O: BitOrder,
+		BitSlice<T, O>: BitField,
+	{
+		let (width, rest) = Gamma.decode(bits)?;
+		if width > 64 {
+			return Err(DecodeError::Overflow);
+		}
+		let low = width as usize - 1;
+		let (value, rest) = pop_field(rest, low)?;
+		Ok((1 << low | value, rest))
+	}
+}
+
+/// The exponential-Golomb code of order `k`.
+///
+/// The value `n` is encoded by computing `m = n + 2^k`, which has `N + 1`
+/// significant bits, and then writing `N - k` `0` bits followed by the `N + 1`
+/// significant bits of `m`, most significant first.
+///
+/// Order `0` is the `ue(v)` code of the H.264 and H.265 video standards, and
+/// is equivalent to the [`Gamma`] code of `n + 1`. Every `u64` can be encoded
+/// at every order.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ExpGolomb {
+	/// The order of the code.
+	k: u32,
+}
+
+impl ExpGolomb {
+	/// Creates an exponential-Golomb code of order `k`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `k` is not less than 64.
+	#[inline]
+	pub fn new(k: u32) -> Self {
+		assert!(
+			k < 64,
+			"exponential-Golomb order {} must be less than 64",
+			k
+		);
+		Self { k }
+	}
+
+	/// Gets the order of the code.
+	#[inline]
+	pub fn order(&self) -> u32 {
+		self.k
+	}
+
+	/// Appends the code for a signed value to the end of a bit-vector.
+	///
+	/// Signed values are first mapped to unsigned code numbers with the H.264
+	/// `se(v)` mapping: positive values `v` become `2v - 1`, and all others
+	/// become `-2v`. This produces the sequence `0, 1, -1, 2, -2, …`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::{
+	///   codec::ExpGolomb,
+	///   prelude::*,
+	/// };
+	///
+	/// let ue = ExpGolomb::new(0);
+	/// let mut bv = BitVec::<u8, Msb0>::new();
+	/// ue.encode_signed(-2, &mut bv);
+	/// assert_eq!(bv, bits![0, 0, 1, 0, 1]);
+	/// assert_eq!(ue.decode_signed(&bv).unwrap().0, -2);
+	/// ```
+	#[inline]
+	#[cfg(feature = "alloc")]
+	pub fn encode_signed<T, O>(&self, value: i64, bits: &mut BitVec<T, O>)
+	where
+		T: BitStore,
+		O: BitOrder,
+		BitSlice<T, O>: BitField,
+	{
+		let code = if value > 0 {
+			2 * value as u128 - 1
+		}
+		else {
+			2 * value.unsigned_abs() as u128
+		};
+		self.encode_wide(code, bits);
+	}
+
+	/// Parses a signed value from the front of a bit-slice.
+	///
+	/// This is the inverse of [`.encode_signed()`].
+	///
+	/// [`.encode_signed()`]: Self::encode_signed
+	#[inline]
+	pub fn decode_signed<'a, T, O>(
+		&self,
+		bits: &'a BitSlice<T, O>,
+	) -> Result<(i64, &'a BitSlice<T, O>), DecodeError>
+	where
+		T: BitStore,
+		O: BitOrder,
+		BitSlice<T, O>: BitField,
+	{
+		let (code, rest) = self.decode_wide(bits)?;
+		let half = (code + 1) / 2;
+		let value = if code & 1 == 1 {
+			i64::try_from(half).map_err(|_| DecodeError::Overflow)?
+		}
+		else if half == 1 << 63 {
+			i64::MIN
+		}
+		else {
+			-i64::try_from(half).map_err(|_| DecodeError::Overflow)?
+		};
+		Ok((value, rest))
+	}
+
+	/// Computes the number of significant bits in `n + 2^k`.
+	#[inline]
+	fn width(&self, value: u128) -> u32 {
+		128 - (value + (1 << self.k)).leading_zeros()
+	}
+
+	/// Encodes a code number, which may exceed `u64::MAX` by up to `2^64`.
+	#[inline]
+	#[cfg(feature = "alloc")]
+	fn encode_wide<T, O>(&self, value: u128, bits: &mut BitVec<T, O>)
+	where
+		T: BitStore,
+		O: BitOrder,
+		BitSlice<T, O>: BitField,
+	{
+		let width = self.width(value) as usize;
+		let len = bits.len();
+		bits.resize(len + width - self.k as usize - 1, false);
+		//  The leading bit of `m` is always `1`, and the remaining bits fit in
+		//  a `u64`.
+		bits.push(true);
+		push_field(bits, width - 1, (value + (1 << self.k)) as u64);
+	}
+
+	/// Decodes a code number, which may exceed `u64::MAX` by up to `2^64`.
+	#[inline]
+	fn decode_wide<'a, T, O>(
+		&self,
+		bits: &'a BitSlice<T, O>,
+	) -> Result<(u128, &'a BitSlice<T, O>), DecodeError>
+	where
+		T: BitStore,
+		O: BitOrder,
+		BitSlice<T, O>: BitField,
+	{
+		let k = self.k as usize;
+		let zeros = count_zeros(bits, 64 - k)?;
+		let low = zeros + k;
+		let (value, rest) =
+			pop_field(unsafe { bits.get_unchecked(zeros + 1 ..) }, low)?;
+		Ok(((1u128 << low | value as u128) - (1 << k), rest))
+	}
+}
+
+impl Codec for ExpGolomb {
+	#[inline]
+	fn encoded_len(&self, value: u64) -> usize {
+		2 * self.width(value as u128) as usize - self.k as usize - 1
+	}
+
+	#[inline]
+	#[cfg(feature = "alloc")]
+	fn encode<T, O>(&self, value: u64, bits: &mut BitVec<T, O>)
+	where
+		T: BitStore,
+		O: BitOrder,
+		BitSlice<T, O>: BitField,
+	{
+		self.encode_wide(value as u128, bits);
+	}
+
+	#[inline]
+	fn decode<'a, T, O>(
+		&self,
+		bits: &'a BitSlice<T, O>,
+	) -> Result<(u64, &'a BitSlice<T, O>), DecodeError>
+	where
+		T: BitStore,
+		O: BitOrder,
+		BitSlice<T, O>: BitField,
+	{
+		let (value, rest) = self.decode_wide(bits)?;
+		let value = u64::try_from(value).map_err(|_| DecodeError::Overflow)?;
+		Ok((value, rest))
+	}
+}
+
+/// The Golomb-Rice code with parameter `k`.
+///
+/// The value `n` is encoded as the [`Unary`] code of `n >> k`, followed by the
+/// low `k` bits of `n`, most significant first. This is efficient for values
+/// that are geometrically distributed around `2^k`.
+///
+/// Every `u64` can be encoded, but the unary quotient grows linearly with `n`,
+/// so `k` must be chosen to suit the data.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Rice {
+	/// The number of low bits written verbatim.
+	k: u32,
+}
+
+impl Rice {
+	/// Creates a Golomb-Rice code with parameter `k`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `k` is not less than 64.
+	#[inline]
+	pub fn new(k: u32) -> Self {
+		assert!(k < 64, "Rice parameter {} must be less than 64", k);
+		Self { k }
+	}
+
+	/// Gets the parameter of the code.
+	#[inline]
+	pub fn parameter(&self) -> u32 {
+		self.k
+	}
+}
+
+impl Codec for Rice {
+	#[inline]
+	fn encoded_len(&self, value: u64) -> usize {
+		Unary.encoded_len(value >> self.k) + self.k as usize
+	}
+
+	#[inline]
+	#[cfg(feature = "alloc")]
+	fn encode<T, O>(&self, value: u64, bits: &mut BitVec<T, O>)
+	where
+		T: BitStore,
+		O: BitOrder,
+		BitSlice<T, O>: BitField,
+	{
+		Unary.encode(value >> self.k, bits);
+		push_field(bits, self.k as usize, value);
+	}
+
+	#[inline]
+	fn decode<'a, T, O>(
+		&self,
+		bits: &'a BitSlice<T, O>,
+	) -> Result<(u64, &'a BitSlice<T, O>), DecodeError>
+	where
+		T: BitStore,
+		O: BitOrder,
+		BitSlice<T, O>: BitField,
+	{
+		let (quot, rest) = Unary.decode(bits)?;
+		if quot > u64::MAX >> self.k {
+			return Err(DecodeError::Overflow);
+		}
+		let (rem, rest) = pop_field(rest, self.k as usize)?;
+		Ok((quot << self.k | rem, rest))
+	}
+}
+
+/// The unsigned LEB128 code.
+///
+/// The value is split into seven-bit groups, least significant group first.
+/// Each group is written as an eight-bit field whose most significant bit is
+/// set on every group except the last. In a `BitVec<u8, Msb0>` that begins at a
+/// byte boundary, this produces exactly the bytes of the standard LEB128
+/// encoding.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Leb128;
+
+impl Codec for Leb128 {
+	#[inline]
+	fn encoded_len(&self, value: u64) -> usize {
+		let groups = (64 - value.leading_zeros() as usize + 6) / 7;
+		8 * cmp::max(groups, 1)
+	}
+
+	#[inline]
+	#[cfg(feature = "alloc")]
+	fn encode<T, O>(&self, mut value: u64, bits: &mut BitVec<T, O>)
+	where
+		T: BitStore,
+		O: BitOrder,
+		BitSlice<T, O>: BitField,
+	{
+		loop {
+			let group = value & 0x7F;
+			value >>= 7;
+			if value == 0 {
+				return push_field(bits, 8, group);
+			}
+			push_field(bits, 8, group | 0x80);
+		}
+	}
+
+	#[inline]
+	fn decode<'a, T, O>(
+		&self,
+		mut bits: &'a BitSlice<T, O>,
+	) -> Result<(u64, &'a BitSlice<T, O>), DecodeError>
+	where
+		T: BitStore,
+		O: BitOrder,
+		BitSlice<T, O>: BitField,
+	{
+		let mut value = 0u64;
+		let mut shamt = 0;
+		loop {
+			let (byte, rest) = pop_field(bits, 8)?;
+			bits = rest;
+			let group = byte & 0x7F;
+			if shamt >= 64 || group << shamt >> shamt != group {
+				return Err(DecodeError::Overflow);
+			}
+			value |= group << shamt;
+			if byte & 0x80 == 0 {
+				return Ok((value, bits));
+			}
+			shamt += 7;
+		}
+	}
+}
+
+/// An error produced when a bit-slice does not begin with a valid code.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DecodeError {
+	/// The bit-slice ended before the code was complete.
+	UnexpectedEnd,
+	/// The code describes a value that does not fit in a `u64` (or an `i64`,
+	/// for signed decoding).
+	Overflow,
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Display for DecodeError {
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		match self {
+			Self::UnexpectedEnd => {
+				fmt.write_str("the bit-slice ended before the code was complete")
+			},
+			Self::Overflow => {
+				fmt.write_str("the encoded value is too large for its type")
+			},
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
+
+/// Counts the significant bits in a positive value.
+///
+/// ## Panics
+///
+/// This panics if `value` is zero.
+#[inline]
+fn significant_bits(value: u64) -> u32 {
+	assert!(value > 0, "Elias codes cannot encode zero");
+	64 - value.leading_zeros()
+}
+
+/// Converts the length of a unary run into a `usize`.
+#[inline]
+fn unary_len(value: u64) -> usize {
+	usize::try_from(value).expect("unary code is too long to fit in memory")
+}
+
+/// Counts the `0` bits at the front of a bit-slice, up to a limit.
+///
+/// ## Parameters
+///
+/// - `bits`: The bit-slice to scan.
+/// - `limit`: The largest count of `0` bits that a valid code can have.
+///
+/// ## Returns
+///
+/// The number of `0` bits before the first `1` bit. This fails with
+/// `UnexpectedEnd` if `bits` ends first, or with `Overflow` if more than
+/// `limit` `0` bits are present.
+#[inline]
+fn count_zeros<T, O>(
+	bits: &BitSlice<T, O>,
+	limit: usize,
+) -> Result<usize, DecodeError>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	let window = cmp::min(bits.len(), limit + 1);
+	match unsafe { bits.get_unchecked(.. window) }.first_one() {
+		Some(zeros) => Ok(zeros),
+		None if window > limit => Err(DecodeError::Overflow),
+		None => Err(DecodeError::UnexpectedEnd),
+	}
+}
+
+/// Appends the low `width` bits of `value` to a bit-vector, most significant
+/// first.
+#[inline]
+#[cfg(feature = "alloc")]
+fn push_field<T, O>(bits: &mut BitVec<T, O>, width: usize, value: u64)
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	if width == 0 {
+		return;
+	}
+	let len = bits.len();
+	bits.resize(len + width, false);
+	unsafe { bits.get_unchecked_mut(len ..) }.store_be(value);
+}
+
+/// Splits a `width`-bit field off the front of a bit-slice, and loads it.
+#[inline]
+fn pop_field<T, O>(
+	bits: &BitSlice<T, O>,
+	width: usize,
+) -> Result<(u64, &BitSlice<T, O>), DecodeError>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	if width == 0 {
+		return Ok((0, bits));
+	}
+	if bits.len() < width {
+		return Err(DecodeError::UnexpectedEnd);
+	}
+	let (field, rest) = unsafe { bits.split_at_unchecked(width) };
+	Ok((field.load_be::<u64>(), rest))
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod tests {
+	use super::*;
+	use crate::prelude::*;
+
+	/// Encodes and decodes a run of values with a code, checking the lengths.
+	fn round_trip<C>(code: C, values: &[u64])
+	where C: Codec {
+		let mut bv = BitVec::<u16, Msb0>::new();
+		for &value in values {
+			let len = bv.len();
+			code.encode(value, &mut bv);
+			assert_eq!(bv.len() - len, code.encoded_len(value), "{}", value);
+		}
+		let mut bits = bv.as_bitslice();
+		for &value in values {
+			let (decoded, rest) = code.decode(bits).unwrap();
+			assert_eq!(decoded, value);
+			bits = rest;
+		}
+		assert!(bits.is_empty());
+
+		let mut bv = BitVec::<u8, Lsb0>::new();
+		for &value in values {
+			code.encode(value, &mut bv);
+		}
+		let mut bits = bv.as_bitslice();
+		for &value in values {
+			let (decoded, rest) = code.decode(bits).unwrap();
+			assert_eq!(decoded, value);
+			bits = rest;
+		}
+	}
+
+	#[test]
+	fn codes() {
+		let small = [0u64, 1, 2, 3, 7, 8, 100, 1000];
+		let large =
+			[0u64, 1, 255, 1 << 32, u64::MAX >> 1, u64::MAX - 1, u64::MAX];
+		round_trip(Unary, &small);
+		round_trip(Gamma, &large[1 ..]);
+		round_trip(Delta, &large[1 ..]);
+		round_trip(Leb128, &large);
+		for &k in &[0, 1, 5, 63] {
+			round_trip(ExpGolomb::new(k), &large);
+			round_trip(Rice::new(k), &small);
+		}
+		round_trip(Rice::new(60), &large);
+	}
+
+	#[test]
+	fn layouts() {
+		let mut bv = BitVec::<u8, Msb0>::new();
+		Unary.encode(3, &mut bv);
+		Gamma.encode(5, &mut bv);
+		Delta.encode(10, &mut bv);
+		Rice::new(2).encode(9, &mut bv);
+		assert_eq!(bv, bits![
+			1, 1, 1, 0, // unary 3
+			0, 0, 1, 0, 1, // gamma 5
+			0, 0, 1, 0, 0, 0, 1, 0, // delta 10
+			1, 1, 0, 0, 1, // rice 9
+		]);
+
+		let ue = ExpGolomb::new(0);
+		bv.clear();
+		for value in 0 .. 5 {
+			ue.encode(value, &mut bv);
+		}
+		assert_eq!(
+			bv,
+			bits![1, 0, 1, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 1,]
+		);
+
+		bv.clear();
+		Leb128.encode(624485, &mut bv);
+		assert_eq!(bv.as_raw_slice(), &[0xE5, 0x8E, 0x26]);
+	}
+
+	#[test]
+	fn signed() {
+		let ue = ExpGolomb::new(0);
+		let values = [0, 1, -1, 2, -2, i64::MAX, i64::MIN, i64::MIN + 1];
+		let mut bv = BitVec::<u8, Msb0>::new();
+		for &value in &values {
+			ue.encode_signed(value, &mut bv);
+		}
+		let mut bits = bv.as_bitslice();
+		for &value in &values {
+			let (decoded, rest) = ue.decode_signed(bits).unwrap();
+			assert_eq!(decoded, value);
+			bits = rest;
+		}
+
+		bv.clear();
+		for code in 0 .. 5 {
+			ue.encode(code, &mut bv);
+		}
+		let mut bits = bv.as_bitslice();
+		for &value in &[0, 1, -1, 2, -2] {
+			let (decoded, rest) = ue.decode_signed(bits).unwrap();
+			assert_eq!(decoded, value);
+			bits = rest;
+		}
+	}
+
+	#[test]
+	fn errors() {
+		assert_eq!(Unary.decode(bits![1, 1]), Err(DecodeError::UnexpectedEnd));
+		assert_eq!(
+			Gamma.decode(bits![0, 0, 1, 0]),
+			Err(DecodeError::UnexpectedEnd)
+		);
+		assert_eq!(Gamma.decode(bits![0; 70]), Err(DecodeError::Overflow));
+		assert_eq!(Gamma.decode(bits![0; 10]), Err(DecodeError::UnexpectedEnd));
+
+		let mut bv = BitVec::<u8, Msb0>::new();
+		ExpGolomb::new(0).encode_signed(i64::MIN, &mut bv);
+		assert_eq!(ExpGolomb::new(0).decode(&bv), Err(DecodeError::Overflow));
+
+		assert_eq!(
+			Rice::new(60).decode(bits![1; 17]),
+			Err(DecodeError::UnexpectedEnd),
+		);
+		let mut bv = bitvec![1; 16];
+		bv.push(false);
+		assert_eq!(Rice::new(60).decode(&bv), Err(DecodeError::Overflow));
+
+		bv.clear();
+		for _ in 0 .. 10 {
+			Leb128.encode(0xFF, &mut bv);
+			bv.truncate(bv.len() - 8);
+		}
+		Leb128.encode(1, &mut bv);
+		assert_eq!(Leb128.decode(&bv), Err(DecodeError::Overflow));
+	}
+}
@@ -2,8 +2,19 @@
 
 use core::any::TypeId;
 
+#[cfg(feature = "alloc")]
+use funty::Integral;
+
 use crate::{
-	order::BitOrder,
+	order::{
+		BitOrder,
+		Lsb0,
+		Lsb0ByteSwap,
+		Lsb0NibbleSwap,
+		Msb0,
+		Msb0ByteSwap,
+		Msb0NibbleSwap,
+	},
 	store::BitStore,
 };
 
@@ -22,6 +33,47 @@ macro_rules! easy_fmt {
 			}
 		}
 	)+ };
+	($(impl $fmt:ident)+ for ExactBitArray) => { $(
+		impl<A, O, const N: usize> core::fmt::$fmt
+			for $crate::array::ExactBitArray<A, O, N>
+		where
+			O: $crate::order::BitOrder,
+			A: $crate::view::BitViewSized,
+		{
+			#[inline]
+			#[cfg(not(tarpaulin_include))]
+			fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+				core::fmt::$fmt::fmt(self.as_bitslice(), fmt)
+			}
+		}
+	)+ };
+	($(impl $fmt:ident)+ for BitVec) => { $(
+		impl<T, O, #[cfg(feature = "allocator")] A: $crate::vec::Allocator>
+			core::fmt::$fmt for in_alloc!(BitVec<T, O>, A)
+		where
+			O: $crate::order::BitOrder,
+			T: $crate::store::BitStore,
+		{
+			#[inline]
+			#[cfg(not(tarpaulin_include))]
+			fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+				core::fmt::$fmt::fmt(self.as_bitslice(), fmt)
+			}
+		}
+	)+ };
+	($(impl $fmt:ident)+ for $this:ident<A, O>) => { $(
+		impl<A, O> core::fmt::$fmt for $this<A, O>
+		where
+			O: $crate::order::BitOrder,
+			A: $crate::view::BitViewSized,
+		{
+			#[inline]
+			#[cfg(not(tarpaulin_include))]
+			fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+				core::fmt::$fmt::fmt(self.as_bitslice(), fmt)
+			}
+		}
+	)+ };
 	($(impl $fmt:ident)+ for $this:ident) => { $(
 		impl<T, O> core::fmt::$fmt for $this<T, O>
 		where
@@ -58,6 +110,43 @@ macro_rules! easy_iter {
 	};
 }
 
+/// Names a bit-vector type, or one of its borrowing iterators, in an
+/// allocator.
+///
+/// These types only take an allocator type parameter when the `allocator`
+/// feature is enabled. Implementations that work in every allocator declare the
+/// parameter as `#[cfg(feature = "allocator")] A: Allocator`, and name the type
+/// through this macro, which drops the allocator when the feature is disabled.
+#[cfg(feature = "allocator")]
+macro_rules! in_alloc {
+	(BitVec<$t:ty, $o:ty>, $a:ty) => {
+		$crate::vec::BitVec<$t, $o, $a>
+	};
+	(Drain<$l:lifetime, $t:ty, $o:ty>, $a:ty) => {
+		$crate::vec::Drain<$l, $t, $o, $a>
+	};
+	(Splice<$l:lifetime, $t:ty, $o:ty, $i:ty>, $a:ty) => {
+		$crate::vec::Splice<$l, $t, $o, $i, $a>
+	};
+}
+
+/// Names a bit-vector type, or one of its borrowing iterators, in an
+/// allocator.
+///
+/// The `allocator` feature is disabled, so the allocator is dropped.
+#[cfg(all(feature = "alloc", not(feature = "allocator")))]
+macro_rules! in_alloc {
+	(BitVec<$t:ty, $o:ty>, $a:ty) => {
+		$crate::vec::BitVec<$t, $o>
+	};
+	(Drain<$l:lifetime, $t:ty, $o:ty>, $a:ty) => {
+		$crate::vec::Drain<$l, $t, $o>
+	};
+	(Splice<$l:lifetime, $t:ty, $o:ty, $i:ty>, $a:ty) => {
+		$crate::vec::Splice<$l, $t, $o, $i>
+	};
+}
+
 /// Tests if two `BitOrder` implementors are the same.
 #[inline]
 pub fn match_order<O, P>() -> bool
@@ -90,6 +179,42 @@ where
 	match_order::<O1, O2>() && match_store::<T1, T2>()
 }
 
+/// Tests if a `BitOrder` implementor is one of the orderings that `BitSlice`
+/// specializes with batched element access.
+#[inline]
+pub fn is_specialized_order<O>() -> bool
+where O: BitOrder {
+	match_order::<O, Lsb0>() || match_order::<O, Msb0>()
+}
+
+/// Tests if a `BitOrder` implementor can load and store a processor word at a
+/// time through `BitSlice::load_stream` and `BitSlice::store_word`.
+///
+/// This is true for the specialized orderings and for their byte- and
+/// nibble-swapped variants.
+#[inline]
+pub fn has_word_access<O>() -> bool
+where O: BitOrder {
+	is_specialized_order::<O>()
+		|| match_order::<O, Lsb0ByteSwap>()
+		|| match_order::<O, Msb0ByteSwap>()
+		|| match_order::<O, Lsb0NibbleSwap>()
+		|| match_order::<O, Msb0NibbleSwap>()
+}
+
+/// Reverses the order of the bits within each element of a buffer.
+///
+/// This converts a buffer between the `Lsb0` and `Msb0` orderings while
+/// preserving the sequence of bits that each index selects.
+#[inline]
+#[cfg(feature = "alloc")]
+pub fn reverse_element_bits<T>(elems: &mut [T])
+where T: BitStore {
+	for elem in elems {
+		elem.store_value(elem.load_value().reverse_bits());
+	}
+}
+
 /// Tests if a type is known to be an unsigned integer.
 ///
 /// Returns `true` for `u{8,16,32,64,128,size}` and `false` for all others.
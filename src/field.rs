@@ -12,6 +12,10 @@ use wyz::comu::{
 	Mut,
 };
 
+#[cfg(feature = "alloc")]
+use crate::boxed::BitBox;
+#[cfg(feature = "allocator")]
+use crate::vec::Allocator;
 use crate::{
 	array::BitArray,
 	devel as dvl,
@@ -29,14 +33,27 @@ use crate::{
 	store::BitStore,
 	view::BitViewSized,
 };
-#[cfg(feature = "alloc")]
-use crate::{
-	boxed::BitBox,
-	vec::BitVec,
-};
 
+mod checked;
+mod convert;
 mod io;
+mod swapped;
 mod tests;
+mod wide;
+
+#[cfg(feature = "std")]
+pub use self::io::{
+	BitReader,
+	BitWriter,
+};
+pub use self::{
+	checked::BitFieldError,
+	convert::{
+		FromBits,
+		IntoBits,
+		InvalidBits,
+	},
+};
 
 #[doc = include_str!("../doc/field/BitField.md")]
 pub trait BitField {
@@ -107,6 +124,128 @@ pub trait BitField {
 	#[doc = include_str!("../doc/field/BitField_store_be.md")]
 	fn store_be<I>(&mut self, value: I)
 	where I: Integral;
+
+	/// Loads a value of any [`FromBits`] type out of the bit-slice.
+	///
+	/// The bit-slice is loaded as `V::Bits` with [`.load()`], and then
+	/// converted into `V`. This can be used to load `bool`s, `char`s,
+	/// floating-point numbers, and user types such as fieldless enums.
+	///
+	/// ## Type Parameters
+	///
+	/// - `V`: The type being loaded.
+	///
+	/// ## Returns
+	///
+	/// The loaded value, or the conversion error if the loaded bit-pattern is
+	/// not a valid `V`.
+	///
+	/// ## Panics
+	///
+	/// This panics under the same conditions as [`.load()`].
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![u8, Msb0; 0, 1, 0];
+	/// assert_eq!(bits[1 .. 2].load_as::<bool>(), Ok(true));
+	/// assert!(bits[.. 2].load_as::<bool>().is_ok());
+	/// assert!(bits[1 ..].load_as::<bool>().is_err());
+	/// ```
+	///
+	/// [`FromBits`]: crate::field::FromBits
+	/// [`.load()`]: Self::load
+	#[inline]
+	fn load_as<V>(&self) -> Result<V, V::Error>
+	where V: FromBits {
+		V::from_bits(self.load::<V::Bits>())
+	}
+
+	/// Loads a value of any [`FromBits`] type out of the bit-slice, using
+	/// little-endian element ordering.
+	///
+	/// This is [`.load_as()`] built on [`.load_le()`].
+	///
+	/// [`FromBits`]: crate::field::FromBits
+	/// [`.load_as()`]: Self::load_as
+	/// [`.load_le()`]: Self::load_le
+	#[inline]
+	fn load_le_as<V>(&self) -> Result<V, V::Error>
+	where V: FromBits {
+		V::from_bits(self.load_le::<V::Bits>())
+	}
+
+	/// Loads a value of any [`FromBits`] type out of the bit-slice, using
+	/// big-endian element ordering.
+	///
+	/// This is [`.load_as()`] built on [`.load_be()`].
+	///
+	/// [`FromBits`]: crate::field::FromBits
+	/// [`.load_as()`]: Self::load_as
+	/// [`.load_be()`]: Self::load_be
+	#[inline]
+	fn load_be_as<V>(&self) -> Result<V, V::Error>
+	where V: FromBits {
+		V::from_bits(self.load_be::<V::Bits>())
+	}
+
+	/// Stores a value of any [`IntoBits`] type into the bit-slice.
+	///
+	/// The value is converted into `V::Bits`, and then stored with
+	/// [`.store()`]. As with that method, bits of the converted integer that do
+	/// not fit in the bit-slice are discarded.
+	///
+	/// ## Panics
+	///
+	/// This panics under the same conditions as [`.store()`].
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bits = bitarr![u32, Lsb0; 0; 64];
+	/// bits[16 .. 48].store_as(1.5f32);
+	/// assert_eq!(bits[16 .. 48].load_as::<f32>(), Ok(1.5));
+	/// ```
+	///
+	/// [`IntoBits`]: crate::field::IntoBits
+	/// [`.store()`]: Self::store
+	#[inline]
+	fn store_as<V>(&mut self, value: V)
+	where V: IntoBits {
+		self.store::<V::Bits>(value.into_bits());
+	}
+
+	/// Stores a value of any [`IntoBits`] type into the bit-slice, using
+	/// little-endian element ordering.
+	///
+	/// This is [`.store_as()`] built on [`.store_le()`].
+	///
+	/// [`IntoBits`]: crate::field::IntoBits
+	/// [`.store_as()`]: Self::store_as
+	/// [`.store_le()`]: Self::store_le
+	#[inline]
+	fn store_le_as<V>(&mut self, value: V)
+	where V: IntoBits {
+		self.store_le::<V::Bits>(value.into_bits());
+	}
+
+	/// Stores a value of any [`IntoBits`] type into the bit-slice, using
+	/// big-endian element ordering.
+	///
+	/// This is [`.store_as()`] built on [`.store_be()`].
+	///
+	/// [`IntoBits`]: crate::field::IntoBits
+	/// [`.store_as()`]: Self::store_as
+	/// [`.store_be()`]: Self::store_be
+	#[inline]
+	fn store_be_as<V>(&mut self, value: V)
+	where V: IntoBits {
+		self.store_be::<V::Bits>(value.into_bits());
+	}
 }
 
 #[doc = include_str!("../doc/field/BitField_Lsb0.md")]
@@ -475,7 +614,8 @@ where
 
 #[cfg(feature = "alloc")]
 #[cfg(not(tarpaulin_include))]
-impl<T, O> BitField for BitVec<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator> BitField
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
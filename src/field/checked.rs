@@ -0,0 +1,316 @@
+#![doc = include_str!("../../doc/field/checked.md")]
+
+use core::fmt::{
+	self,
+	Display,
+	Formatter,
+};
+
+use funty::Integral;
+
+use super::BitField;
+use crate::{
+	mem::bits_of,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+/// Fallible bit-field transfers.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	Self: BitField,
+{
+	/// Loads an integer out of the bit-slice, or reports why it cannot.
+	///
+	/// This is the fallible version of [`.load()`]: rather than panicking when
+	/// the bit-slice is empty or wider than `I`, it returns an error.
+	///
+	/// ## Type Parameters
+	///
+	/// - `I`: The integer type being loaded.
+	///
+	/// ## Returns
+	///
+	/// The loaded integer, or an error if `self.len()` is not in the range
+	/// `1 ..= I::BITS`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::BitFieldError;
+	///
+	/// let bits = bits![u8, Msb0; 1; 12];
+	/// assert_eq!(bits[.. 4].try_load::<u8>(), Ok(0x0F));
+	/// assert_eq!(
+	///   bits.try_load::<u8>(),
+	///   Err(BitFieldError::TooWide { len: 12, bits: 8 }),
+	/// );
+	/// assert_eq!(bits[.. 0].try_load::<u8>(), Err(BitFieldError::Empty));
+	/// ```
+	///
+	/// [`.load()`]: crate::field::BitField::load
+	#[inline]
+	pub fn try_load<I>(&self) -> Result<I, BitFieldError>
+	where I: Integral {
+		check_len::<I>(self.len())?;
+		Ok(self.load::<I>())
+	}
+
+	/// Loads an integer out of the bit-slice with little-endian element
+	/// ordering, or reports why it cannot.
+	///
+	/// This is the fallible version of [`.load_le()`].
+	///
+	/// [`.load_le()`]: crate::field::BitField::load_le
+	#[inline]
+	pub fn try_load_le<I>(&self) -> Result<I, BitFieldError>
+	where I: Integral {
+		check_len::<I>(self.len())?;
+		Ok(self.load_le::<I>())
+	}
+
+	/// Loads an integer out of the bit-slice with big-endian element ordering,
+	/// or reports why it cannot.
+	///
+	/// This is the fallible version of [`.load_be()`].
+	///
+	/// [`.load_be()`]: crate::field::BitField::load_be
+	#[inline]
+	pub fn try_load_be<I>(&self) -> Result<I, BitFieldError>
+	where I: Integral {
+		check_len::<I>(self.len())?;
+		Ok(self.load_be::<I>())
+	}
+
+	/// Stores an integer into the bit-slice, or reports why it cannot.
+	///
+	/// This is the fallible version of [`.store()`]. Like that method, it
+	/// truncates `value` to the width of the bit-slice; use
+	/// [`.store_checked()`] to reject values that do not fit.
+	///
+	/// ## Type Parameters
+	///
+	/// - `I`: The integer type being stored.
+	///
+	/// ## Parameters
+	///
+	/// - `&mut self`: A bit-slice region, whose length should be in the range
+	///   `1 ..= I::BITS`.
+	/// - `value`: An integer whose `self.len()` least significant bits will be
+	///   written into `self`.
+	///
+	/// ## Returns
+	///
+	/// An error, leaving the bit-slice unchanged, if `self.len()` is not in the
+	/// range `1 ..= I::BITS`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::BitFieldError;
+	///
+	/// let bits = bits![mut u8, Msb0; 0; 12];
+	/// assert!(bits[.. 4].try_store(0x1Fu8).is_ok());
+	/// assert_eq!(bits[.. 4].load::<u8>(), 0x0F);
+	/// assert_eq!(
+	///   bits.try_store(0u8),
+	///   Err(BitFieldError::TooWide { len: 12, bits: 8 }),
+	/// );
+	/// ```
+	///
+	/// [`.store()`]: crate::field::BitField::store
+	/// [`.store_checked()`]: Self::store_checked
+	#[inline]
+	pub fn try_store<I>(&mut self, value: I) -> Result<(), BitFieldError>
+	where I: Integral {
+		check_len::<I>(self.len())?;
+		self.store::<I>(value);
+		Ok(())
+	}
+
+	/// Stores an integer into the bit-slice with little-endian element
+	/// ordering, or reports why it cannot.
+	///
+	/// This is the fallible version of [`.store_le()`].
+	///
+	/// [`.store_le()`]: crate::field::BitField::store_le
+	#[inline]
+	pub fn try_store_le<I>(&mut self, value: I) -> Result<(), BitFieldError>
+	where I: Integral {
+		check_len::<I>(self.len())?;
+		self.store_le::<I>(value);
+		Ok(())
+	}
+
+	/// Stores an integer into the bit-slice with big-endian element ordering,
+	/// or reports why it cannot.
+	///
+	/// This is the fallible version of [`.store_be()`].
+	///
+	/// [`.store_be()`]: crate::field::BitField::store_be
+	#[inline]
+	pub fn try_store_be<I>(&mut self, value: I) -> Result<(), BitFieldError>
+	where I: Integral {
+		check_len::<I>(self.len())?;
+		self.store_be::<I>(value);
+		Ok(())
+	}
+
+	/// Stores an integer into the bit-slice only if it can be loaded back out
+	/// unchanged.
+	///
+	/// In addition to the length checks of [`.try_store()`], this rejects
+	/// values whose significant bits exceed the width of the bit-slice, rather
+	/// than silently truncating them. Unsigned values must have no set bits at
+	/// or above `self.len()`; signed values must be representable as a
+	/// `self.len()`-bit two’s-complement integer.
+	///
+	/// ## Returns
+	///
+	/// An error, leaving the bit-slice unchanged, if the bit-slice has an
+	/// unusable length or `value` does not fit in it.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::BitFieldError;
+	///
+	/// let bits = bits![mut u8, Msb0; 0; 8];
+	///
+	/// assert!(bits[.. 4].store_checked(15u8).is_ok());
+	/// assert_eq!(
+	///   bits[.. 4].store_checked(16u8),
+	///   Err(BitFieldError::ValueTooWide { len: 4 }),
+	/// );
+	/// assert_eq!(bits[.. 4].load::<u8>(), 15);
+	///
+	/// assert!(bits[4 ..].store_checked(-8i8).is_ok());
+	/// assert!(bits[4 ..].store_checked(8i8).is_err());
+	/// assert_eq!(bits[4 ..].load::<i8>(), -8);
+	/// ```
+	///
+	/// [`.try_store()`]: Self::try_store
+	#[inline]
+	pub fn store_checked<I>(&mut self, value: I) -> Result<(), BitFieldError>
+	where I: Integral {
+		check_value(self.len(), value)?;
+		self.store::<I>(value);
+		Ok(())
+	}
+
+	/// Stores an integer into the bit-slice with little-endian element
+	/// ordering, only if it can be loaded back out unchanged.
+	///
+	/// See [`.store_checked()`] for the conditions that are checked.
+	///
+	/// [`.store_checked()`]: Self::store_checked
+	#[inline]
+	pub fn store_checked_le<I>(
+		&mut self,
+		value: I,
+	) -> Result<(), BitFieldError>
+	where
+		I: Integral,
+	{
+		check_value(self.len(), value)?;
+		self.store_le::<I>(value);
+		Ok(())
+	}
+
+	/// Stores an integer into the bit-slice with big-endian element ordering,
+	/// only if it can be loaded back out unchanged.
+	///
+	/// See [`.store_checked()`] for the conditions that are checked.
+	///
+	/// [`.store_checked()`]: Self::store_checked
+	#[inline]
+	pub fn store_checked_be<I>(
+		&mut self,
+		value: I,
+	) -> Result<(), BitFieldError>
+	where
+		I: Integral,
+	{
+		check_value(self.len(), value)?;
+		self.store_be::<I>(value);
+		Ok(())
+	}
+}
+
+/// Tests that a bit-slice length can transfer an `I` integer.
+///
+/// This is the fallible counterpart to `field::check`.
+fn check_len<I>(len: usize) -> Result<(), BitFieldError>
+where I: Integral {
+	if len == 0 {
+		return Err(BitFieldError::Empty);
+	}
+	let bits = bits_of::<I>();
+	if len > bits {
+		return Err(BitFieldError::TooWide { len, bits });
+	}
+	Ok(())
+}
+
+/// Tests that `value` survives a round trip through a `len`-bit region.
+///
+/// Shifting the value up so that its `len`th bit is in the sign position, and
+/// then back down, discards its excess high bits and zero- or sign-extends the
+/// remainder exactly as a load would. The value fits if this does not change
+/// it.
+fn check_value<I>(len: usize, value: I) -> Result<(), BitFieldError>
+where I: Integral {
+	check_len::<I>(len)?;
+	let shamt = bits_of::<I>() - len;
+	if (value << shamt) >> shamt != value {
+		return Err(BitFieldError::ValueTooWide { len });
+	}
+	Ok(())
+}
+
+#[doc = include_str!("../../doc/field/BitFieldError.md")]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BitFieldError {
+	/// The bit-slice has no bits to transfer.
+	Empty,
+	/// The bit-slice is wider than the integer being transferred.
+	TooWide {
+		/// The length of the bit-slice.
+		len:  usize,
+		/// The width of the integer type.
+		bits: usize,
+	},
+	/// The value has more significant bits than the bit-slice can hold.
+	ValueTooWide {
+		/// The length of the bit-slice.
+		len: usize,
+	},
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Display for BitFieldError {
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		match *self {
+			Self::Empty => fmt
+				.write_str("cannot transfer an integer through an empty region"),
+			Self::TooWide { len, bits } => write!(
+				fmt,
+				"cannot transfer a {}-bit region through a {}-bit integer",
+				len, bits,
+			),
+			Self::ValueTooWide { len } => {
+				write!(fmt, "value does not fit in a {}-bit region", len)
+			},
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BitFieldError {}
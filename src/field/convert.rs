@@ -0,0 +1,297 @@
+#![doc = include_str!("../../doc/field/convert.md")]
+
+use core::{
+	convert::Infallible,
+	fmt::{
+		self,
+		Display,
+		Formatter,
+	},
+	num::{
+		NonZeroI8,
+		NonZeroI16,
+		NonZeroI32,
+		NonZeroI64,
+		NonZeroI128,
+		NonZeroIsize,
+		NonZeroU8,
+		NonZeroU16,
+		NonZeroU32,
+		NonZeroU64,
+		NonZeroU128,
+		NonZeroUsize,
+	},
+};
+
+use funty::Integral;
+
+use crate::mem::bits_of;
+
+#[doc = include_str!("../../doc/field/FromBits.md")]
+pub trait FromBits: Sized {
+	/// The integer type loaded out of a bit-slice.
+	type Bits: Integral;
+
+	/// The error produced when a loaded integer is not a valid value.
+	///
+	/// Types whose conversion cannot fail should use
+	/// [`Infallible`](core::convert::Infallible).
+	type Error;
+
+	/// Converts a loaded integer into a value.
+	fn from_bits(bits: Self::Bits) -> Result<Self, Self::Error>;
+}
+
+/** A type that can be reduced to an integer and stored into a bit-slice.
+
+This is the trait that allows [`BitField::store_as`] and its siblings to accept
+values other than plain integers. The value is converted with [`into_bits`],
+and the produced integer is stored following all of the ordinary [`BitField`]
+rules, including truncation to the width of the bit-slice.
+
+See [`FromBits`] for an example implementation.
+
+[`BitField`]: crate::field::BitField
+[`BitField::store_as`]: crate::field::BitField::store_as
+[`into_bits`]: Self::into_bits
+**/
+pub trait IntoBits {
+	/// The integer type stored into a bit-slice.
+	type Bits: Integral;
+
+	/// Converts a value into the integer that will be stored.
+	fn into_bits(self) -> Self::Bits;
+}
+
+/// Marks a bit-pattern that is not a valid value of the type being loaded.
+///
+/// This is the [`FromBits::Error`] type for the built-in conversions that can
+/// fail, such as `bool`, `char`, and the `NonZero` integers.
+///
+/// ## Examples
+///
+/// ```rust
+/// use bitvec::prelude::*;
+///
+/// let bits = bits![u8, Lsb0; 0, 1];
+/// assert_eq!(bits.load_le_as::<bool>().unwrap_err().bits(), 2);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct InvalidBits<I> {
+	/// The rejected integer.
+	bits: I,
+}
+
+impl<I> InvalidBits<I>
+where I: Integral
+{
+	/// Marks an integer as invalid.
+	#[inline]
+	pub(crate) fn new(bits: I) -> Self {
+		Self { bits }
+	}
+
+	/// Gets the integer that was rejected.
+	#[inline]
+	pub fn bits(&self) -> I {
+		self.bits
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<I> Display for InvalidBits<I>
+where I: Integral
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(
+			fmt,
+			"invalid bit-pattern {:#x} for the loaded type",
+			self.bits
+		)
+	}
+}
+
+#[cfg(feature = "std")]
+impl<I> std::error::Error for InvalidBits<I> where I: Integral {}
+
+/// Implements the conversion traits as the identity on the integers.
+macro_rules! integers {
+	($($int:ty),+ $(,)?) => { $(
+		impl FromBits for $int {
+			type Bits = Self;
+			type Error = Infallible;
+
+			#[inline(always)]
+			fn from_bits(bits: Self) -> Result<Self, Infallible> {
+				Ok(bits)
+			}
+		}
+
+		impl IntoBits for $int {
+			type Bits = Self;
+
+			#[inline(always)]
+			fn into_bits(self) -> Self {
+				self
+			}
+		}
+	)+ };
+}
+
+integers!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Implements the conversion traits for the `NonZero` integers, which reject
+/// zero.
+macro_rules! nonzero {
+	($($nz:ty => $int:ty),+ $(,)?) => { $(
+		impl FromBits for $nz {
+			type Bits = $int;
+			type Error = InvalidBits<$int>;
+
+			#[inline]
+			fn from_bits(bits: $int) -> Result<Self, Self::Error> {
+				Self::new(bits).ok_or_else(|| InvalidBits::new(bits))
+			}
+		}
+
+		impl IntoBits for $nz {
+			type Bits = $int;
+
+			#[inline]
+			fn into_bits(self) -> $int {
+				self.get()
+			}
+		}
+	)+ };
+}
+
+nonzero!(
+	NonZeroU8 => u8,
+	NonZeroU16 => u16,
+	NonZeroU32 => u32,
+	NonZeroU64 => u64,
+	NonZeroU128 => u128,
+	NonZeroUsize => usize,
+	NonZeroI8 => i8,
+	NonZeroI16 => i16,
+	NonZeroI32 => i32,
+	NonZeroI64 => i64,
+	NonZeroI128 => i128,
+	NonZeroIsize => isize,
+);
+
+/// Floating-point numbers are transferred as their raw IEEE-754 bit-patterns.
+macro_rules! floats {
+	($($float:ty => $int:ty),+ $(,)?) => { $(
+		impl FromBits for $float {
+			type Bits = $int;
+			type Error = Infallible;
+
+			#[inline]
+			fn from_bits(bits: $int) -> Result<Self, Infallible> {
+				Ok(Self::from_bits(bits))
+			}
+		}
+
+		impl IntoBits for $float {
+			type Bits = $int;
+
+			#[inline]
+			fn into_bits(self) -> $int {
+				self.to_bits()
+			}
+		}
+	)+ };
+}
+
+floats!(f32 => u32, f64 => u64);
+
+/// Booleans are stored as `0` or `1`; any other loaded value is rejected.
+impl FromBits for bool {
+	type Bits = u8;
+	type Error = InvalidBits<u8>;
+
+	#[inline]
+	fn from_bits(bits: u8) -> Result<Self, Self::Error> {
+		match bits {
+			0 => Ok(false),
+			1 => Ok(true),
+			_ => Err(InvalidBits::new(bits)),
+		}
+	}
+}
+
+impl IntoBits for bool {
+	type Bits = u8;
+
+	#[inline]
+	fn into_bits(self) -> u8 {
+		self as u8
+	}
+}
+
+/// Characters are transferred as their Unicode scalar values; surrogates and
+/// values above `char::MAX` are rejected.
+impl FromBits for char {
+	type Bits = u32;
+	type Error = InvalidBits<u32>;
+
+	#[inline]
+	fn from_bits(bits: u32) -> Result<Self, Self::Error> {
+		Self::from_u32(bits).ok_or_else(|| InvalidBits::new(bits))
+	}
+}
+
+impl IntoBits for char {
+	type Bits = u32;
+
+	#[inline]
+	fn into_bits(self) -> u32 {
+		self as u32
+	}
+}
+
+/// Arrays of unsigned integers are transferred as one wider integer, with the
+/// element at index 0 in its least significant bits.
+macro_rules! arrays {
+	($($elem:ty; $n:literal => $int:ty),+ $(,)?) => { $(
+		impl FromBits for [$elem; $n] {
+			type Bits = $int;
+			type Error = Infallible;
+
+			#[inline]
+			fn from_bits(bits: $int) -> Result<Self, Infallible> {
+				let mut out = [0; $n];
+				for (idx, slot) in out.iter_mut().enumerate() {
+					*slot = (bits >> (idx * bits_of::<$elem>())) as $elem;
+				}
+				Ok(out)
+			}
+		}
+
+		impl IntoBits for [$elem; $n] {
+			type Bits = $int;
+
+			#[inline]
+			fn into_bits(self) -> $int {
+				self.iter().rev().fold(0, |accum, &elem| {
+					accum << bits_of::<$elem>() | elem as $int
+				})
+			}
+		}
+	)+ };
+}
+
+arrays!(
+	u8; 2 => u16,
+	u8; 4 => u32,
+	u8; 8 => u64,
+	u8; 16 => u128,
+	u16; 2 => u32,
+	u16; 4 => u64,
+	u16; 8 => u128,
+	u32; 2 => u64,
+	u32; 4 => u128,
+	u64; 2 => u128,
+);
@@ -8,10 +8,20 @@ use std::io::{
 	Write,
 };
 
-use super::BitField;
+use funty::Integral;
+
+use super::{
+	BitField,
+	check,
+};
+#[cfg(feature = "allocator")]
+use crate::vec::Allocator;
 use crate::{
 	mem::bits_of,
-	order::BitOrder,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
 	slice::BitSlice,
 	store::BitStore,
 	vec::BitVec,
@@ -68,7 +78,8 @@ where
 }
 
 #[doc = include_str!("../../doc/field/io/Read_BitVec.md")]
-impl<T, O> Read for BitVec<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator> Read
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -85,7 +96,8 @@ where
 }
 
 #[doc = include_str!("../../doc/field/io/Write_BitVec.md")]
-impl<T, O> Write for BitVec<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator> Write
+	for in_alloc!(BitVec<T, O>, A)
 where
 	O: BitOrder,
 	T: BitStore,
@@ -104,3 +116,428 @@ where
 		Ok(())
 	}
 }
+
+#[doc = include_str!("../../doc/field/io/BitReader.md")]
+pub struct BitReader<R, O = Lsb0>
+where
+	R: Read,
+	O: BitOrder,
+{
+	/// The byte source.
+	inner: R,
+	/// Bits pulled from the source that have not yet been consumed.
+	buf:   BitVec<u8, O>,
+}
+
+impl<R, O> BitReader<R, O>
+where
+	R: Read,
+	O: BitOrder,
+	BitSlice<u8, O>: BitField,
+{
+	/// Creates a bit-reader over a byte source.
+	#[inline]
+	pub fn new(inner: R) -> Self {
+		Self {
+			inner,
+			buf: BitVec::new(),
+		}
+	}
+
+	/// Gets a reference to the byte source.
+	#[inline]
+	pub fn get_ref(&self) -> &R {
+		&self.inner
+	}
+
+	/// Gets a mutable reference to the byte source.
+	///
+	/// Reading directly from the source skips over any bits that the
+	/// bit-reader has buffered but not yet produced.
+	#[inline]
+	pub fn get_mut(&mut self) -> &mut R {
+		&mut self.inner
+	}
+
+	/// Unwraps the bit-reader, returning the byte source.
+	///
+	/// Any bits that have been pulled from the source but not yet produced are
+	/// discarded. The reader only ever buffers part of one byte between calls,
+	/// so [`.align_to_byte()`] first to discard them deliberately.
+	///
+	/// [`.align_to_byte()`]: Self::align_to_byte
+	#[inline]
+	pub fn into_inner(self) -> R {
+		self.inner
+	}
+
+	/// Reads one bit from the stream.
+	#[inline]
+	pub fn read_bit(&mut self) -> io::Result<bool> {
+		self.fill(1)?;
+		let bit = self.buf[0];
+		self.consume(1);
+		Ok(bit)
+	}
+
+	/// Reads some number of bits from the stream into a new bit-vector.
+	///
+	/// ## Parameters
+	///
+	/// - `&mut self`
+	/// - `count`: The number of bits to read.
+	///
+	/// ## Returns
+	///
+	/// A bit-vector of exactly `count` bits, in stream order.
+	#[inline]
+	pub fn read_bits(&mut self, count: usize) -> io::Result<BitVec<u8, O>> {
+		self.fill(count)?;
+		let bits = self.buf[.. count].to_bitvec();
+		self.consume(count);
+		Ok(bits)
+	}
+
+	/// Reads an integer from the next `width` bits of the stream, using the
+	/// target’s native endianness.
+	///
+	/// ## Panics
+	///
+	/// This panics if `width` is `0` or wider than `I`, before reading from the
+	/// source.
+	///
+	/// ## Original
+	///
+	/// [`BitField::load`](crate::field::BitField::load)
+	#[inline]
+	pub fn read<I>(&mut self, width: usize) -> io::Result<I>
+	where I: Integral {
+		self.read_int(width, <BitSlice<u8, O> as BitField>::load::<I>)
+	}
+
+	/// Reads an integer from the next `width` bits of the stream, using
+	/// little-endian element ordering.
+	///
+	/// ## Panics
+	///
+	/// This panics if `width` is `0` or wider than `I`, before reading from the
+	/// source.
+	///
+	/// ## Original
+	///
+	/// [`BitField::load_le`](crate::field::BitField::load_le)
+	#[inline]
+	pub fn read_le<I>(&mut self, width: usize) -> io::Result<I>
+	where I: Integral {
+		self.read_int(width, <BitSlice<u8, O> as BitField>::load_le::<I>)
+	}
+
+	/// Reads an integer from the next `width` bits of the stream, using
+	/// big-endian element ordering.
+	///
+	/// ## Panics
+	///
+	/// This panics if `width` is `0` or wider than `I`, before reading from the
+	/// source.
+	///
+	/// ## Original
+	///
+	/// [`BitField::load_be`](crate::field::BitField::load_be)
+	#[inline]
+	pub fn read_be<I>(&mut self, width: usize) -> io::Result<I>
+	where I: Integral {
+		self.read_int(width, <BitSlice<u8, O> as BitField>::load_be::<I>)
+	}
+
+	/// Discards some number of bits from the stream.
+	#[inline]
+	pub fn skip(&mut self, count: usize) -> io::Result<()> {
+		self.fill(count)?;
+		self.consume(count);
+		Ok(())
+	}
+
+	/// Discards bits until the stream is at a byte boundary.
+	///
+	/// ## Returns
+	///
+	/// The number of bits discarded. This is always less than eight.
+	#[inline]
+	pub fn align_to_byte(&mut self) -> usize {
+		let count = self.buf.len() % bits_of::<u8>();
+		self.consume(count);
+		count
+	}
+
+	/// Tests whether the stream is at a byte boundary.
+	#[inline]
+	pub fn is_aligned(&self) -> bool {
+		self.buf.len() % bits_of::<u8>() == 0
+	}
+
+	/// Reads an integer out of the stream with a `BitField` load function.
+	#[inline]
+	fn read_int<I>(
+		&mut self,
+		width: usize,
+		load: fn(&BitSlice<u8, O>) -> I,
+	) -> io::Result<I>
+	where
+		I: Integral,
+	{
+		check::<I>("load", width);
+		self.fill(width)?;
+		let value = load(&self.buf[.. width]);
+		self.consume(width);
+		Ok(value)
+	}
+
+	/// Removes bits from the front of the buffer.
+	#[inline]
+	fn consume(&mut self, count: usize) {
+		self.buf.shift_left_shrink(count);
+	}
+
+	/// Pulls whole bytes from the source until at least `count` bits are
+	/// buffered.
+	///
+	/// If the source ends or fails, every byte that it did produce is still
+	/// appended to the buffer.
+	fn fill(&mut self, count: usize) -> io::Result<()> {
+		let have = self.buf.len();
+		if have >= count {
+			return Ok(());
+		}
+		let mut bytes =
+			vec![0u8; (count - have + bits_of::<u8>() - 1) / bits_of::<u8>()];
+		let mut filled = 0;
+		let mut out = Ok(());
+		while filled < bytes.len() {
+			match self.inner.read(&mut bytes[filled ..]) {
+				Ok(0) => {
+					out = Err(io::Error::new(
+						io::ErrorKind::UnexpectedEof,
+						"the byte source ended before enough bits were read",
+					));
+					break;
+				},
+				Ok(n) => filled += n,
+				Err(err) if err.kind() == io::ErrorKind::Interrupted => {},
+				Err(err) => {
+					out = Err(err);
+					break;
+				},
+			}
+		}
+		self.buf.extend_from_raw_slice(&bytes[.. filled]);
+		out
+	}
+}
+
+#[doc = include_str!("../../doc/field/io/BitWriter.md")]
+pub struct BitWriter<W, O = Lsb0>
+where
+	W: Write,
+	O: BitOrder,
+{
+	/// The byte sink.
+	inner: W,
+	/// Bits that do not yet complete a byte.
+	buf:   BitVec<u8, O>,
+	/// The bit used to pad the final partial byte.
+	fill:  bool,
+}
+
+impl<W, O> BitWriter<W, O>
+where
+	W: Write,
+	O: BitOrder,
+	BitSlice<u8, O>: BitField,
+{
+	/// Creates a bit-writer over a byte sink, which pads partial bytes with
+	/// `0`.
+	#[inline]
+	pub fn new(inner: W) -> Self {
+		Self::with_fill(inner, false)
+	}
+
+	/// Creates a bit-writer over a byte sink, which pads partial bytes with
+	/// `fill`.
+	#[inline]
+	pub fn with_fill(inner: W, fill: bool) -> Self {
+		Self {
+			inner,
+			buf: BitVec::new(),
+			fill,
+		}
+	}
+
+	/// Gets a reference to the byte sink.
+	#[inline]
+	pub fn get_ref(&self) -> &W {
+		&self.inner
+	}
+
+	/// Gets a mutable reference to the byte sink.
+	///
+	/// Writing directly to the sink places bytes before any partial byte that
+	/// the bit-writer is still holding.
+	#[inline]
+	pub fn get_mut(&mut self) -> &mut W {
+		&mut self.inner
+	}
+
+	/// Writes one bit to the stream.
+	#[inline]
+	pub fn write_bit(&mut self, bit: bool) -> io::Result<()> {
+		self.buf.push(bit);
+		self.emit()
+	}
+
+	/// Writes the contents of a bit-slice to the stream, in order.
+	#[inline]
+	pub fn write_bits<T, O2>(&mut self, bits: &BitSlice<T, O2>) -> io::Result<()>
+	where
+		T: BitStore,
+		O2: BitOrder,
+	{
+		self.buf.extend_from_bitslice(bits);
+		self.emit()
+	}
+
+	/// Writes the low `width` bits of an integer to the stream, using the
+	/// target’s native endianness.
+	///
+	/// ## Panics
+	///
+	/// This panics if `width` is `0` or wider than `I`, before writing to the
+	/// sink.
+	///
+	/// ## Original
+	///
+	/// [`BitField::store`](crate::field::BitField::store)
+	#[inline]
+	pub fn write<I>(&mut self, width: usize, value: I) -> io::Result<()>
+	where I: Integral {
+		self.write_int(width, value, <BitSlice<u8, O> as BitField>::store::<I>)
+	}
+
+	/// Writes the low `width` bits of an integer to the stream, using
+	/// little-endian element ordering.
+	///
+	/// ## Panics
+	///
+	/// This panics if `width` is `0` or wider than `I`, before writing to the
+	/// sink.
+	///
+	/// ## Original
+	///
+	/// [`BitField::store_le`](crate::field::BitField::store_le)
+	#[inline]
+	pub fn write_le<I>(&mut self, width: usize, value: I) -> io::Result<()>
+	where I: Integral {
+		self.write_int(
+			width,
+			value,
+			<BitSlice<u8, O> as BitField>::store_le::<I>,
+		)
+	}
+
+	/// Writes the low `width` bits of an integer to the stream, using
+	/// big-endian element ordering.
+	///
+	/// ## Panics
+	///
+	/// This panics if `width` is `0` or wider than `I`, before writing to the
+	/// sink.
+	///
+	/// ## Original
+	///
+	/// [`BitField::store_be`](crate::field::BitField::store_be)
+	#[inline]
+	pub fn write_be<I>(&mut self, width: usize, value: I) -> io::Result<()>
+	where I: Integral {
+		self.write_int(
+			width,
+			value,
+			<BitSlice<u8, O> as BitField>::store_be::<I>,
+		)
+	}
+
+	/// Pads the stream with the fill bit until it is at a byte boundary, and
+	/// writes the completed byte to the sink.
+	///
+	/// ## Returns
+	///
+	/// The number of fill bits written. This is always less than eight.
+	#[inline]
+	pub fn align_to_byte(&mut self) -> io::Result<usize> {
+		let rem = self.buf.len() % bits_of::<u8>();
+		if rem == 0 {
+			return Ok(0);
+		}
+		let count = bits_of::<u8>() - rem;
+		self.buf.resize(self.buf.len() + count, self.fill);
+		self.emit()?;
+		Ok(count)
+	}
+
+	/// Tests whether the stream is at a byte boundary.
+	#[inline]
+	pub fn is_aligned(&self) -> bool {
+		self.buf.is_empty()
+	}
+
+	/// Flushes the byte sink.
+	///
+	/// This does not write a partial byte, as the sink can only accept whole
+	/// bytes. Use [`.align_to_byte()`] or [`.finish()`] to pad it out.
+	///
+	/// [`.align_to_byte()`]: Self::align_to_byte
+	/// [`.finish()`]: Self::finish
+	#[inline]
+	pub fn flush(&mut self) -> io::Result<()> {
+		self.inner.flush()
+	}
+
+	/// Completes the stream, returning the byte sink.
+	///
+	/// This pads any partial byte with the fill bit, writes it, and flushes the
+	/// sink.
+	#[inline]
+	pub fn finish(mut self) -> io::Result<W> {
+		self.align_to_byte()?;
+		self.inner.flush()?;
+		Ok(self.inner)
+	}
+
+	/// Writes an integer into the stream with a `BitField` store function.
+	#[inline]
+	fn write_int<I>(
+		&mut self,
+		width: usize,
+		value: I,
+		store: fn(&mut BitSlice<u8, O>, I),
+	) -> io::Result<()>
+	where
+		I: Integral,
+	{
+		check::<I>("store", width);
+		let len = self.buf.len();
+		self.buf.resize(len + width, false);
+		store(&mut self.buf[len ..], value);
+		self.emit()
+	}
+
+	/// Writes every completed byte in the buffer to the sink.
+	#[inline]
+	fn emit(&mut self) -> io::Result<()> {
+		let bytes = self.buf.len() / bits_of::<u8>();
+		if bytes == 0 {
+			return Ok(());
+		}
+		self.inner.write_all(&self.buf.as_raw_slice()[.. bytes])?;
+		self.buf.shift_left_shrink(bytes * bits_of::<u8>());
+		Ok(())
+	}
+}
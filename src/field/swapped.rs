@@ -0,0 +1,162 @@
+#![doc = include_str!("../../doc/field/swapped.md")]
+
+use funty::Integral;
+
+use super::{
+	check,
+	BitField,
+};
+use crate::{
+	domain::Domain,
+	mem::bits_of,
+	order::{
+		self,
+		BitOrder,
+		Lsb0,
+		Lsb0ByteSwap,
+		Lsb0NibbleSwap,
+		Msb0,
+		Msb0ByteSwap,
+		Msb0NibbleSwap,
+	},
+	slice::BitSlice,
+	store::BitStore,
+};
+
+/// The most elements that a transfer can touch: a `u128` that begins at the
+/// last bit of a byte.
+const MAX_ELTS: usize = bits_of::<u128>() / 8 + 1;
+
+/// A buffer holding the elements a transfer touches, in the base layout.
+type Buffer<T> = [<T as BitStore>::Mem; MAX_ELTS];
+
+/// Implements `BitField` for a swapped ordering by forwarding to its base.
+macro_rules! swapped {
+	($($order:ty => $base:ty, $swap:path);+ $(;)?) => { $(
+		impl<T> BitField for BitSlice<T, $order>
+		where T: BitStore
+		{
+			#[inline]
+			fn load_le<I>(&self) -> I
+			where I: Integral {
+				check::<I>("load", self.len());
+				let (buf, head) = gather(self, $swap);
+				view::<T, $base>(&buf, head, self.len()).load_le()
+			}
+
+			#[inline]
+			fn load_be<I>(&self) -> I
+			where I: Integral {
+				check::<I>("load", self.len());
+				let (buf, head) = gather(self, $swap);
+				view::<T, $base>(&buf, head, self.len()).load_be()
+			}
+
+			#[inline]
+			fn store_le<I>(&mut self, value: I)
+			where I: Integral {
+				check::<I>("store", self.len());
+				let (mut buf, head) = gather(self, $swap);
+				view_mut::<T, $base>(&mut buf, head, self.len()).store_le(value);
+				scatter(self, &buf, $swap);
+			}
+
+			#[inline]
+			fn store_be<I>(&mut self, value: I)
+			where I: Integral {
+				check::<I>("store", self.len());
+				let (mut buf, head) = gather(self, $swap);
+				view_mut::<T, $base>(&mut buf, head, self.len()).store_be(value);
+				scatter(self, &buf, $swap);
+			}
+		}
+	)+ };
+}
+
+swapped! {
+	Lsb0ByteSwap => Lsb0, order::swap_bytes;
+	Msb0ByteSwap => Msb0, order::swap_bytes;
+	Lsb0NibbleSwap => Lsb0, order::swap_nibbles;
+	Msb0NibbleSwap => Msb0, order::swap_nibbles;
+}
+
+/// Copies the live bits of a bit-slice into a buffer in the base layout.
+///
+/// ## Parameters
+///
+/// - `bits`: A bit-slice no wider than a `u128`.
+/// - `swap`: The rearrangement between the bit-slice’s ordering and its base.
+///
+/// ## Returns
+///
+/// The buffer, whose leading elements hold the bit-slice’s elements with all
+/// dead bits cleared, and the index of the bit-slice’s first bit within the
+/// first element.
+fn gather<T, O>(
+	bits: &BitSlice<T, O>,
+	swap: fn(T::Mem) -> T::Mem,
+) -> (Buffer<T>, usize)
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	let mut buf = [<T::Mem as Integral>::ZERO; MAX_ELTS];
+	for (slot, elem) in buf.iter_mut().zip(bits.domain()) {
+		*slot = swap(elem);
+	}
+	(buf, bits.as_bitspan().head().into_inner() as usize)
+}
+
+/// Writes the leading elements of a buffer back into a bit-slice.
+///
+/// Each element is rearranged out of the base layout, and only the bits that
+/// `bits` governs are modified.
+fn scatter<T, O>(
+	bits: &mut BitSlice<T, O>,
+	buf: &Buffer<T>,
+	swap: fn(T::Mem) -> T::Mem,
+) where
+	T: BitStore,
+	O: BitOrder,
+{
+	let mut elems = buf.iter().copied().map(swap);
+	match bits.domain_mut() {
+		Domain::Enclave(mut elem) => {
+			elem.store_value(elems.next().unwrap_or_default());
+		},
+		Domain::Region { head, body, tail } => {
+			if let Some(mut elem) = head {
+				elem.store_value(elems.next().unwrap_or_default());
+			}
+			for (slot, elem) in body.iter_mut().zip(&mut elems) {
+				slot.store_value(elem);
+			}
+			if let Some(mut elem) = tail {
+				elem.store_value(elems.next().unwrap_or_default());
+			}
+		},
+	}
+}
+
+/// Views the span of a buffer that corresponds to the gathered bit-slice.
+fn view<T, O>(buf: &Buffer<T>, head: usize, len: usize) -> &BitSlice<T::Mem, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	&BitSlice::from_slice(buf)[head .. head + len]
+}
+
+/// Mutably views the span of a buffer that corresponds to the gathered
+/// bit-slice.
+fn view_mut<T, O>(
+	buf: &mut Buffer<T>,
+	head: usize,
+	len: usize,
+) -> &mut BitSlice<T::Mem, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	&mut BitSlice::from_slice_mut(buf)[head .. head + len]
+}
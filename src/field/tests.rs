@@ -257,6 +257,287 @@ fn msb0_u8_be_u24() {
 	);
 }
 
+#[test]
+fn lsb0_u8_le_u128() {
+	let mut bits = BitArray::<[u8; 16], Lsb0>::ZERO;
+
+	let val = random::<u128>();
+	bits.store_le(val);
+	assert_eq!(bits.as_raw_slice(), &val.to_le_bytes());
+	assert_eq!(bits.load_le::<u128>(), val);
+
+	let bits = &mut bits[4 .. 124];
+	let val = val >> 8 | 1 << 119;
+	bits.store_le(val);
+	assert_eq!(bits.load_le::<u128>(), val);
+	assert_eq!(bits.load_le::<i128>(), (val << 8) as i128 >> 8);
+}
+
+#[test]
+fn msb0_u64_be_u128() {
+	let mut bits = BitArray::<[u64; 3], Msb0>::ZERO;
+
+	let val = random::<u128>();
+	bits[.. 128].store_be(val);
+	assert_eq!(bits.as_raw_slice()[0], (val >> 64) as u64);
+	assert_eq!(bits.as_raw_slice()[1], val as u64);
+	assert_eq!(bits[.. 128].load_be::<u128>(), val);
+
+	let bits = &mut bits[37 .. 165];
+	bits.store_be((val as i128).wrapping_neg());
+	assert_eq!(bits.load_be::<i128>(), (val as i128).wrapping_neg());
+	assert_eq!(bits.load_be::<u128>(), val.wrapping_neg());
+}
+
+#[test]
+fn checked() {
+	use crate::field::BitFieldError;
+
+	let mut bits = BitArray::<[u16; 2], Msb0>::ZERO;
+
+	assert_eq!(bits[.. 0].try_load_le::<u8>(), Err(BitFieldError::Empty));
+	assert_eq!(
+		bits[.. 9].try_load_be::<u8>(),
+		Err(BitFieldError::TooWide { len: 9, bits: 8 }),
+	);
+	assert_eq!(bits[.. 0].try_store_be(0u8), Err(BitFieldError::Empty));
+	assert_eq!(
+		bits.try_store_le(0u16),
+		Err(BitFieldError::TooWide { len: 32, bits: 16 }),
+	);
+
+	//  Spans an element boundary.
+	assert!(bits[10 .. 22].try_store_le(0xABCDu16).is_ok());
+	assert_eq!(bits[10 .. 22].try_load_le::<u16>(), Ok(0xBCD));
+	assert!(bits[10 .. 22].try_store_be(0x123u16).is_ok());
+	assert_eq!(bits[10 .. 22].try_load_be::<u16>(), Ok(0x123));
+
+	assert_eq!(
+		bits[10 .. 22].store_checked_le(0x1000u16),
+		Err(BitFieldError::ValueTooWide { len: 12 }),
+	);
+	assert_eq!(bits[10 .. 22].load_be::<u16>(), 0x123);
+	assert!(bits[10 .. 22].store_checked_be(0xFFFu16).is_ok());
+	assert_eq!(bits[10 .. 22].load_be::<u16>(), 0xFFF);
+
+	//  Signed values must be representable in the region width.
+	for value in -2048i16 ..= 2047 {
+		assert!(bits[10 .. 22].store_checked_le(value).is_ok());
+		assert_eq!(bits[10 .. 22].load_le::<i16>(), value);
+	}
+	assert!(bits[10 .. 22].store_checked_le(2048i16).is_err());
+	assert!(bits[10 .. 22].store_checked_le(-2049i16).is_err());
+	assert!(bits[.. 16].store_checked(i16::MIN).is_ok());
+	assert!(bits[.. 16].store_checked(u16::MAX).is_ok());
+}
+
+#[test]
+fn convert() {
+	use core::num::{
+		NonZeroI16,
+		NonZeroU128,
+		NonZeroU8,
+	};
+
+	let mut bits = BitArray::<[u8; 8], Msb0>::ZERO;
+
+	bits[5 .. 6].store_as(true);
+	assert_eq!(bits[5 .. 6].load_as::<bool>(), Ok(true));
+	bits[4 .. 6].store::<u8>(3);
+	assert_eq!(bits[4 .. 6].load_as::<bool>().unwrap_err().bits(), 3);
+
+	bits[3 .. 27].store_le_as('\u{1F980}');
+	assert_eq!(bits[3 .. 27].load_le_as::<char>(), Ok('\u{1F980}'));
+	bits[3 .. 27].store_be::<u32>(0xD800);
+	assert!(bits[3 .. 27].load_be_as::<char>().is_err());
+
+	bits[.. 64].store_be_as(-0.25f64);
+	assert_eq!(bits.load_be_as::<f64>(), Ok(-0.25));
+	bits[7 .. 39].store_le_as(f32::INFINITY);
+	assert_eq!(bits[7 .. 39].load_le_as::<f32>(), Ok(f32::INFINITY));
+
+	bits[9 .. 14].store_as(NonZeroU8::new(17).unwrap());
+	assert_eq!(bits[9 .. 14].load_as::<NonZeroU8>().unwrap().get(), 17);
+	bits[9 .. 14].store::<u8>(0);
+	assert!(bits[9 .. 14].load_as::<NonZeroU8>().is_err());
+	bits[20 .. 30].store_be_as(NonZeroI16::new(-3).unwrap());
+	assert_eq!(bits[20 .. 30].load_be_as::<NonZeroI16>().unwrap().get(), -3);
+	bits[.. 40].store_le_as(NonZeroU128::new(1 << 38).unwrap());
+	assert_eq!(bits[.. 40].load_le_as::<NonZeroU128>().unwrap().get(), 1 << 38);
+	bits[.. 40].store_le::<u128>(0);
+	assert!(bits[.. 40].load_le_as::<NonZeroU128>().is_err());
+
+	bits[4 .. 36].store_le_as([0x12u8, 0x34, 0x56, 0x78]);
+	assert_eq!(bits[4 .. 36].load_le::<u32>(), 0x7856_3412);
+	assert_eq!(bits[4 .. 36].load_le_as::<[u16; 2]>(), Ok([0x3412, 0x7856]));
+	bits[4 .. 36].store_be_as([0xABCDu16, 0x0123]);
+	assert_eq!(
+		bits[4 .. 36].load_be_as::<[u8; 4]>(),
+		Ok([0xCD, 0xAB, 0x23, 0x01])
+	);
+}
+
+#[test]
+fn wide() {
+	let src_data = random::<[u8; 24]>();
+	let src = src_data.view_bits::<Msb0>();
+	let mut dest = BitArray::<[u64; 3], Lsb0>::ZERO;
+
+	//  Agree with the integer transfers wherever those can be used.
+	for (start, end) in [(0, 100), (3, 120), (13, 64), (7, 8)] {
+		let (dest_start, dest_end) = (start + 5, end + 5);
+		dest.fill(true);
+		src[start .. end].load_le_into(&mut dest[dest_start .. dest_end]);
+		assert_eq!(
+			dest[dest_start .. dest_end].load_le::<u128>(),
+			src[start .. end].load_le::<u128>(),
+		);
+		assert!(dest[.. dest_start].all());
+		assert!(dest[dest_end ..].all());
+
+		dest[dest_start .. dest_end].store_be_from(&src[start .. end]);
+		assert_eq!(
+			dest[dest_start .. dest_end].load_be::<u128>(),
+			src[start .. end].load_be::<u128>(),
+		);
+	}
+
+	//  Wider than any integer: round-trip through a differently typed buffer.
+	let mut work = BitArray::<[u16; 13], Msb0>::ZERO;
+	src[9 .. 180].load_le_into(&mut work[3 .. 174]);
+	let mut back = BitArray::<[u8; 24], Msb0>::ZERO;
+	back[9 .. 180].store_le_from(&work[3 .. 174]);
+	assert_eq!(back[9 .. 180], src[9 .. 180]);
+
+	src[9 .. 180].load_be_into(&mut work[3 .. 174]);
+	back.fill(false);
+	work[3 .. 174].load_be_into(&mut back[9 .. 180]);
+	assert_eq!(back[9 .. 180], src[9 .. 180]);
+
+	//  Truncation and zero-extension.
+	dest.fill(true);
+	src[.. 100].load_be_into(&mut dest[.. 60]);
+	assert_eq!(
+		dest[.. 60].load_be::<u64>(),
+		src[.. 100].load_be::<u128>() as u64 & !(!0 << 60),
+	);
+	src[.. 60].load_le_into(&mut dest[.. 100]);
+	assert_eq!(dest[.. 100].load_le::<u128>(), src[.. 60].load_le::<u128>());
+
+	//  Arrays.
+	let words: [u32; 4] = src[4 .. 128].load_le_array();
+	let value = src[4 .. 128].load_le::<u128>();
+	assert_eq!(words, [
+		value as u32,
+		(value >> 32) as u32,
+		(value >> 64) as u32,
+		(value >> 96) as u32,
+	]);
+	let mut bits = BitArray::<[u32; 5], Lsb0>::ZERO;
+	bits[20 .. 144].store_le_array(words);
+	assert_eq!(bits[20 .. 144].load_le::<u128>(), value);
+
+	let words = src[3 .. 189].load_be_array::<u64, 3>();
+	let mut bits = BitArray::<[u64; 3], Msb0>::ZERO;
+	bits[1 .. 187].store_be_array(words);
+	assert_eq!(bits[1 .. 187], src[3 .. 189]);
+	assert_eq!(src[3 .. 189].load_be_array::<u128, 2>()[1], {
+		let mut low = BitArray::<[u8; 16], Msb0>::ZERO;
+		low.store_be_from(&src[61 .. 189]);
+		low.load_be::<u128>()
+	});
+
+	assert_eq!(src[.. 0].load_le_array::<u8, 2>(), [0; 2]);
+}
+
+#[test]
+#[should_panic]
+fn wide_array_overflow() {
+	bits![0; 65].load_le_array::<u32, 2>();
+}
+
+#[test]
+fn swapped_orders() {
+	use crate::{
+		field::BitField,
+		order::{
+			Lsb0ByteSwap,
+			Lsb0NibbleSwap,
+			Msb0ByteSwap,
+			Msb0NibbleSwap,
+		},
+	};
+
+	//  Each swapped ordering must transfer exactly what its base ordering
+	//  would, given the same sequence of bits.
+	fn check<O, B>()
+	where
+		O: BitOrder,
+		B: BitOrder,
+		BitSlice<u16, O>: BitField,
+		BitSlice<u16, B>: BitField,
+	{
+		let data = random::<[u16; 4]>();
+		let bits = data.view_bits::<O>();
+		let mut base = BitArray::<[u16; 4], B>::ZERO;
+		for (mut slot, bit) in base.iter_mut().zip(bits.iter().by_vals()) {
+			*slot = bit;
+		}
+
+		for &start in &[0, 3, 9, 16] {
+			for &len in &[1, 7, 16, 25, 32] {
+				let range = start .. start + len;
+				assert_eq!(
+					bits[range.clone()].load_le::<u32>(),
+					base[range.clone()].load_le::<u32>(),
+				);
+				assert_eq!(
+					bits[range.clone()].load_be::<u32>(),
+					base[range.clone()].load_be::<u32>(),
+				);
+
+				let value = random::<u32>();
+				let mut stored = data;
+				stored.view_bits_mut::<O>()[range.clone()].store_le(value);
+				let mut expected = base;
+				expected[range.clone()].store_le(value);
+				assert!(
+					stored
+						.view_bits::<O>()
+						.iter()
+						.by_vals()
+						.eq(expected.iter().by_vals())
+				);
+
+				stored.view_bits_mut::<O>()[range.clone()].store_be(value);
+				expected[range].store_be(value);
+				assert!(
+					stored
+						.view_bits::<O>()
+						.iter()
+						.by_vals()
+						.eq(expected.iter().by_vals())
+				);
+			}
+		}
+
+		let mut other = BitArray::<[u32; 2], Msb0NibbleSwap>::ZERO;
+		other[3 .. 50].clone_from_bitslice(&bits[7 .. 54]);
+		assert!(
+			other[3 .. 50]
+				.iter()
+				.by_vals()
+				.eq(bits[7 .. 54].iter().by_vals())
+		);
+		assert_eq!(other[3 .. 50], bits[7 .. 54]);
+	}
+
+	check::<Lsb0ByteSwap, Lsb0>();
+	check::<Msb0ByteSwap, Msb0>();
+	check::<Lsb0NibbleSwap, Lsb0>();
+	check::<Msb0NibbleSwap, Msb0>();
+}
+
 #[test]
 #[cfg(feature = "std")]
 fn read_bits() {
@@ -313,3 +594,51 @@ fn write_bits() {
 
 	assert_eq!(data, [0b1010_0000, 0b1011_0101, 0b0011_0100, 0b0000_1100]);
 }
+
+#[test]
+#[cfg(feature = "std")]
+fn bit_streams() {
+	use crate::field::{
+		BitReader,
+		BitWriter,
+	};
+
+	let values = random::<[u32; 8]>();
+	let widths = [1usize, 3, 7, 8, 13, 17, 29, 32];
+
+	let mut writer = BitWriter::<_, Lsb0>::new(Vec::new());
+	for (&width, &value) in widths.iter().zip(values.iter()) {
+		writer.write_le(width, value).unwrap();
+		writer.write_bit(value & 1 != 0).unwrap();
+	}
+	assert!(!writer.is_aligned());
+	let bytes = writer.finish().unwrap();
+	//  110 bits of integers and 8 single bits round up to 15 bytes.
+	assert_eq!(bytes.len(), 15);
+
+	let mut reader = BitReader::<_, Lsb0>::new(&bytes[..]);
+	for (&width, &value) in widths.iter().zip(values.iter()) {
+		let mask = !0u32 >> (32 - width);
+		assert_eq!(reader.read_le::<u32>(width).unwrap(), value & mask);
+		assert_eq!(reader.read_bit().unwrap(), value & 1 != 0);
+	}
+	assert_eq!(reader.align_to_byte(), 2);
+	assert!(reader.read_bit().is_err());
+
+	let mut writer = BitWriter::<_, Msb0>::with_fill(Vec::new(), true);
+	writer.write_be::<u16>(12, 0xABC).unwrap();
+	writer.write_bits(bits![u8, Lsb0; 0, 1]).unwrap();
+	assert_eq!(writer.get_ref(), &[0xAB]);
+	assert_eq!(writer.finish().unwrap(), [0xAB, 0xC7]);
+
+	let data = [0xABu8, 0xC7, 0x10];
+	let mut reader = BitReader::<_, Msb0>::new(&data[..]);
+	reader.skip(4).unwrap();
+	assert_eq!(reader.read_bits(6).unwrap(), bits![1, 0, 1, 1, 1, 1]);
+	assert!(!reader.is_aligned());
+	let err = reader.read_be::<u32>(32).unwrap_err();
+	assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+	assert_eq!(reader.read_be::<u16>(14).unwrap(), 0b00_0111_0001_0000);
+	assert!(reader.is_aligned());
+	assert!(reader.into_inner().is_empty());
+}
@@ -0,0 +1,486 @@
+#![doc = include_str!("../../doc/field/wide.md")]
+
+use core::{
+	cmp,
+	iter,
+	ops::Range,
+};
+
+use funty::Integral;
+
+use super::{
+	BitField,
+	resize,
+};
+use crate::{
+	mem::bits_of,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+/// Wide bit-field transfers.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	Self: BitField,
+{
+	/// Copies the numerical value of the bit-slice into another bit-slice,
+	/// using the target’s element ordering.
+	///
+	/// This forwards to [`.load_le_into()`] on little-endian targets, and to
+	/// [`.load_be_into()`] on big-endian targets. Like [`.load()`], it does not
+	/// produce a stable memory layout.
+	///
+	/// [`.load()`]: crate::field::BitField::load
+	/// [`.load_be_into()`]: Self::load_be_into
+	/// [`.load_le_into()`]: Self::load_le_into
+	#[inline]
+	pub fn load_into<T2, O2>(&self, dest: &mut BitSlice<T2, O2>)
+	where
+		T2: BitStore,
+		O2: BitOrder,
+		BitSlice<T2, O2>: BitField,
+	{
+		if cfg!(target_endian = "little") {
+			self.load_le_into(dest);
+		}
+		else {
+			self.load_be_into(dest);
+		}
+	}
+
+	/// Copies the numerical value of the bit-slice into another bit-slice,
+	/// with little-endian element ordering.
+	///
+	/// Both bit-slices are treated as integers of any width, following the
+	/// rules of [`.load_le()`] and [`.store_le()`]: each memory element holds a
+	/// segment of the value, and the segments gain numerical significance with
+	/// increasing memory address. The two bit-slices may have different storage
+	/// types and bit-orderings.
+	///
+	/// When the bit-slices have the same length, this behaves like
+	/// `dest.store_le(self.load_le())`, but is not limited to the width of an
+	/// integer.
+	///
+	/// ## Parameters
+	///
+	/// - `&self`: The bit-slice whose value is copied.
+	/// - `dest`: The bit-slice that receives the value. If it is shorter than
+	///   `self`, the high bits of the value are discarded; if it is longer, its
+	///   high bits are cleared to zero.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let packet = [0x21u8, 0x43, 0x65, 0x87, 0xA9, 0xCB, 0xED, 0x0F, 0x1F];
+	/// let field = &packet.view_bits::<Msb0>()[4 .. 72];
+	///
+	/// let mut work = bitarr![u64, Lsb0; 0; 128];
+	/// field.load_le_into(&mut work[.. 68]);
+	/// assert_eq!(work[.. 64].load_le::<u64>(), 0xF0FE_DCBA_9876_5431);
+	/// assert_eq!(work[64 .. 68].load_le::<u8>(), 0x1);
+	/// ```
+	///
+	/// [`.load_le()`]: crate::field::BitField::load_le
+	/// [`.store_le()`]: crate::field::BitField::store_le
+	#[inline]
+	pub fn load_le_into<T2, O2>(&self, dest: &mut BitSlice<T2, O2>)
+	where
+		T2: BitStore,
+		O2: BitOrder,
+		BitSlice<T2, O2>: BitField,
+	{
+		transfer(bit_segments(self, segments(self)), dest, segments(dest));
+	}
+
+	/// Copies the numerical value of the bit-slice into another bit-slice,
+	/// with big-endian element ordering.
+	///
+	/// This follows the rules of [`.load_be()`] and [`.store_be()`]: the
+	/// segments of the value lose numerical significance with increasing
+	/// memory address. Otherwise, it behaves like [`.load_le_into()`].
+	///
+	/// [`.load_be()`]: crate::field::BitField::load_be
+	/// [`.load_le_into()`]: Self::load_le_into
+	/// [`.store_be()`]: crate::field::BitField::store_be
+	#[inline]
+	pub fn load_be_into<T2, O2>(&self, dest: &mut BitSlice<T2, O2>)
+	where
+		T2: BitStore,
+		O2: BitOrder,
+		BitSlice<T2, O2>: BitField,
+	{
+		transfer(
+			bit_segments(self, segments(self).rev()),
+			dest,
+			segments(dest).rev(),
+		);
+	}
+
+	/// Copies the numerical value of another bit-slice into the bit-slice,
+	/// using the target’s element ordering.
+	///
+	/// This is `src.load_into(self)`; see [`.load_into()`].
+	///
+	/// [`.load_into()`]: Self::load_into
+	#[inline]
+	pub fn store_from<T2, O2>(&mut self, src: &BitSlice<T2, O2>)
+	where
+		T2: BitStore,
+		O2: BitOrder,
+		BitSlice<T2, O2>: BitField,
+	{
+		src.load_into(self);
+	}
+
+	/// Copies the numerical value of another bit-slice into the bit-slice,
+	/// with little-endian element ordering.
+	///
+	/// This is `src.load_le_into(self)`; see [`.load_le_into()`].
+	///
+	/// [`.load_le_into()`]: Self::load_le_into
+	#[inline]
+	pub fn store_le_from<T2, O2>(&mut self, src: &BitSlice<T2, O2>)
+	where
+		T2: BitStore,
+		O2: BitOrder,
+		BitSlice<T2, O2>: BitField,
+	{
+		src.load_le_into(self);
+	}
+
+	/// Copies the numerical value of another bit-slice into the bit-slice,
+	/// with big-endian element ordering.
+	///
+	/// This is `src.load_be_into(self)`; see [`.load_be_into()`].
+	///
+	/// [`.load_be_into()`]: Self::load_be_into
+	#[inline]
+	pub fn store_be_from<T2, O2>(&mut self, src: &BitSlice<T2, O2>)
+	where
+		T2: BitStore,
+		O2: BitOrder,
+		BitSlice<T2, O2>: BitField,
+	{
+		src.load_be_into(self);
+	}
+
+	/// Loads the numerical value of the bit-slice into an array of integers,
+	/// using the target’s element ordering.
+	///
+	/// This forwards to [`.load_le_array()`] on little-endian targets, and to
+	/// [`.load_be_array()`] on big-endian targets.
+	///
+	/// [`.load_be_array()`]: Self::load_be_array
+	/// [`.load_le_array()`]: Self::load_le_array
+	#[inline]
+	pub fn load_array<I, const N: usize>(&self) -> [I; N]
+	where I: Integral {
+		if cfg!(target_endian = "little") {
+			self.load_le_array()
+		}
+		else {
+			self.load_be_array()
+		}
+	}
+
+	/// Loads the numerical value of the bit-slice into an array of integers,
+	/// with little-endian element ordering.
+	///
+	/// The bit-slice is read as in [`.load_le_into()`], and its value is split
+	/// into `N` integers of `I::BITS` each. The integer at index 0 receives the
+	/// least significant bits, and the integer at index `N - 1` receives the
+	/// most significant. Integers past the end of the value are zero; unlike
+	/// [`.load_le()`], signed integers are not sign-extended.
+	///
+	/// ## Type Parameters
+	///
+	/// - `I`: The integer type in the array.
+	/// - `N`: The number of integers to load.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is greater than `N * I::BITS`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bits = bitarr![u8, Lsb0; 0; 80];
+	/// bits[.. 64].store_le(0x0123_4567_89AB_CDEFu64);
+	///
+	/// let words: [u16; 5] = bits[.. 72].load_le_array();
+	/// assert_eq!(words, [0xCDEF, 0x89AB, 0x4567, 0x0123, 0]);
+	/// assert_eq!(bits.load_le_array::<u32, 3>(), [0x89AB_CDEF, 0x0123_4567, 0]);
+	/// ```
+	///
+	/// [`.load_le()`]: crate::field::BitField::load_le
+	/// [`.load_le_into()`]: Self::load_le_into
+	#[inline]
+	pub fn load_le_array<I, const N: usize>(&self) -> [I; N]
+	where I: Integral {
+		check_array::<I>("load", N, self.len());
+		let mut out = [I::ZERO; N];
+		let mut value = Regroup::new(bit_segments(self, segments(self)));
+		for slot in out.iter_mut() {
+			*slot = value.take_int();
+		}
+		out
+	}
+
+	/// Loads the numerical value of the bit-slice into an array of integers,
+	/// with big-endian element ordering.
+	///
+	/// This reads the bit-slice as in [`.load_be_into()`]. The integer at index
+	/// 0 receives the most significant bits, and the integer at index `N - 1`
+	/// receives the least significant. Otherwise, it behaves like
+	/// [`.load_le_array()`].
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0x01u8, 0x23, 0x45, 0x67, 0x89, 0xAB];
+	/// let bits = data.view_bits::<Msb0>();
+	/// assert_eq!(bits.load_be_array::<u16, 3>(), [0x0123, 0x4567, 0x89AB]);
+	/// assert_eq!(bits[8 ..].load_be_array::<u32, 2>(), [0x23, 0x4567_89AB]);
+	/// ```
+	///
+	/// [`.load_be_into()`]: Self::load_be_into
+	/// [`.load_le_array()`]: Self::load_le_array
+	#[inline]
+	pub fn load_be_array<I, const N: usize>(&self) -> [I; N]
+	where I: Integral {
+		check_array::<I>("load", N, self.len());
+		let mut out = [I::ZERO; N];
+		let mut value = Regroup::new(bit_segments(self, segments(self).rev()));
+		for slot in out.iter_mut().rev() {
+			*slot = value.take_int();
+		}
+		out
+	}
+
+	/// Stores an array of integers into the bit-slice as one numerical value,
+	/// using the target’s element ordering.
+	///
+	/// This forwards to [`.store_le_array()`] on little-endian targets, and to
+	/// [`.store_be_array()`] on big-endian targets.
+	///
+	/// [`.store_be_array()`]: Self::store_be_array
+	/// [`.store_le_array()`]: Self::store_le_array
+	#[inline]
+	pub fn store_array<I, const N: usize>(&mut self, values: [I; N])
+	where I: Integral {
+		if cfg!(target_endian = "little") {
+			self.store_le_array(values);
+		}
+		else {
+			self.store_be_array(values);
+		}
+	}
+
+	/// Stores an array of integers into the bit-slice as one numerical value,
+	/// with little-endian element ordering.
+	///
+	/// This is the inverse of [`.load_le_array()`]: the integer at index 0
+	/// provides the least significant bits of the value. Bits of the value
+	/// past the end of the bit-slice are discarded.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is greater than `N * I::BITS`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bits = bitarr![u16, Msb0; 0; 48];
+	/// bits[4 .. 44].store_le_array([0x89AB_CDEFu32, 0x67]);
+	/// assert_eq!(bits[4 .. 44].load_le::<u64>(), 0x67_89AB_CDEF);
+	/// ```
+	///
+	/// [`.load_le_array()`]: Self::load_le_array
+	#[inline]
+	pub fn store_le_array<I, const N: usize>(&mut self, values: [I; N])
+	where I: Integral {
+		check_array::<I>("store", N, self.len());
+		transfer(int_segments(values.iter().copied()), self, segments(self));
+	}
+
+	/// Stores an array of integers into the bit-slice as one numerical value,
+	/// with big-endian element ordering.
+	///
+	/// This is the inverse of [`.load_be_array()`]: the integer at index
+	/// `N - 1` provides the least significant bits of the value.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len()` is greater than `N * I::BITS`.
+	///
+	/// [`.load_be_array()`]: Self::load_be_array
+	#[inline]
+	pub fn store_be_array<I, const N: usize>(&mut self, values: [I; N])
+	where I: Integral {
+		check_array::<I>("store", N, self.len());
+		transfer(
+			int_segments(values.iter().rev().copied()),
+			self,
+			segments(self).rev(),
+		);
+	}
+}
+
+/// Asserts that a bit-slice is not longer than an array of integers.
+fn check_array<I>(action: &'static str, count: usize, len: usize)
+where I: Integral {
+	let width = bits_of::<I>();
+	assert!(
+		len <= width.saturating_mul(count),
+		"cannot {} a {}-bit region through {} {}-bit integers",
+		action,
+		len,
+		count,
+		width,
+	);
+}
+
+/// Splits a bit-slice into the ranges that each lie within one memory element,
+/// in order of increasing memory address.
+///
+/// Each range is a contiguous segment of the bit-slice’s numerical value, as
+/// seen by the `BitField` methods.
+fn segments<T, O>(
+	bits: &BitSlice<T, O>,
+) -> impl DoubleEndedIterator<Item = Range<usize>>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	let width = bits_of::<T::Mem>();
+	let len = bits.len();
+	let head = bits.as_bitspan().head().into_inner() as usize;
+	let first = cmp::min(len, width - head);
+	iter::once(0 .. first)
+		.filter(|range| !range.is_empty())
+		.chain(
+			(first .. len)
+				.step_by(width)
+				.map(move |start| start .. cmp::min(start + width, len)),
+		)
+}
+
+/// Loads the given single-element ranges of a bit-slice as integer segments.
+fn bit_segments<'a, T, O>(
+	bits: &'a BitSlice<T, O>,
+	ranges: impl Iterator<Item = Range<usize>> + 'a,
+) -> impl Iterator<Item = (u64, usize)> + 'a
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	ranges.map(move |range| {
+		let width = range.len();
+		(bits[range].load_le::<u64>(), width)
+	})
+}
+
+/// Splits integers into segments of at most 64 bits, in order of increasing
+/// numerical significance.
+fn int_segments<I>(
+	ints: impl Iterator<Item = I>,
+) -> impl Iterator<Item = (u64, usize)>
+where I: Integral {
+	ints.flat_map(|int| {
+		(0 .. bits_of::<I>()).step_by(64).map(move |shamt| {
+			(
+				resize::<I, u64>(int >> shamt),
+				cmp::min(64, bits_of::<I>() - shamt),
+			)
+		})
+	})
+}
+
+/// Writes a numerical value, provided as a sequence of segments in order of
+/// increasing significance, into the given segments of a bit-slice.
+fn transfer<V, T, O>(
+	value: V,
+	dest: &mut BitSlice<T, O>,
+	ranges: impl Iterator<Item = Range<usize>>,
+) where
+	V: Iterator<Item = (u64, usize)>,
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	let mut value = Regroup::new(value);
+	for range in ranges {
+		let width = range.len();
+		dest[range].store_le::<u64>(value.take(width));
+	}
+}
+
+/// Regroups the segments of a numerical value into segments of other widths.
+struct Regroup<I> {
+	/// The source of segments, in order of increasing significance.
+	iter:  I,
+	/// Bits that have been read from `iter` but not yet taken.
+	accum: u128,
+	/// The number of live bits in `accum`.
+	count: usize,
+}
+
+impl<I> Regroup<I>
+where I: Iterator<Item = (u64, usize)>
+{
+	/// Begins regrouping a numerical value.
+	fn new(iter: I) -> Self {
+		Self {
+			iter,
+			accum: 0,
+			count: 0,
+		}
+	}
+
+	/// Takes the next `width` bits of the value, which are zero once the value
+	/// has been exhausted.
+	fn take(&mut self, width: usize) -> u64 {
+		debug_assert!(width <= 64, "cannot take more than 64 bits at once");
+		while self.count < width {
+			match self.iter.next() {
+				Some((segment, bits)) => {
+					self.accum |= (segment as u128 & mask(bits)) << self.count;
+					self.count += bits;
+				},
+				None => self.count = width,
+			}
+		}
+		let out = (self.accum & mask(width)) as u64;
+		self.accum >>= width;
+		self.count -= width;
+		out
+	}
+
+	/// Takes the next `N::BITS` bits of the value as an integer.
+	fn take_int<N>(&mut self) -> N
+	where N: Integral {
+		let mut out = N::ZERO;
+		for shamt in (0 .. bits_of::<N>()).step_by(64) {
+			let width = cmp::min(64, bits_of::<N>() - shamt);
+			out |= resize::<u64, N>(self.take(width)) << shamt;
+		}
+		out
+	}
+}
+
+/// Produces a mask of the low `width` bits, for `width` in `0 ..= 64`.
+fn mask(width: usize) -> u128 {
+	(1 << width) - 1
+}
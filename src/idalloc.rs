@@ -0,0 +1,486 @@
+#![doc = include_str!("../doc/idalloc.md")]
+
+use core::{
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	ops::{
+		Deref,
+		Range,
+	},
+	sync::atomic::Ordering,
+};
+
+use funty::Integral;
+use radium::{
+	Radium,
+	types::RadiumUsize,
+};
+
+#[cfg(feature = "alloc")]
+use crate::{
+	boxed::BitBox,
+	order::Lsb0,
+	vec::BitVec,
+};
+use crate::{
+	mem::{
+		self,
+		BitRegister,
+	},
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+#[doc = include_str!("../doc/idalloc/IdAllocator.md")]
+pub struct IdAllocator<S> {
+	/// The allocation bitmap. A `1` bit marks an allocated identifier.
+	bits: S,
+	/// The index of the memory element at which searches begin.
+	hint: RadiumUsize,
+}
+
+#[cfg(feature = "alloc")]
+impl IdAllocator<BitBox<RadiumUsize, Lsb0>> {
+	/// Creates an allocator for the identifiers `0 .. capacity`, all free.
+	///
+	/// The bitmap is a heap-allocated bit-box of `AtomicUsize`, on targets
+	/// that have it.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::idalloc::IdAllocator;
+	///
+	/// let ids = IdAllocator::new(100);
+	/// assert_eq!(ids.capacity(), 100);
+	/// assert_eq!(ids.alloc(), Some(0));
+	/// ```
+	#[inline]
+	pub fn new(capacity: usize) -> Self {
+		Self::from_bits(BitVec::repeat(false, capacity).into_boxed_bitslice())
+	}
+}
+
+impl<S, T, O> IdAllocator<S>
+where
+	S: Deref<Target = BitSlice<T, O>>,
+	T: BitStore + Radium<Item = <T as BitStore>::Mem>,
+	O: BitOrder,
+{
+	/// Creates an allocator over an existing bitmap.
+	///
+	/// Each bit of the bitmap tracks one identifier, equal to its index. Bits
+	/// that are already `1` are treated as allocated.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::{idalloc::IdAllocator, prelude::*};
+	/// use core::sync::atomic::AtomicU64;
+	///
+	/// let ids = IdAllocator::from_bits(BitArray::<[AtomicU64; 2]>::ZERO);
+	/// assert_eq!(ids.capacity(), 128);
+	/// assert_eq!(ids.alloc_range(3), Some(0));
+	/// assert_eq!(ids.alloc(), Some(3));
+	/// ```
+	#[inline]
+	pub fn from_bits(bits: S) -> Self {
+		Self {
+			bits,
+			hint: RadiumUsize::new(0),
+		}
+	}
+
+	/// Gets the number of identifiers managed by the allocator.
+	#[inline]
+	pub fn capacity(&self) -> usize {
+		self.bits.len()
+	}
+
+	/// Views the allocation bitmap.
+	///
+	/// Allocated identifiers are marked by `1` bits.
+	#[inline]
+	pub fn as_bitslice(&self) -> &BitSlice<T, O> {
+		&self.bits
+	}
+
+	/// Extracts the allocation bitmap.
+	#[inline]
+	pub fn into_inner(self) -> S {
+		self.bits
+	}
+
+	/// Tests whether an identifier is currently allocated.
+	///
+	/// ## Panics
+	///
+	/// This panics if `id` is not less than the capacity.
+	#[inline]
+	pub fn is_allocated(&self, id: usize) -> bool {
+		self.bits.get_ordered(id, Ordering::Acquire)
+	}
+
+	/// Counts the identifiers that are currently allocated.
+	///
+	/// When other threads are allocating or freeing, this is only a snapshot,
+	/// and may be out of date as soon as it returns.
+	#[inline]
+	pub fn count_allocated(&self) -> usize {
+		self.bits.count_ones()
+	}
+
+	/// Allocates the lowest free identifier at or after the search hint.
+	///
+	/// The search begins at the memory element where the most recent
+	/// allocation or release took place, and wraps around to the start of the
+	/// bitmap. Each element is scanned with the same word-at-a-time search as
+	/// [`BitSlice::first_zero`], and the chosen bit is claimed with a single
+	/// atomic read-modify-write. If another thread claims it first, the scan
+	/// resumes at the next free bit.
+	///
+	/// ## Returns
+	///
+	/// The newly allocated identifier, or `None` if every identifier is
+	/// allocated.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::idalloc::IdAllocator;
+	///
+	/// let ids = IdAllocator::new(2);
+	/// assert_eq!(ids.alloc(), Some(0));
+	/// assert_eq!(ids.alloc(), Some(1));
+	/// assert_eq!(ids.alloc(), None);
+	/// ```
+	#[inline]
+	pub fn alloc(&self) -> Option<usize> {
+		let elements = self.bits.as_bitspan().elements();
+		let start = self.hint.load(Ordering::Relaxed) % elements.max(1);
+		for elem in (start .. elements).chain(0 .. start) {
+			let range = self.element_range(elem);
+			while let Some(offset) = self.bits[range.clone()].first_zero() {
+				let id = range.start + offset;
+				if !self.bits.fetch_set(id, Ordering::AcqRel) {
+					self.hint.store(elem, Ordering::Relaxed);
+					return Some(id);
+				}
+			}
+		}
+		None
+	}
+
+	/// Allocates `count` contiguous identifiers.
+	///
+	/// The search looks for a run of at least `count` free bits, first from
+	/// the search hint and then from the start of the bitmap. The run is
+	/// claimed one memory element at a time with compare-and-exchange; if
+	/// another thread claims any part of it first, the parts already claimed
+	/// are released and the search continues past the conflict.
+	///
+	/// ## Returns
+	///
+	/// The first identifier of the allocated range, or `None` if no run of
+	/// `count` free identifiers was found.
+	///
+	/// ## Panics
+	///
+	/// This panics if `count` is zero.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::idalloc::IdAllocator;
+	///
+	/// let ids = IdAllocator::new(200);
+	/// assert_eq!(ids.alloc(), Some(0));
+	/// assert_eq!(ids.alloc_range(100), Some(1));
+	/// assert_eq!(ids.alloc_range(100), None);
+	/// assert_eq!(ids.alloc_range(99), Some(101));
+	/// ```
+	#[inline]
+	pub fn alloc_range(&self, count: usize) -> Option<usize> {
+		assert!(count != 0, "cannot allocate an empty range");
+		let hint = self.hint.load(Ordering::Relaxed);
+		let origin = if hint < self.bits.as_bitspan().elements() {
+			self.element_range(hint).start
+		}
+		else {
+			0
+		};
+		self.claim_run(origin, count)
+			.or_else(|| self.claim_run(0, count))
+	}
+
+	/// Releases an identifier, making it available to later allocations.
+	///
+	/// ## Panics
+	///
+	/// This panics if `id` is not less than the capacity, or is not currently
+	/// allocated.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::idalloc::IdAllocator;
+	///
+	/// let ids = IdAllocator::new(8);
+	/// let id = ids.alloc().unwrap();
+	/// ids.free(id);
+	/// assert!(!ids.is_allocated(id));
+	/// ```
+	#[inline]
+	pub fn free(&self, id: usize) {
+		assert!(
+			self.bits.fetch_clear(id, Ordering::AcqRel),
+			"identifier {} is not allocated",
+			id,
+		);
+		self.hint.store(self.element_of(id), Ordering::Relaxed);
+	}
+
+	/// Releases a contiguous range of identifiers.
+	///
+	/// The range is released one memory element at a time, so other threads
+	/// may observe it partially released.
+	///
+	/// ## Panics
+	///
+	/// This panics if the range exceeds the capacity, or if any identifier in
+	/// it is not currently allocated. Identifiers in memory elements before the
+	/// one where the unallocated identifier was found will already have been
+	/// released.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::idalloc::IdAllocator;
+	///
+	/// let ids = IdAllocator::new(100);
+	/// let start = ids.alloc_range(70).unwrap();
+	/// ids.free_range(start + 10, 50);
+	/// assert_eq!(ids.count_allocated(), 20);
+	/// ```
+	#[inline]
+	pub fn free_range(&self, start: usize, count: usize) {
+		let end = start + count;
+		self.bits.assert_in_bounds(end, 0 ..= self.bits.len());
+		if let Err(id) = self.swap_run(start .. end, true) {
+			panic!("identifier {} is not allocated", id);
+		}
+		if count != 0 {
+			self.hint.store(self.element_of(start), Ordering::Relaxed);
+		}
+	}
+
+	/// Finds and claims a run of `count` free bits at or after `from`.
+	fn claim_run(&self, mut from: usize, count: usize) -> Option<usize> {
+		let len = self.bits.len();
+		loop {
+			let start = from + self.bits[from ..].first_zero()?;
+			let end = self.bits[start ..]
+				.first_one()
+				.map_or(len, |offset| start + offset);
+			if end - start < count {
+				from = end;
+				continue;
+			}
+			match self.swap_run(start .. start + count, false) {
+				Ok(()) => {
+					self.hint.store(self.element_of(start), Ordering::Relaxed);
+					return Some(start);
+				},
+				Err(conflict) => from = conflict + 1,
+			}
+		}
+	}
+
+	/// Atomically inverts a run of bits that are all expected to hold
+	/// `current`, one memory element at a time.
+	///
+	/// If any element’s part of the run does not hold `current`, the parts
+	/// already inverted are restored when claiming (`current` is `false`), and
+	/// the index of the first unexpected bit is returned.
+	fn swap_run(&self, range: Range<usize>, current: bool) -> Result<(), usize> {
+		let (old, new) = if current {
+			(<T::Mem as BitRegister>::ALL, <T::Mem as Integral>::ZERO)
+		}
+		else {
+			(<T::Mem as Integral>::ZERO, <T::Mem as BitRegister>::ALL)
+		};
+		let mut from = range.start;
+		while from < range.end {
+			let upto = self.element_range(self.element_of(from)).end;
+			let seg = from .. upto.min(range.end);
+			let result = self.bits.compare_exchange_bits(
+				seg.clone(),
+				old,
+				new,
+				Ordering::AcqRel,
+				Ordering::Acquire,
+			);
+			if let Err(found) = result {
+				let found = if current { !found } else { found };
+				if !current {
+					self.swap_run(range.start .. seg.start, true)
+						.expect("claimed identifiers were released elsewhere");
+				}
+				return Err(seg.start + found.trailing_zeros() as usize);
+			}
+			from = seg.end;
+		}
+		Ok(())
+	}
+
+	/// Gets the index of the memory element holding a bit.
+	fn element_of(&self, id: usize) -> usize {
+		let head = self.bits.as_bitspan().head().into_inner() as usize;
+		(head + id) / mem::bits_of::<T::Mem>()
+	}
+
+	/// Gets the range of bit indices held in a memory element.
+	fn element_range(&self, elem: usize) -> Range<usize> {
+		let head = self.bits.as_bitspan().head().into_inner() as usize;
+		let width = mem::bits_of::<T::Mem>();
+		let start = (elem * width).saturating_sub(head);
+		let end = ((elem + 1) * width - head).min(self.bits.len());
+		start .. end
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<S, T, O> Debug for IdAllocator<S>
+where
+	S: Deref<Target = BitSlice<T, O>>,
+	T: BitStore + Radium<Item = <T as BitStore>::Mem>,
+	O: BitOrder,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_struct("IdAllocator")
+			.field("capacity", &self.capacity())
+			.field("allocated", &self.count_allocated())
+			.finish()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<S, T, O> From<S> for IdAllocator<S>
+where
+	S: Deref<Target = BitSlice<T, O>>,
+	T: BitStore + Radium<Item = <T as BitStore>::Mem>,
+	O: BitOrder,
+{
+	#[inline]
+	fn from(bits: S) -> Self {
+		Self::from_bits(bits)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use core::sync::atomic::{
+		AtomicU8,
+		AtomicUsize,
+	};
+
+	use static_assertions::*;
+
+	use super::*;
+	use crate::prelude::*;
+
+	#[test]
+	fn single_ids() {
+		let data = [0u8; 3].map(AtomicU8::new);
+		let ids = IdAllocator::from_bits(&data.view_bits::<Msb0>()[3 .. 21]);
+		assert_eq!(ids.capacity(), 18);
+
+		for expected in 0 .. 18 {
+			assert_eq!(ids.alloc(), Some(expected));
+		}
+		assert_eq!(ids.alloc(), None);
+		assert_eq!(ids.count_allocated(), 18);
+
+		ids.free(9);
+		ids.free(2);
+		assert!(!ids.is_allocated(2));
+		assert_eq!(ids.alloc(), Some(2));
+		assert_eq!(ids.alloc(), Some(9));
+		assert_eq!(data[0].load(Ordering::Relaxed), 0b0001_1111);
+		assert_eq!(data[2].load(Ordering::Relaxed), 0b1111_1000);
+	}
+
+	#[test]
+	#[should_panic(expected = "identifier 4 is not allocated")]
+	fn double_free() {
+		let ids = IdAllocator::from_bits(BitArray::<[AtomicU8; 1]>::ZERO);
+		ids.free(ids.alloc_range(5).unwrap() + 4);
+		ids.free(4);
+	}
+
+	#[test]
+	fn ranges() {
+		let data = [0u8; 4].map(AtomicU8::new);
+		let ids = IdAllocator::from_bits(&data.view_bits::<Lsb0>()[5 ..]);
+
+		assert_eq!(ids.alloc(), Some(0));
+		assert_eq!(ids.alloc_range(12), Some(1));
+		ids.as_bitslice().fetch_set(16, Ordering::Relaxed);
+		assert_eq!(ids.alloc_range(4), Some(17));
+		assert_eq!(ids.alloc_range(3), Some(13));
+		assert_eq!(ids.alloc_range(7), None);
+		assert_eq!(ids.alloc_range(6), Some(21));
+		assert!(ids.as_bitslice().all());
+
+		ids.free_range(3, 20);
+		assert_eq!(ids.count_allocated(), 7);
+		assert_eq!(ids.alloc_range(20), Some(3));
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn concurrent() {
+		use std::{
+			sync::Arc,
+			thread,
+		};
+
+		assert_impl_all!(IdAllocator<BitBox<AtomicUsize>>: Send, Sync);
+		assert_impl_all!(IdAllocator<BitArray<[AtomicUsize; 4]>>: Send, Sync);
+
+		let ids = Arc::new(IdAllocator::new(1 << 13));
+		let handles = (0 .. 4)
+			.map(|_| {
+				let ids = ids.clone();
+				thread::spawn(move || {
+					let mut mine = Vec::new();
+					for round in 0 .. 200 {
+						mine.push(ids.alloc().unwrap());
+						let start = ids.alloc_range(1 + round % 13).unwrap();
+						mine.extend(start .. start + 1 + round % 13);
+						if round % 3 == 0 {
+							let id = mine.swap_remove(round % mine.len());
+							ids.free(id);
+						}
+					}
+					mine
+				})
+			})
+			.collect::<Vec<_>>();
+		let mut all = handles
+			.into_iter()
+			.flat_map(|handle| handle.join().unwrap())
+			.collect::<Vec<_>>();
+		all.sort_unstable();
+		let len = all.len();
+		all.dedup();
+		assert_eq!(all.len(), len);
+		assert_eq!(ids.count_allocated(), len);
+		assert!(all.iter().all(|&id| ids.is_allocated(id)));
+	}
+}
@@ -27,12 +27,17 @@ pub mod macros;
 pub mod access;
 pub mod array;
 pub mod boxed;
+pub mod codec;
 pub mod domain;
 pub mod field;
+pub mod idalloc;
 pub mod index;
 pub mod mem;
 pub mod order;
+pub mod parse;
 pub mod ptr;
+pub mod rank;
+pub mod roaring;
 mod serdes;
 pub mod slice;
 pub mod store;
@@ -42,7 +47,10 @@ pub mod view;
 #[doc = include_str!("../doc/prelude.md")]
 pub mod prelude {
 	pub use crate::{
-		array::BitArray,
+		array::{
+			BitArray,
+			ExactBitArray,
+		},
 		bitarr,
 		bits,
 		field::BitField as _,
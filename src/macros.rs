@@ -23,6 +23,20 @@ macro_rules! BitArr {
 	(for $len:expr) => {
 		$crate::BitArr!(for $len, in usize)
 	};
+
+	(exact $len:expr, in $store:ty, $order:ty $(,)?) => {
+		$crate::array::ExactBitArray::<
+			[$store; $crate::mem::elts::<$store>($len)], $order, { $len }
+		>
+	};
+
+	(exact $len:expr, in $store:ty $(,)?) => {
+		$crate::BitArr!(exact $len, in $store, $crate::order::Lsb0)
+	};
+
+	(exact $len:expr) => {
+		$crate::BitArr!(exact $len, in usize)
+	};
 }
 
 #[macro_export]
@@ -363,3 +377,79 @@ macro_rules! bitbox {
 		$crate::bitvec!($($arg)*).into_boxed_bitslice()
 	};
 }
+
+#[macro_export]
+#[doc = include_str!("../doc/macros/bitfield.md")]
+macro_rules! bitfield {
+	(
+		$(#[$attr:meta])*
+		$vis:vis struct $name:ident : $store:ty, $order:ty {
+			$(
+				$(#[$fattr:meta])*
+				$fvis:vis $get:ident, $set:ident, $with:ident
+				: $typ:ident @ $bits:expr $(, $endian:ident)?
+			);* $(;)?
+		}
+	) => {
+		$(#[$attr])*
+		$vis struct $name {
+			bits: $crate::array::BitArray<$store, $order>,
+		}
+
+		//  Callers are not expected to use every accessor of every field.
+		#[allow(dead_code)]
+		impl $name {
+			/// A value with every field cleared to zero.
+			pub const ZERO: Self = Self {
+				bits: $crate::array::BitArray::ZERO,
+			};
+
+			/// Wraps an existing bit-array, without modifying its contents.
+			#[inline]
+			pub fn from_bitarray(
+				bits: $crate::array::BitArray<$store, $order>,
+			) -> Self {
+				Self { bits }
+			}
+
+			/// Unwraps the underlying bit-array.
+			#[inline]
+			pub fn into_bitarray(self) -> $crate::array::BitArray<$store, $order> {
+				self.bits
+			}
+
+			/// Views the underlying bit-array.
+			#[inline]
+			pub fn as_bitarray(&self) -> &$crate::array::BitArray<$store, $order> {
+				&self.bits
+			}
+
+			/// Views the underlying bit-array mutably.
+			#[inline]
+			pub fn as_mut_bitarray(
+				&mut self,
+			) -> &mut $crate::array::BitArray<$store, $order> {
+				&mut self.bits
+			}
+
+			$(
+				$crate::__bitfield_field! {
+					$(#[$fattr])*
+					$fvis $get, $set, $with : $typ @ $bits $(, $endian)?
+				}
+			)*
+		}
+
+		impl $crate::macros::internal::core::fmt::Debug for $name {
+			#[inline]
+			fn fmt(
+				&self,
+				fmt: &mut $crate::macros::internal::core::fmt::Formatter,
+			) -> $crate::macros::internal::core::fmt::Result {
+				fmt.debug_struct(stringify!($name))
+					$(.field(stringify!($get), &self.$get()))*
+					.finish()
+			}
+		}
+	};
+}
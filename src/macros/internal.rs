@@ -358,6 +358,94 @@ macro_rules! __ty_from_bytes {
 	};
 }
 
+/// Generates the accessors for one field of a [`bitfield!`] struct.
+///
+/// [`bitfield!`]: crate::bitfield
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bitfield_field {
+	//  Single-bit flags are read and written directly.
+	(
+		$(#[$attr:meta])*
+		$vis:vis $get:ident, $set:ident, $with:ident : bool @ $bit:expr
+	) => {
+		$(#[$attr])*
+		#[inline]
+		$vis fn $get(&self) -> bool {
+			self.bits[$bit]
+		}
+
+		#[doc = concat!("Sets the `", stringify!($get), "` flag.")]
+		#[inline]
+		$vis fn $set(&mut self, value: bool) -> &mut Self {
+			self.bits.set($bit, value);
+			self
+		}
+
+		#[doc = concat!(
+			"Returns `self` with the `", stringify!($get), "` flag replaced."
+		)]
+		#[inline]
+		#[must_use]
+		$vis fn $with(mut self, value: bool) -> Self {
+			self.$set(value);
+			self
+		}
+	};
+
+	//  Integer fields use the `BitField` method named by the endianness marker.
+	(
+		$(#[$attr:meta])*
+		$vis:vis $get:ident, $set:ident, $with:ident
+		: $typ:ident @ $bits:expr $(, $endian:ident)?
+	) => {
+		$(#[$attr])*
+		#[inline]
+		$vis fn $get(&self) -> $typ {
+			$crate::__bitfield_field!(@load $($endian)?; self.bits[$bits])
+		}
+
+		#[doc = concat!(
+			"Sets the `", stringify!($get), "` field. Bits of `value` that do not ",
+			"fit in the field are discarded."
+		)]
+		#[inline]
+		$vis fn $set(&mut self, value: $typ) -> &mut Self {
+			$crate::__bitfield_field!(@store $($endian)?; self.bits[$bits], value);
+			self
+		}
+
+		#[doc = concat!(
+			"Returns `self` with the `", stringify!($get), "` field replaced."
+		)]
+		#[inline]
+		#[must_use]
+		$vis fn $with(mut self, value: $typ) -> Self {
+			self.$set(value);
+			self
+		}
+	};
+
+	(@load ; $field:expr) => {
+		$crate::field::BitField::load(&$field)
+	};
+	(@load le; $field:expr) => {
+		$crate::field::BitField::load_le(&$field)
+	};
+	(@load be; $field:expr) => {
+		$crate::field::BitField::load_be(&$field)
+	};
+	(@store ; $field:expr, $value:expr) => {
+		$crate::field::BitField::store(&mut $field, $value)
+	};
+	(@store le; $field:expr, $value:expr) => {
+		$crate::field::BitField::store_le(&mut $field, $value)
+	};
+	(@store be; $field:expr, $value:expr) => {
+		$crate::field::BitField::store_be(&mut $field, $value)
+	};
+}
+
 /// Constructs a `u8` from bits applied in `Lsb0` order (`a` low, `h` high).
 #[doc(hidden)]
 #[inline(always)]
@@ -585,3 +585,57 @@ fn make_elem() {
 		invoke_make_elem!(Cell<usize> as usize, crate::order::Lsb0; 0, 0, 1, 1);
 	assert_eq!(cell.get(), 12);
 }
+
+crate::bitfield! {
+	/// A bit-field struct that exercises every field kind.
+	#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+	struct Fields: [u16; 2], Msb0 {
+		/// A flag.
+		flag, set_flag, with_flag: bool @ 0;
+		pub(crate) small, set_small, with_small: u8 @ 1 .. 4;
+		signed, set_signed, with_signed: i8 @ 4 .. 8;
+		big, set_big, with_big: u16 @ 8 .. 24, be;
+		little, set_little, with_little: u16 @ 8 .. 24, le;
+		tail, set_tail, with_tail: i16 @ 24 .. 32, be;
+	}
+}
+
+#[test]
+fn bitfield_struct() {
+	let mut fields = Fields::default()
+		.with_flag(true)
+		.with_small(0b1101)
+		.with_signed(-3)
+		.with_big(0x1234);
+	assert!(fields.flag());
+	assert_eq!(fields.small(), 0b101);
+	assert_eq!(fields.signed(), -3);
+	assert_eq!(fields.big(), 0x1234);
+	assert_eq!(fields.little(), 0x3412);
+	assert_eq!(fields.into_bitarray().into_inner(), [0xDD12, 0x3400]);
+
+	fields.set_little(0x7856).set_flag(false);
+	assert_eq!(fields.big(), 0x5678);
+	fields = fields.with_tail(-1);
+	assert_eq!(fields.tail(), -1);
+	assert_eq!(fields.as_bitarray()[24 ..], bits![1; 8]);
+	fields.as_mut_bitarray()[28 ..].fill(false);
+	assert_eq!(fields.tail(), -16);
+	assert_ne!(fields, Fields::ZERO);
+	assert_eq!(
+		Fields::from_bitarray(fields.into_bitarray()).small(),
+		fields.small(),
+	);
+
+	#[cfg(feature = "alloc")]
+	{
+		#[cfg(not(feature = "std"))]
+		use alloc::format;
+
+		assert_eq!(
+			format!("{:?}", Fields::ZERO.with_signed(-1)),
+			"Fields { flag: false, small: 0, signed: -1, big: 0, little: 0, \
+			 tail: 0 }",
+		);
+	}
+}
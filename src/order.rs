@@ -221,6 +221,79 @@ unsafe impl BitOrder for Msb0 {
 	}
 }
 
+/// Implements an ordering as a fixed rearrangement of the bits of one of the
+/// contiguous orderings.
+///
+/// Each rearrangement exchanges bit positions by XOR with a constant, so it is
+/// its own inverse, carries one-hot selectors to one-hot selectors, and maps
+/// the mask of an index range in the base ordering directly to the mask of
+/// that range in the new ordering.
+macro_rules! swapped {
+	($($(#[$attr:meta])* $order:ident => $base:ident, $swap:ident, $flip:expr);+ $(;)?) => { $(
+		$(#[$attr])*
+		#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+		pub struct $order;
+
+		unsafe impl BitOrder for $order {
+			#[inline]
+			fn at<R>(index: BitIdx<R>) -> BitPos<R>
+			where R: BitRegister {
+				let pos = $base::at::<R>(index).into_inner();
+				unsafe { BitPos::new_unchecked(pos ^ $flip(R::MASK)) }
+			}
+
+			#[inline]
+			fn select<R>(index: BitIdx<R>) -> BitSel<R>
+			where R: BitRegister {
+				let sel = $base::select::<R>(index).into_inner();
+				unsafe { BitSel::new_unchecked($swap(sel)) }
+			}
+
+			#[inline]
+			fn mask<R>(
+				from: impl Into<Option<BitIdx<R>>>,
+				upto: impl Into<Option<BitEnd<R>>>,
+			) -> BitMask<R>
+			where
+				R: BitRegister,
+			{
+				BitMask::new($swap($base::mask(from, upto).into_inner()))
+			}
+		}
+	)+ };
+}
+
+swapped! {
+	#[doc = include_str!("../doc/order/Lsb0ByteSwap.md")]
+	Lsb0ByteSwap => Lsb0, swap_bytes, |mask: u8| mask & !7;
+	#[doc = include_str!("../doc/order/Msb0ByteSwap.md")]
+	Msb0ByteSwap => Msb0, swap_bytes, |mask: u8| mask & !7;
+	#[doc = include_str!("../doc/order/Lsb0NibbleSwap.md")]
+	Lsb0NibbleSwap => Lsb0, swap_nibbles, |_: u8| 4;
+	#[doc = include_str!("../doc/order/Msb0NibbleSwap.md")]
+	Msb0NibbleSwap => Msb0, swap_nibbles, |_: u8| 4;
+}
+
+/// Reverses the order of the bytes in an element.
+///
+/// This moves the bit at position `n` to position `n ^ (R::MASK & !7)`.
+#[inline]
+pub(crate) fn swap_bytes<R>(elem: R) -> R
+where R: BitRegister {
+	elem.swap_bytes()
+}
+
+/// Exchanges the high and low halves of each byte in an element.
+///
+/// This moves the bit at position `n` to position `n ^ 4`.
+#[inline]
+pub(crate) fn swap_nibbles<R>(elem: R) -> R
+where R: BitRegister {
+	//  `0x0F` repeated in every byte: `0xFF..` divided by `0x11`.
+	let low = R::ALL / (R::ONE << 4 | R::ONE);
+	(elem & low) << 4 | (elem >> 4) & low
+}
+
 #[cfg(target_endian = "little")]
 #[doc = include_str!("../doc/order/LocalBits.md")]
 pub use self::Lsb0 as LocalBits;
@@ -428,6 +501,26 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn swapped_positions() {
+		let pos = |n: u8| BitIdx::<u32>::new(n).unwrap();
+		assert_eq!(Lsb0ByteSwap::at(pos(0)).into_inner(), 24);
+		assert_eq!(Lsb0ByteSwap::at(pos(9)).into_inner(), 17);
+		assert_eq!(Msb0ByteSwap::at(pos(0)).into_inner(), 7);
+		assert_eq!(Msb0ByteSwap::at(pos(9)).into_inner(), 14);
+		assert_eq!(Lsb0NibbleSwap::at(pos(0)).into_inner(), 4);
+		assert_eq!(Lsb0NibbleSwap::at(pos(9)).into_inner(), 13);
+		assert_eq!(Msb0NibbleSwap::at(pos(0)).into_inner(), 27);
+		assert_eq!(Msb0NibbleSwap::at(pos(9)).into_inner(), 18);
+
+		assert_eq!(swap_nibbles(0x1234_5678u32), 0x2143_6587);
+		for n in 0 .. 8 {
+			let idx = BitIdx::<u8>::new(n).unwrap();
+			assert_eq!(Lsb0ByteSwap::at(idx), Lsb0::at(idx));
+			assert_eq!(Msb0ByteSwap::at(idx), Msb0::at(idx));
+		}
+	}
+
 	//  Split these out into individual test functions so they can parallelize.
 
 	mod lsb0 {
@@ -528,4 +621,136 @@ mod tests {
 			verify_for_type::<usize, HiLo>(cfg!(feature = "verbose"));
 		}
 	}
+
+	mod lsb0_byte_swap {
+		use super::*;
+
+		#[test]
+		fn verify_u8() {
+			verify_for_type::<u8, Lsb0ByteSwap>(cfg!(feature = "verbose"));
+		}
+
+		#[test]
+		#[cfg(not(tarpaulin))]
+		fn verify_u16() {
+			verify_for_type::<u16, Lsb0ByteSwap>(cfg!(feature = "verbose"));
+		}
+
+		#[test]
+		#[cfg(not(tarpaulin))]
+		fn verify_u32() {
+			verify_for_type::<u32, Lsb0ByteSwap>(cfg!(feature = "verbose"));
+		}
+
+		#[test]
+		#[cfg(all(target_pointer_width = "64", not(tarpaulin)))]
+		fn verify_u64() {
+			verify_for_type::<u64, Lsb0ByteSwap>(cfg!(feature = "verbose"));
+		}
+
+		#[test]
+		#[cfg(not(tarpaulin))]
+		fn verify_usize() {
+			verify_for_type::<usize, Lsb0ByteSwap>(cfg!(feature = "verbose"));
+		}
+	}
+
+	mod msb0_byte_swap {
+		use super::*;
+
+		#[test]
+		fn verify_u8() {
+			verify_for_type::<u8, Msb0ByteSwap>(cfg!(feature = "verbose"));
+		}
+
+		#[test]
+		#[cfg(not(tarpaulin))]
+		fn verify_u16() {
+			verify_for_type::<u16, Msb0ByteSwap>(cfg!(feature = "verbose"));
+		}
+
+		#[test]
+		#[cfg(not(tarpaulin))]
+		fn verify_u32() {
+			verify_for_type::<u32, Msb0ByteSwap>(cfg!(feature = "verbose"));
+		}
+
+		#[test]
+		#[cfg(all(target_pointer_width = "64", not(tarpaulin)))]
+		fn verify_u64() {
+			verify_for_type::<u64, Msb0ByteSwap>(cfg!(feature = "verbose"));
+		}
+
+		#[test]
+		#[cfg(not(tarpaulin))]
+		fn verify_usize() {
+			verify_for_type::<usize, Msb0ByteSwap>(cfg!(feature = "verbose"));
+		}
+	}
+
+	mod lsb0_nibble_swap {
+		use super::*;
+
+		#[test]
+		fn verify_u8() {
+			verify_for_type::<u8, Lsb0NibbleSwap>(cfg!(feature = "verbose"));
+		}
+
+		#[test]
+		#[cfg(not(tarpaulin))]
+		fn verify_u16() {
+			verify_for_type::<u16, Lsb0NibbleSwap>(cfg!(feature = "verbose"));
+		}
+
+		#[test]
+		#[cfg(not(tarpaulin))]
+		fn verify_u32() {
+			verify_for_type::<u32, Lsb0NibbleSwap>(cfg!(feature = "verbose"));
+		}
+
+		#[test]
+		#[cfg(all(target_pointer_width = "64", not(tarpaulin)))]
+		fn verify_u64() {
+			verify_for_type::<u64, Lsb0NibbleSwap>(cfg!(feature = "verbose"));
+		}
+
+		#[test]
+		#[cfg(not(tarpaulin))]
+		fn verify_usize() {
+			verify_for_type::<usize, Lsb0NibbleSwap>(cfg!(feature = "verbose"));
+		}
+	}
+
+	mod msb0_nibble_swap {
+		use super::*;
+
+		#[test]
+		fn verify_u8() {
+			verify_for_type::<u8, Msb0NibbleSwap>(cfg!(feature = "verbose"));
+		}
+
+		#[test]
+		#[cfg(not(tarpaulin))]
+		fn verify_u16() {
+			verify_for_type::<u16, Msb0NibbleSwap>(cfg!(feature = "verbose"));
+		}
+
+		#[test]
+		#[cfg(not(tarpaulin))]
+		fn verify_u32() {
+			verify_for_type::<u32, Msb0NibbleSwap>(cfg!(feature = "verbose"));
+		}
+
+		#[test]
+		#[cfg(all(target_pointer_width = "64", not(tarpaulin)))]
+		fn verify_u64() {
+			verify_for_type::<u64, Msb0NibbleSwap>(cfg!(feature = "verbose"));
+		}
+
+		#[test]
+		#[cfg(not(tarpaulin))]
+		fn verify_usize() {
+			verify_for_type::<usize, Msb0NibbleSwap>(cfg!(feature = "verbose"));
+		}
+	}
 }
@@ -0,0 +1,242 @@
+#![doc = include_str!("../doc/parse.md")]
+
+use core::fmt::{
+	self,
+	Display,
+	Formatter,
+};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[doc = include_str!("../doc/parse/ParseBitsError.md")]
+pub struct ParseBitsError {
+	/// The byte offset in the source text at which parsing failed.
+	pos:  usize,
+	/// The reason that parsing failed.
+	kind: ParseBitsErrorKind,
+}
+
+impl ParseBitsError {
+	/// Creates a new parse error.
+	#[inline]
+	pub(crate) fn new(pos: usize, kind: ParseBitsErrorKind) -> Self {
+		Self { pos, kind }
+	}
+
+	/// Gets the byte offset in the source text at which parsing failed.
+	///
+	/// For errors about an individual character, this is the offset of that
+	/// character. For errors about the text as a whole, such as an unclosed
+	/// list or a too-short bit-array, this is the offset of the end of the
+	/// text.
+	#[inline]
+	pub fn position(&self) -> usize {
+		self.pos
+	}
+
+	/// Gets the reason that parsing failed.
+	#[inline]
+	pub fn kind(&self) -> ParseBitsErrorKind {
+		self.kind
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Display for ParseBitsError {
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(fmt, "{} at position {}", self.kind, self.pos)
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseBitsError {}
+
+/// The reasons that a bit-string can fail to parse.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ParseBitsErrorKind {
+	/// A character is not a digit in the radix of the word containing it.
+	InvalidDigit(char),
+	/// A word, or the whole text, contains no digits.
+	Empty,
+	/// A list opened with `[` is not closed with `]`.
+	Unclosed,
+	/// Text follows the `]` that closes a list.
+	Trailing,
+	/// The text produces more bits than the destination can hold. This
+	/// carries the destination’s length.
+	TooLong(usize),
+	/// The text produces fewer bits than the destination requires.
+	TooShort {
+		/// The number of bits that the text produced.
+		actual:   usize,
+		/// The length of the destination.
+		expected: usize,
+	},
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Display for ParseBitsErrorKind {
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		match *self {
+			Self::InvalidDigit(chr) => write!(fmt, "invalid digit {:?}", chr),
+			Self::Empty => fmt.write_str("no digits found"),
+			Self::Unclosed => fmt.write_str("unclosed `[` list"),
+			Self::Trailing => fmt.write_str("unexpected text after `]`"),
+			Self::TooLong(len) => {
+				write!(fmt, "too many bits for a destination of {} bits", len)
+			},
+			Self::TooShort { actual, expected } => write!(
+				fmt,
+				"{} bits cannot fill a destination of {} bits",
+				actual, expected,
+			),
+		}
+	}
+}
+
+/// The radices in which a bit-string word can be written.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub(crate) enum Radix {
+	/// Base 2, prefixed by `0b`.
+	Bin,
+	/// Base 8, prefixed by `0o`.
+	Oct,
+	/// Base 16, prefixed by `0x`.
+	Hex,
+}
+
+impl Radix {
+	/// Gets the number of bits that each digit produces.
+	#[inline]
+	fn width(self) -> u32 {
+		match self {
+			Self::Bin => 1,
+			Self::Oct => 3,
+			Self::Hex => 4,
+		}
+	}
+
+	/// Finds the radix named by a prefix letter.
+	#[inline]
+	fn from_prefix(byte: u8) -> Option<Self> {
+		match byte.to_ascii_lowercase() {
+			b'b' => Some(Self::Bin),
+			b'o' => Some(Self::Oct),
+			b'x' => Some(Self::Hex),
+			_ => None,
+		}
+	}
+}
+
+/** Parses a bit-string, feeding each bit it produces to a sink.
+
+## Parameters
+
+- `src`: The text to parse.
+- `radix`: If this is `Some`, every word is read in that radix, and only its
+  prefix is stripped. If it is `None`, each word’s radix is chosen by its
+  prefix, and unprefixed words are binary.
+- `push`: Receives each bit in order. It returns `false` when the destination
+  cannot hold any more bits, which stops parsing.
+
+## Returns
+
+The number of bits that were fed to `push`, or the first error encountered.
+**/
+#[inline]
+pub(crate) fn parse<F>(
+	src: &str,
+	radix: Option<Radix>,
+	mut push: F,
+) -> Result<usize, ParseBitsError>
+where
+	F: FnMut(bool) -> bool,
+{
+	let end = src.trim_end().len();
+	let start = end - src[.. end].trim_start().len();
+	let text = &src[start .. end];
+	let mut count = 0;
+
+	if !text.starts_with('[') {
+		parse_word(text, start, radix, &mut count, &mut push)?;
+		return Ok(count);
+	}
+
+	let close = match text.find(']') {
+		Some(idx) => start + idx,
+		None => {
+			return Err(ParseBitsError::new(end, ParseBitsErrorKind::Unclosed));
+		},
+	};
+	if close + 1 != end {
+		return Err(ParseBitsError::new(
+			close + 1,
+			ParseBitsErrorKind::Trailing,
+		));
+	}
+
+	let mut base = start + 1;
+	let mut entries = src[base .. close].split(',').peekable();
+	while let Some(entry) = entries.next() {
+		let head = entry.len() - entry.trim_start().len();
+		let word = entry.trim();
+		//  The list may be empty, or end with a trailing comma.
+		if !(word.is_empty() && entries.peek().is_none()) {
+			parse_word(word, base + head, radix, &mut count, &mut push)?;
+		}
+		base += entry.len() + 1;
+	}
+	Ok(count)
+}
+
+/// Parses a single word of digits, with an optional prefix.
+#[inline]
+fn parse_word<F>(
+	word: &str,
+	base: usize,
+	radix: Option<Radix>,
+	count: &mut usize,
+	push: &mut F,
+) -> Result<(), ParseBitsError>
+where
+	F: FnMut(bool) -> bool,
+{
+	let bytes = word.as_bytes();
+	let prefix = match bytes {
+		[b'0', pfx, ..] => Radix::from_prefix(*pfx),
+		_ => None,
+	};
+	let (radix, skip) = match (radix, prefix) {
+		(None, Some(pfx)) => (pfx, 2),
+		(None, None) => (Radix::Bin, 0),
+		(Some(radix), Some(pfx)) if radix == pfx => (radix, 2),
+		(Some(radix), _) => (radix, 0),
+	};
+	let width = radix.width();
+
+	let mut empty = true;
+	for (idx, chr) in word[skip ..].char_indices() {
+		if chr == '_' {
+			continue;
+		}
+		let pos = base + skip + idx;
+		let digit = chr.to_digit(1 << width).ok_or_else(|| {
+			ParseBitsError::new(pos, ParseBitsErrorKind::InvalidDigit(chr))
+		})?;
+		empty = false;
+		for shamt in (0 .. width).rev() {
+			if !push(digit >> shamt & 1 != 0) {
+				return Err(ParseBitsError::new(
+					pos,
+					ParseBitsErrorKind::TooLong(*count),
+				));
+			}
+			*count += 1;
+		}
+	}
+	if empty {
+		return Err(ParseBitsError::new(base, ParseBitsErrorKind::Empty));
+	}
+	Ok(())
+}
@@ -351,7 +351,7 @@ where
 	#[inline]
 	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
 		unsafe { self.bitptr.span_unchecked(1) }
-			.render(fmt, "Ref", &[("bit", &self.data as &dyn Debug)])
+			.render(fmt, "BitRef", &[("bit", &self.data as &dyn Debug)])
 	}
 }
 
@@ -633,8 +633,8 @@ where
 	///
 	/// - `&self`
 	/// - `fmt`: The formatter into which the pointer is rendered.
-	/// - `name`: The suffix of the structure rendering its pointer. The `Bit`
-	///   prefix is applied to the object type name in this format.
+	/// - `name`: The name of the structure rendering its pointer, written
+	///   verbatim as the object type name in this format.
 	/// - `fields`: Any additional fields in the object’s debug info to be
 	///   rendered.
 	///
@@ -644,7 +644,7 @@ where
 	///
 	/// # Behavior
 	///
-	/// This function writes `Bit{name}<{ord}, {type}> {{ {fields } }}` into the
+	/// This function writes `{name}<{ord}, {type}> {{ {fields } }}` into the
 	/// `fmt` formatter, where `{fields}` includes the address, head index, and
 	/// bit length of the pointer, as well as any additional fields provided by
 	/// the caller.
@@ -663,7 +663,7 @@ where
 	) -> fmt::Result {
 		write!(
 			fmt,
-			"Bit{}<{}, {}>",
+			"{}<{}, {}>",
 			name,
 			any::type_name::<T::Mem>(),
 			any::type_name::<O>(),
@@ -755,7 +755,7 @@ where
 {
 	#[inline]
 	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
-		self.render(fmt, "Span", None)
+		self.render(fmt, "BitSpan", None)
 	}
 }
 
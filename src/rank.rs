@@ -0,0 +1,492 @@
+#![doc = include_str!("../doc/rank.md")]
+#![cfg(feature = "alloc")]
+
+use alloc::vec::Vec;
+use core::ops::Deref;
+
+use crate::{
+	mem::bits_of,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+/// The number of bits covered by one entry in the block table.
+const BLOCK_BITS: usize = 512;
+
+/// The number of bits covered by one entry in the superblock table.
+///
+/// This must be small enough that every relative count within a superblock
+/// fits in a `u16`.
+const SUPER_BITS: usize = 1 << 16;
+
+/// The number of block-table entries in each superblock.
+const BLOCKS_PER_SUPER: usize = SUPER_BITS / BLOCK_BITS;
+
+/// The width of the chunks scanned when selecting within a block.
+const WORD_BITS: usize = bits_of::<usize>();
+
+#[derive(Clone, Debug)]
+#[doc = include_str!("../doc/rank/RankSelect.md")]
+pub struct RankSelect<B> {
+	/// The indexed bit-slice.
+	bits:   B,
+	/// The number of `1` bits before the start of each superblock.
+	supers: Vec<usize>,
+	/// The number of `1` bits between the start of each block’s superblock and
+	/// the start of the block.
+	blocks: Vec<u16>,
+	/// The total number of `1` bits in the indexed bit-slice.
+	ones:   usize,
+}
+
+impl<B, T, O> RankSelect<B>
+where
+	B: Deref<Target = BitSlice<T, O>>,
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Builds a rank/select index over a bit-slice.
+	///
+	/// This walks the entire bit-slice once, counting its `1` bits in
+	/// block-sized chunks.
+	///
+	/// ## Parameters
+	///
+	/// - `bits`: Any handle that dereferences to a `BitSlice`. The index
+	///   retains it, and will not permit it to be modified.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rank::RankSelect;
+	///
+	/// let bv = bitvec![0, 1, 0, 1, 1];
+	/// let borrowed = RankSelect::new(bv.as_bitslice());
+	/// assert_eq!(borrowed.count_ones(), 3);
+	///
+	/// let owned = RankSelect::new(bv);
+	/// assert_eq!(owned.count_zeros(), 2);
+	/// ```
+	#[inline]
+	pub fn new(bits: B) -> Self {
+		let len = bits.len();
+		let mut supers = Vec::with_capacity(len / SUPER_BITS + 1);
+		let mut blocks = Vec::with_capacity(len / BLOCK_BITS + 1);
+		let mut ones = 0;
+
+		for superblock in bits.chunks(SUPER_BITS) {
+			supers.push(ones);
+			let mut rel = 0usize;
+			for block in superblock.chunks(BLOCK_BITS) {
+				blocks.push(rel as u16);
+				rel += block.count_ones();
+			}
+			ones += rel;
+		}
+
+		Self {
+			bits,
+			supers,
+			blocks,
+			ones,
+		}
+	}
+
+	/// Views the indexed bit-slice.
+	#[inline]
+	pub fn as_bitslice(&self) -> &BitSlice<T, O> {
+		&self.bits
+	}
+
+	/// Removes the index, returning the bit-slice handle it wrapped.
+	#[inline]
+	pub fn into_inner(self) -> B {
+		self.bits
+	}
+
+	/// Gets the length (in bits) of the indexed bit-slice.
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.bits.len()
+	}
+
+	/// Tests whether the indexed bit-slice is empty.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.bits.is_empty()
+	}
+
+	/// Counts the number of bits set to `1` in the indexed bit-slice.
+	///
+	/// Unlike [`BitSlice::count_ones`], this is a stored value and does not
+	/// inspect the bit-slice.
+	///
+	/// [`BitSlice::count_ones`]: crate::slice::BitSlice::count_ones
+	#[inline]
+	pub fn count_ones(&self) -> usize {
+		self.ones
+	}
+
+	/// Counts the number of bits cleared to `0` in the indexed bit-slice.
+	///
+	/// Unlike [`BitSlice::count_zeros`], this is a stored value and does not
+	/// inspect the bit-slice.
+	///
+	/// [`BitSlice::count_zeros`]: crate::slice::BitSlice::count_zeros
+	#[inline]
+	pub fn count_zeros(&self) -> usize {
+		self.len() - self.ones
+	}
+
+	/// Counts the number of bits set to `1` before an index.
+	///
+	/// This is equivalent to `bits[.. index].count_ones()`, but runs in
+	/// constant time.
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `index`: The exclusive end of the counted region. It may be equal to
+	///   `self.len()`.
+	///
+	/// ## Returns
+	///
+	/// The number of `1` bits in `0 .. index`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is greater than `self.len()`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rank::RankSelect;
+	///
+	/// let bits = bits![1, 0, 1, 1, 0];
+	/// let index = RankSelect::new(bits);
+	/// assert_eq!(index.rank1(0), 0);
+	/// assert_eq!(index.rank1(3), 2);
+	/// assert_eq!(index.rank1(5), 3);
+	/// ```
+	#[inline]
+	pub fn rank1(&self, index: usize) -> usize {
+		let len = self.len();
+		assert!(
+			index <= len,
+			"rank index {} out of range for bit-slice of length {}",
+			index,
+			len,
+		);
+		if index == len {
+			return self.ones;
+		}
+
+		let block = index / BLOCK_BITS;
+		let start = block * BLOCK_BITS;
+		self.supers[index / SUPER_BITS]
+			+ self.blocks[block] as usize
+			+ unsafe { self.bits.get_unchecked(start .. index) }.count_ones()
+	}
+
+	/// Counts the number of bits cleared to `0` before an index.
+	///
+	/// This is equivalent to `bits[.. index].count_zeros()`, but runs in
+	/// constant time.
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `index`: The exclusive end of the counted region. It may be equal to
+	///   `self.len()`.
+	///
+	/// ## Returns
+	///
+	/// The number of `0` bits in `0 .. index`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is greater than `self.len()`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rank::RankSelect;
+	///
+	/// let bits = bits![1, 0, 1, 1, 0];
+	/// let index = RankSelect::new(bits);
+	/// assert_eq!(index.rank0(2), 1);
+	/// assert_eq!(index.rank0(5), 2);
+	/// ```
+	#[inline]
+	pub fn rank0(&self, index: usize) -> usize {
+		index - self.rank1(index)
+	}
+
+	/// Finds the index of the `rank`th bit set to `1`.
+	///
+	/// This is equivalent to `bits.iter_ones().nth(rank)`, but does not scan
+	/// the bit-slice from its start.
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `rank`: The zero-based count of the `1` bit to find. `0` finds the
+	///   first `1` bit, `1` finds the second, and so on.
+	///
+	/// ## Returns
+	///
+	/// The index of the requested `1` bit, or `None` if the bit-slice has
+	/// `rank` or fewer `1` bits. When this returns `Some(idx)`, then
+	/// `self.rank1(idx) == rank` and `self.as_bitslice()[idx]` is `true`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rank::RankSelect;
+	///
+	/// let bits = bits![0, 0, 1, 0, 1];
+	/// let index = RankSelect::new(bits);
+	/// assert_eq!(index.select1(0), Some(2));
+	/// assert_eq!(index.select1(1), Some(4));
+	/// assert!(index.select1(2).is_none());
+	/// ```
+	#[inline]
+	pub fn select1(&self, rank: usize) -> Option<usize> {
+		if rank >= self.ones {
+			return None;
+		}
+		let (block, rank) = self.find_block(
+			rank,
+			|sup| self.supers[sup],
+			|_, blk| self.blocks[blk] as usize,
+		);
+		Some(self.select_in_block(block, rank, true))
+	}
+
+	/// Finds the index of the `rank`th bit cleared to `0`.
+	///
+	/// This is equivalent to `bits.iter_zeros().nth(rank)`, but does not scan
+	/// the bit-slice from its start.
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `rank`: The zero-based count of the `0` bit to find. `0` finds the
+	///   first `0` bit, `1` finds the second, and so on.
+	///
+	/// ## Returns
+	///
+	/// The index of the requested `0` bit, or `None` if the bit-slice has
+	/// `rank` or fewer `0` bits. When this returns `Some(idx)`, then
+	/// `self.rank0(idx) == rank` and `self.as_bitslice()[idx]` is `false`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rank::RankSelect;
+	///
+	/// let bits = bits![1, 1, 0, 1, 0];
+	/// let index = RankSelect::new(bits);
+	/// assert_eq!(index.select0(0), Some(2));
+	/// assert_eq!(index.select0(1), Some(4));
+	/// assert!(index.select0(2).is_none());
+	/// ```
+	#[inline]
+	pub fn select0(&self, rank: usize) -> Option<usize> {
+		if rank >= self.count_zeros() {
+			return None;
+		}
+		let (block, rank) = self.find_block(
+			rank,
+			|sup| sup * SUPER_BITS - self.supers[sup],
+			|sup, blk| {
+				(blk - sup * BLOCKS_PER_SUPER) * BLOCK_BITS
+					- self.blocks[blk] as usize
+			},
+		);
+		Some(self.select_in_block(block, rank, false))
+	}
+}
+
+/// Select implementation details.
+impl<B, T, O> RankSelect<B>
+where
+	B: Deref<Target = BitSlice<T, O>>,
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Locates the block that contains a selected bit.
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `rank`: The zero-based count of the bit being selected. The caller
+	///   must ensure that it is less than the total number of such bits.
+	/// - `sup_count`: Computes the number of selected bits before a superblock.
+	/// - `blk_count`: Computes the number of selected bits between the start of
+	///   a superblock and one of its blocks.
+	///
+	/// ## Returns
+	///
+	/// - The index of the block containing the selected bit.
+	/// - The number of selected bits in that block that precede it.
+	fn find_block(
+		&self,
+		rank: usize,
+		sup_count: impl Fn(usize) -> usize,
+		blk_count: impl Fn(usize, usize) -> usize,
+	) -> (usize, usize) {
+		let sup = last_at_most(0 .. self.supers.len(), rank, &sup_count);
+		let rank = rank - sup_count(sup);
+
+		let first = sup * BLOCKS_PER_SUPER;
+		let last = self.blocks.len().min(first + BLOCKS_PER_SUPER);
+		let blk = last_at_most(first .. last, rank, |b| blk_count(sup, b));
+		(blk, rank - blk_count(sup, blk))
+	}
+
+	/// Finds a selected bit within one block.
+	///
+	/// The block is scanned in processor-word-sized chunks, counting each, in
+	/// order to find the chunk that contains the selected bit. Only that chunk
+	/// is searched for the bit itself.
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `block`: The index of the block containing the selected bit.
+	/// - `rank`: The number of selected bits in the block that precede it.
+	/// - `value`: The bit-value being selected.
+	///
+	/// ## Returns
+	///
+	/// The index of the selected bit in the whole bit-slice.
+	fn select_in_block(
+		&self,
+		block: usize,
+		mut rank: usize,
+		value: bool,
+	) -> usize {
+		let start = block * BLOCK_BITS;
+		let end = self.len().min(start + BLOCK_BITS);
+		let bits = unsafe { self.bits.get_unchecked(start .. end) };
+
+		let mut offset = start;
+		for chunk in bits.chunks(WORD_BITS) {
+			let count = if value {
+				chunk.count_ones()
+			}
+			else {
+				chunk.count_zeros()
+			};
+			if rank < count {
+				let found = if value {
+					chunk.iter_ones().nth(rank)
+				}
+				else {
+					chunk.iter_zeros().nth(rank)
+				};
+				return offset
+					+ found.expect("rank/select index is inconsistent");
+			}
+			rank -= count;
+			offset += chunk.len();
+		}
+		unreachable!("rank/select index is inconsistent");
+	}
+}
+
+/// Finds the last index in a range whose monotonic count does not exceed a
+/// target.
+///
+/// The first index in `range` must have a count of at most `target`.
+fn last_at_most(
+	range: core::ops::Range<usize>,
+	target: usize,
+	count: impl Fn(usize) -> usize,
+) -> usize {
+	let (mut lo, mut hi) = (range.start, range.end);
+	//  Invariant: `count(lo) <= target`, and every index at or above `hi` has
+	//  a count greater than `target`.
+	while hi - lo > 1 {
+		let mid = lo + (hi - lo) / 2;
+		if count(mid) <= target {
+			lo = mid;
+		}
+		else {
+			hi = mid;
+		}
+	}
+	lo
+}
+
+#[cfg(test)]
+mod tests {
+	use rand::random;
+
+	use super::*;
+	use crate::prelude::*;
+
+	/// Compares every query against a linear scan of the bit-slice.
+	fn check<T, O>(bits: &BitSlice<T, O>)
+	where
+		T: BitStore,
+		O: BitOrder,
+	{
+		let index = RankSelect::new(bits);
+		assert_eq!(index.count_ones(), bits.count_ones());
+		assert_eq!(index.count_zeros(), bits.count_zeros());
+
+		let (mut ones, mut zeros) = (0, 0);
+		for (idx, bit) in bits.iter().by_vals().enumerate() {
+			assert_eq!(index.rank1(idx), ones);
+			assert_eq!(index.rank0(idx), zeros);
+			if bit {
+				assert_eq!(index.select1(ones), Some(idx));
+				ones += 1;
+			}
+			else {
+				assert_eq!(index.select0(zeros), Some(idx));
+				zeros += 1;
+			}
+		}
+		assert_eq!(index.rank1(bits.len()), ones);
+		assert_eq!(index.rank0(bits.len()), zeros);
+		assert!(index.select1(ones).is_none());
+		assert!(index.select0(zeros).is_none());
+	}
+
+	#[test]
+	fn small() {
+		check(BitSlice::<u8, Lsb0>::empty());
+		check(bits![u8, Msb0; 0, 1, 1, 0, 1, 0, 0, 1]);
+		check(&bits![u16, Lsb0; 1; 40][3 ..]);
+		check(&bits![u32, Msb0; 0; 40][.. 37]);
+	}
+
+	#[test]
+	fn multiple_superblocks() {
+		let data = (0 .. 2100).map(|_| random::<u64>()).collect::<Vec<_>>();
+		check(&data.view_bits::<Lsb0>()[5 ..]);
+		check(&data.view_bits::<Msb0>()[.. 134_000]);
+
+		let mut sparse = BitVec::<u32, Msb0>::repeat(false, 200_000);
+		for idx in (0 .. 200_000).step_by(4099) {
+			sparse.set(idx, true);
+		}
+		check(&sparse);
+		check(&!sparse);
+	}
+
+	#[test]
+	#[should_panic]
+	fn rank_out_of_bounds() {
+		RankSelect::new(bits![0; 4]).rank1(5);
+	}
+}
@@ -0,0 +1,1261 @@
+#![doc = include_str!("../doc/roaring.md")]
+#![cfg(feature = "alloc")]
+
+use alloc::{
+	boxed::Box,
+	vec::Vec,
+};
+use core::{
+	cmp::{
+		self,
+		Ordering,
+	},
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	iter::{
+		FlatMap,
+		FromIterator,
+		FusedIterator,
+	},
+	ops::{
+		BitAnd,
+		BitAndAssign,
+		BitOr,
+		BitOrAssign,
+		RangeInclusive,
+		Sub,
+		SubAssign,
+	},
+	slice,
+};
+
+use crate::{
+	array::BitArray,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+/// The number of bit-slice indices covered by each container.
+const CONTAINER_BITS: usize = 1 << 16;
+
+/// The largest number of `1` bits that an array container holds before it is
+/// converted to a bitmap container.
+const ARRAY_MAX: usize = 4096;
+
+/// The size, in bytes, of a bitmap container’s storage.
+const BITMAP_BYTES: usize = CONTAINER_BITS / 8;
+
+/// The storage of a bitmap container.
+type Dense = BitArray<[u64; 1024], Lsb0>;
+
+#[derive(Clone, Default)]
+#[doc = include_str!("../doc/roaring/RoaringBitmap.md")]
+pub struct RoaringBitmap {
+	/// The non-empty containers, sorted by key. Each key is the index of the
+	/// first bit that its container covers, divided by `CONTAINER_BITS`.
+	containers: Vec<(usize, Container)>,
+}
+
+/// Constructors and conversions.
+impl RoaringBitmap {
+	/// Creates an empty compressed bitmap.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::roaring::RoaringBitmap;
+	///
+	/// let rb = RoaringBitmap::new();
+	/// assert!(rb.is_empty());
+	/// ```
+	#[inline]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Compresses the `1` bits of a bit-slice.
+	///
+	/// Each 65,536-bit section of `bits` that contains any `1` bits becomes an
+	/// array or bitmap container, according to how many it contains. Call
+	/// [`.optimize()`] afterwards to also consider run containers.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::{
+	///   prelude::*,
+	///   roaring::RoaringBitmap,
+	/// };
+	///
+	/// let bits = bits![0, 1, 0, 0, 1];
+	/// let rb = RoaringBitmap::from_bitslice(bits);
+	/// assert_eq!(rb.len(), 2);
+	/// assert!(rb.contains(4));
+	/// ```
+	///
+	/// [`.optimize()`]: Self::optimize
+	#[inline]
+	pub fn from_bitslice<T, O>(bits: &BitSlice<T, O>) -> Self
+	where
+		T: BitStore,
+		O: BitOrder,
+	{
+		let containers = bits
+			.chunks(CONTAINER_BITS)
+			.enumerate()
+			.filter_map(|(key, window)| {
+				Container::from_window(window).map(|cont| (key, cont))
+			})
+			.collect();
+		Self { containers }
+	}
+
+	/// Decompresses the bitmap into a bit-vector.
+	///
+	/// The bit-vector is just long enough to hold the highest `1` bit in the
+	/// bitmap, and is empty if the bitmap is empty.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::{
+	///   prelude::*,
+	///   roaring::RoaringBitmap,
+	/// };
+	///
+	/// let rb = [1, 4].iter().copied().collect::<RoaringBitmap>();
+	/// let bv = rb.to_bitvec::<u8, Msb0>();
+	/// assert_eq!(bv, bits![0, 1, 0, 0, 1]);
+	/// ```
+	#[inline]
+	pub fn to_bitvec<T, O>(&self) -> BitVec<T, O>
+	where
+		T: BitStore,
+		O: BitOrder,
+	{
+		let len = self.last_one().map_or(0, |idx| idx + 1);
+		let mut out = BitVec::repeat(false, len);
+		for (key, cont) in &self.containers {
+			let start = key * CONTAINER_BITS;
+			let end = cmp::min(start + CONTAINER_BITS, len);
+			let window = &mut out[start .. end];
+			match cont {
+				Container::Array(vals) => {
+					for &low in vals {
+						window.set(low as usize, true);
+					}
+				},
+				Container::Bitmap { bits, .. } => {
+					window.clone_from_bitslice(&bits[.. end - start]);
+				},
+				Container::Run(runs) => {
+					for &(first, last) in runs {
+						window[first as usize ..= last as usize].fill(true);
+					}
+				},
+			}
+		}
+		out
+	}
+
+	/// Converts each container to the smallest of the array, bitmap, and run
+	/// representations.
+	///
+	/// Insertion and removal keep containers in array or bitmap form, so sets
+	/// with long stretches of consecutive indices are best optimized after
+	/// they are built.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::{
+	///   prelude::*,
+	///   roaring::RoaringBitmap,
+	/// };
+	///
+	/// let mut rb = RoaringBitmap::from_bitslice(bits![1; 10_000]);
+	/// rb.optimize();
+	/// assert_eq!(rb.len(), 10_000);
+	/// ```
+	#[inline]
+	pub fn optimize(&mut self) {
+		for (_, cont) in &mut self.containers {
+			cont.optimize();
+		}
+	}
+}
+
+/// Queries and modification.
+impl RoaringBitmap {
+	/// Counts the `1` bits in the bitmap.
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.containers.iter().map(|(_, cont)| cont.len()).sum()
+	}
+
+	/// Tests if the bitmap has no `1` bits.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.containers.is_empty()
+	}
+
+	/// Tests if the bit at `index` is `1`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::roaring::RoaringBitmap;
+	///
+	/// let mut rb = RoaringBitmap::new();
+	/// rb.insert(1_000_000);
+	/// assert!(rb.contains(1_000_000));
+	/// assert!(!rb.contains(1_000_001));
+	/// ```
+	#[inline]
+	pub fn contains(&self, index: usize) -> bool {
+		let (key, low) = split(index);
+		self.find(key)
+			.map_or(false, |idx| self.containers[idx].1.contains(low))
+	}
+
+	/// Sets the bit at `index` to `1`.
+	///
+	/// ## Returns
+	///
+	/// `true` if the bit was previously `0`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::roaring::RoaringBitmap;
+	///
+	/// let mut rb = RoaringBitmap::new();
+	/// assert!(rb.insert(5));
+	/// assert!(!rb.insert(5));
+	/// ```
+	#[inline]
+	pub fn insert(&mut self, index: usize) -> bool {
+		let (key, low) = split(index);
+		match self.find(key) {
+			Ok(idx) => self.containers[idx].1.insert(low),
+			Err(idx) => {
+				self.containers
+					.insert(idx, (key, Container::Array([low].to_vec())));
+				true
+			},
+		}
+	}
+
+	/// Sets the bit at `index` to `0`.
+	///
+	/// ## Returns
+	///
+	/// `true` if the bit was previously `1`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::roaring::RoaringBitmap;
+	///
+	/// let mut rb = RoaringBitmap::new();
+	/// rb.insert(5);
+	/// assert!(rb.remove(5));
+	/// assert!(!rb.remove(5));
+	/// assert!(rb.is_empty());
+	/// ```
+	#[inline]
+	pub fn remove(&mut self, index: usize) -> bool {
+		let (key, low) = split(index);
+		let idx = match self.find(key) {
+			Ok(idx) => idx,
+			Err(_) => return false,
+		};
+		let cont = &mut self.containers[idx].1;
+		let out = cont.remove(low);
+		if cont.len() == 0 {
+			self.containers.remove(idx);
+		}
+		out
+	}
+
+	/// Finds the index of the first `1` bit in the bitmap.
+	#[inline]
+	pub fn first_one(&self) -> Option<usize> {
+		self.iter_ones().next()
+	}
+
+	/// Finds the index of the last `1` bit in the bitmap.
+	#[inline]
+	pub fn last_one(&self) -> Option<usize> {
+		self.iter_ones().next_back()
+	}
+
+	/// Enumerates the index of each `1` bit in the bitmap, in ascending order.
+	///
+	/// This yields the same sequence as [`BitSlice::iter_ones`] on the
+	/// decompressed bit-slice.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::{
+	///   prelude::*,
+	///   roaring::RoaringBitmap,
+	/// };
+	///
+	/// let bits = bits![0, 1, 0, 0, 1];
+	/// let rb = RoaringBitmap::from_bitslice(bits);
+	/// assert!(rb.iter_ones().eq(bits.iter_ones()));
+	/// ```
+	///
+	/// [`BitSlice::iter_ones`]: crate::slice::BitSlice::iter_ones
+	#[inline]
+	pub fn iter_ones(&self) -> IterOnes {
+		let func: fn(&(usize, Container)) -> ContainerIter =
+			Container::iter_keyed;
+		IterOnes {
+			inner: self.containers.iter().flat_map(func),
+		}
+	}
+
+	/// Binary-searches for the container with a given key.
+	#[inline]
+	fn find(&self, key: usize) -> Result<usize, usize> {
+		self.containers.binary_search_by_key(&key, |&(key, _)| key)
+	}
+
+	/// Gets the container with a given key, if it exists.
+	#[inline]
+	fn get(&self, key: usize) -> Option<&Container> {
+		self.find(key).ok().map(|idx| &self.containers[idx].1)
+	}
+}
+
+/// Set operations.
+impl RoaringBitmap {
+	/// Produces the union of two bitmaps: the bits that are `1` in either.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::roaring::RoaringBitmap;
+	///
+	/// let a = [1, 2].iter().copied().collect::<RoaringBitmap>();
+	/// let b = [2, 100_000].iter().copied().collect::<RoaringBitmap>();
+	/// assert!(a.union(&b).iter_ones().eq([1, 2, 100_000].iter().copied()));
+	/// ```
+	#[inline]
+	pub fn union(&self, other: &Self) -> Self {
+		let mut containers = Vec::new();
+		let mut lhs = self.containers.iter().peekable();
+		let mut rhs = other.containers.iter().peekable();
+		loop {
+			let next = match (lhs.peek(), rhs.peek()) {
+				(Some((lk, lc)), Some((rk, rc))) => match lk.cmp(rk) {
+					Ordering::Less => lhs.next().cloned(),
+					Ordering::Greater => rhs.next().cloned(),
+					Ordering::Equal => {
+						let out = lc.union(rc).map(|cont| (*lk, cont));
+						lhs.next();
+						rhs.next();
+						out
+					},
+				},
+				(Some(_), None) => lhs.next().cloned(),
+				(None, Some(_)) => rhs.next().cloned(),
+				(None, None) => break,
+			};
+			containers.extend(next);
+		}
+		Self { containers }
+	}
+
+	/// Produces the intersection of two bitmaps: the bits that are `1` in
+	/// both.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::roaring::RoaringBitmap;
+	///
+	/// let a = [1, 2].iter().copied().collect::<RoaringBitmap>();
+	/// let b = [2, 100_000].iter().copied().collect::<RoaringBitmap>();
+	/// assert!(a.intersection(&b).iter_ones().eq([2].iter().copied()));
+	/// ```
+	#[inline]
+	pub fn intersection(&self, other: &Self) -> Self {
+		self.filter_map(|key, cont| {
+			other.get(key).and_then(|rhs| cont.intersection(rhs))
+		})
+	}
+
+	/// Produces the difference of two bitmaps: the bits that are `1` in `self`
+	/// and `0` in `other`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::roaring::RoaringBitmap;
+	///
+	/// let a = [1, 2].iter().copied().collect::<RoaringBitmap>();
+	/// let b = [2, 100_000].iter().copied().collect::<RoaringBitmap>();
+	/// assert!(a.difference(&b).iter_ones().eq([1].iter().copied()));
+	/// ```
+	#[inline]
+	pub fn difference(&self, other: &Self) -> Self {
+		self.filter_map(|key, cont| match other.get(key) {
+			Some(rhs) => cont.difference(rhs),
+			None => Some(cont.clone()),
+		})
+	}
+
+	/// Produces the union of a bitmap and the `1` bits of a bit-slice.
+	///
+	/// The bit-slice is read one container-sized section at a time, and is
+	/// never converted to a bitmap as a whole.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::{
+	///   prelude::*,
+	///   roaring::RoaringBitmap,
+	/// };
+	///
+	/// let rb = [1, 100_000].iter().copied().collect::<RoaringBitmap>();
+	/// let out = rb.union_bitslice(bits![1, 0, 1]);
+	/// assert!(out.iter_ones().eq([0, 1, 2, 100_000].iter().copied()));
+	/// ```
+	#[inline]
+	pub fn union_bitslice<T, O>(&self, bits: &BitSlice<T, O>) -> Self
+	where
+		T: BitStore,
+		O: BitOrder,
+	{
+		let mut containers = Vec::new();
+		let mut lhs = self.containers.iter().peekable();
+		for (key, window) in bits.chunks(CONTAINER_BITS).enumerate() {
+			while let Some(next) = lhs.next_if(|&&(lk, _)| lk < key) {
+				containers.push(next.clone());
+			}
+			let rhs = Container::from_window(window);
+			let next = match (lhs.next_if(|&&(lk, _)| lk == key), rhs) {
+				(Some((_, lc)), Some(rc)) => lc.union(&rc),
+				(Some((_, lc)), None) => Some(lc.clone()),
+				(None, rc) => rc,
+			};
+			containers.extend(next.map(|cont| (key, cont)));
+		}
+		containers.extend(lhs.cloned());
+		Self { containers }
+	}
+
+	/// Produces the intersection of a bitmap and the `1` bits of a bit-slice.
+	///
+	/// Only the sections of the bit-slice that correspond to containers in
+	/// `self` are read.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::{
+	///   prelude::*,
+	///   roaring::RoaringBitmap,
+	/// };
+	///
+	/// let rb = [1, 2, 100_000].iter().copied().collect::<RoaringBitmap>();
+	/// let out = rb.intersection_bitslice(bits![1, 0, 1]);
+	/// assert!(out.iter_ones().eq([2].iter().copied()));
+	/// ```
+	#[inline]
+	pub fn intersection_bitslice<T, O>(&self, bits: &BitSlice<T, O>) -> Self
+	where
+		T: BitStore,
+		O: BitOrder,
+	{
+		self.filter_map(|key, cont| {
+			window(bits, key)
+				.and_then(Container::from_window)
+				.and_then(|rhs| cont.intersection(&rhs))
+		})
+	}
+
+	/// Produces the difference of a bitmap and the `1` bits of a bit-slice:
+	/// the bits that are `1` in `self` and `0` (or out of bounds) in `bits`.
+	///
+	/// Only the sections of the bit-slice that correspond to containers in
+	/// `self` are read.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::{
+	///   prelude::*,
+	///   roaring::RoaringBitmap,
+	/// };
+	///
+	/// let rb = [1, 2, 100_000].iter().copied().collect::<RoaringBitmap>();
+	/// let out = rb.difference_bitslice(bits![1, 0, 1]);
+	/// assert!(out.iter_ones().eq([1, 100_000].iter().copied()));
+	/// ```
+	#[inline]
+	pub fn difference_bitslice<T, O>(&self, bits: &BitSlice<T, O>) -> Self
+	where
+		T: BitStore,
+		O: BitOrder,
+	{
+		self.filter_map(|key, cont| {
+			match window(bits, key).and_then(Container::from_window) {
+				Some(rhs) => cont.difference(&rhs),
+				None => Some(cont.clone()),
+			}
+		})
+	}
+
+	/// Builds a new bitmap by transforming each container of `self`.
+	#[inline]
+	fn filter_map<F>(&self, mut func: F) -> Self
+	where F: FnMut(usize, &Container) -> Option<Container> {
+		let containers = self
+			.containers
+			.iter()
+			.filter_map(|(key, cont)| func(*key, cont).map(|cont| (*key, cont)))
+			.collect();
+		Self { containers }
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Debug for RoaringBitmap {
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("RoaringBitmap ")?;
+		fmt.debug_set().entries(self.iter_ones()).finish()
+	}
+}
+
+impl PartialEq for RoaringBitmap {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {
+		self.containers.len() == other.containers.len()
+			&& self.iter_ones().eq(other.iter_ones())
+	}
+}
+
+impl Eq for RoaringBitmap {}
+
+impl<T, O> From<&BitSlice<T, O>> for RoaringBitmap
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn from(bits: &BitSlice<T, O>) -> Self {
+		Self::from_bitslice(bits)
+	}
+}
+
+impl FromIterator<usize> for RoaringBitmap {
+	#[inline]
+	fn from_iter<I>(iter: I) -> Self
+	where I: IntoIterator<Item = usize> {
+		let mut out = Self::new();
+		out.extend(iter);
+		out
+	}
+}
+
+impl Extend<usize> for RoaringBitmap {
+	#[inline]
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = usize> {
+		for idx in iter {
+			self.insert(idx);
+		}
+	}
+}
+
+impl<'a> IntoIterator for &'a RoaringBitmap {
+	type IntoIter = IterOnes<'a>;
+	type Item = usize;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter_ones()
+	}
+}
+
+/// Implements a set operator for both bitmap and bit-slice operands.
+macro_rules! set_op {
+	($($op:ident :: $func:ident, $assign:ident :: $afunc:ident
+		=> $roar:ident, $slice:ident);+ $(;)?) => { $(
+		impl $op<&RoaringBitmap> for &RoaringBitmap {
+			type Output = RoaringBitmap;
+
+			#[inline]
+			fn $func(self, rhs: &RoaringBitmap) -> Self::Output {
+				self.$roar(rhs)
+			}
+		}
+
+		impl<T, O> $op<&BitSlice<T, O>> for &RoaringBitmap
+		where
+			T: BitStore,
+			O: BitOrder,
+		{
+			type Output = RoaringBitmap;
+
+			#[inline]
+			fn $func(self, rhs: &BitSlice<T, O>) -> Self::Output {
+				self.$slice(rhs)
+			}
+		}
+
+		impl $assign<&RoaringBitmap> for RoaringBitmap {
+			#[inline]
+			fn $afunc(&mut self, rhs: &RoaringBitmap) {
+				*self = self.$roar(rhs);
+			}
+		}
+
+		impl<T, O> $assign<&BitSlice<T, O>> for RoaringBitmap
+		where
+			T: BitStore,
+			O: BitOrder,
+		{
+			#[inline]
+			fn $afunc(&mut self, rhs: &BitSlice<T, O>) {
+				*self = self.$slice(rhs);
+			}
+		}
+	)+ };
+}
+
+set_op! {
+	BitOr::bitor, BitOrAssign::bitor_assign => union, union_bitslice;
+	BitAnd::bitand, BitAndAssign::bitand_assign
+		=> intersection, intersection_bitslice;
+	Sub::sub, SubAssign::sub_assign => difference, difference_bitslice;
+}
+
+/// An iterator over the indices of each `1` bit in a [`RoaringBitmap`].
+///
+/// This is created by [`RoaringBitmap::iter_ones`].
+#[derive(Clone)]
+pub struct IterOnes<'a> {
+	/// The iterators of each container, in key order.
+	inner: FlatMap<
+		slice::Iter<'a, (usize, Container)>,
+		ContainerIter<'a>,
+		fn(&'a (usize, Container)) -> ContainerIter<'a>,
+	>,
+}
+
+impl Iterator for IterOnes<'_> {
+	type Item = usize;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.inner.next()
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inner.size_hint()
+	}
+}
+
+impl DoubleEndedIterator for IterOnes<'_> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.inner.next_back()
+	}
+}
+
+impl FusedIterator for IterOnes<'_> {}
+
+#[cfg(not(tarpaulin_include))]
+impl Debug for IterOnes<'_> {
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_struct("IterOnes").finish()
+	}
+}
+
+/// One 65,536-bit section of a compressed bitmap.
+#[derive(Clone, Debug)]
+enum Container {
+	/// The sorted low indices of each `1` bit. This holds at most `ARRAY_MAX`
+	/// entries.
+	Array(Vec<u16>),
+	/// The section as an uncompressed bit-array. This holds more than
+	/// `ARRAY_MAX` `1` bits.
+	Bitmap {
+		/// The bits of the section.
+		bits: Box<Dense>,
+		/// The number of `1` bits in `bits`.
+		len:  usize,
+	},
+	/// The sorted, non-adjacent, inclusive ranges of `1` bits. This is only
+	/// produced by `optimize`.
+	Run(Vec<(u16, u16)>),
+}
+
+impl Container {
+	/// Compresses a section of a bit-slice. The section must not be longer
+	/// than `CONTAINER_BITS`.
+	fn from_window<T, O>(window: &BitSlice<T, O>) -> Option<Self>
+	where
+		T: BitStore,
+		O: BitOrder,
+	{
+		let len = window.count_ones();
+		if len == 0 {
+			None
+		}
+		else if len <= ARRAY_MAX {
+			Some(Self::Array(
+				window.iter_ones().map(|idx| idx as u16).collect(),
+			))
+		}
+		else {
+			let mut bits = Box::new(Dense::ZERO);
+			bits[.. window.len()].clone_from_bitslice(window);
+			Some(Self::Bitmap { bits, len })
+		}
+	}
+
+	/// Compresses an uncompressed section.
+	fn from_dense(bits: Box<Dense>) -> Option<Self> {
+		let len = bits.count_ones();
+		if len <= ARRAY_MAX {
+			Self::from_array(bits.iter_ones().map(|idx| idx as u16).collect())
+		}
+		else {
+			Some(Self::Bitmap { bits, len })
+		}
+	}
+
+	/// Wraps a sorted list of low indices.
+	fn from_array(vals: Vec<u16>) -> Option<Self> {
+		if vals.is_empty() {
+			None
+		}
+		else if vals.len() <= ARRAY_MAX {
+			Some(Self::Array(vals))
+		}
+		else {
+			let mut bits = Box::new(Dense::ZERO);
+			for &low in &vals {
+				bits.set(low as usize, true);
+			}
+			let len = vals.len();
+			Some(Self::Bitmap { bits, len })
+		}
+	}
+
+	/// Counts the `1` bits in the container.
+	fn len(&self) -> usize {
+		match self {
+			Self::Array(vals) => vals.len(),
+			Self::Bitmap { len, .. } => *len,
+			Self::Run(runs) => runs
+				.iter()
+				.map(|&(first, last)| (last - first) as usize + 1)
+				.sum(),
+		}
+	}
+
+	/// Tests if a low index is `1`.
+	fn contains(&self, low: u16) -> bool {
+		match self {
+			Self::Array(vals) => vals.binary_search(&low).is_ok(),
+			Self::Bitmap { bits, .. } => bits[low as usize],
+			Self::Run(runs) => runs
+				.binary_search_by(|&(first, last)| {
+					if last < low {
+						Ordering::Less
+					}
+					else if first > low {
+						Ordering::Greater
+					}
+					else {
+						Ordering::Equal
+					}
+				})
+				.is_ok(),
+		}
+	}
+
+	/// Sets a low index to `1`, returning whether it was previously `0`.
+	fn insert(&mut self, low: u16) -> bool {
+		match self {
+			Self::Array(vals) => {
+				let idx = match vals.binary_search(&low) {
+					Ok(_) => return false,
+					Err(idx) => idx,
+				};
+				vals.insert(idx, low);
+				if vals.len() > ARRAY_MAX {
+					*self = Self::from_dense(self.to_dense())
+						.expect("container is not empty");
+				}
+				true
+			},
+			Self::Bitmap { bits, len } => {
+				let out = !bits.replace(low as usize, true);
+				*len += out as usize;
+				out
+			},
+			Self::Run(_) => {
+				if self.contains(low) {
+					return false;
+				}
+				self.expand();
+				self.insert(low)
+			},
+		}
+	}
+
+	/// Sets a low index to `0`, returning whether it was previously `1`.
+	fn remove(&mut self, low: u16) -> bool {
+		match self {
+			Self::Array(vals) => match vals.binary_search(&low) {
+				Ok(idx) => {
+					vals.remove(idx);
+					true
+				},
+				Err(_) => false,
+			},
+			Self::Bitmap { bits, len } => {
+				if !bits.replace(low as usize, false) {
+					return false;
+				}
+				*len -= 1;
+				if *len <= ARRAY_MAX {
+					*self = Self::Array(
+						bits.iter_ones().map(|idx| idx as u16).collect(),
+					);
+				}
+				true
+			},
+			Self::Run(_) => {
+				if !self.contains(low) {
+					return false;
+				}
+				self.expand();
+				self.remove(low)
+			},
+		}
+	}
+
+	/// Converts a run container to an array or bitmap container.
+	fn expand(&mut self) {
+		if let Self::Run(_) = self {
+			*self = Self::from_dense(self.to_dense())
+				.expect("container is not empty");
+		}
+	}
+
+	/// Converts the container to its smallest representation.
+	fn optimize(&mut self) {
+		let len = self.len();
+		let runs = self.count_runs();
+		let plain_size = if len <= ARRAY_MAX {
+			2 * len
+		}
+		else {
+			BITMAP_BYTES
+		};
+		if 4 * runs < plain_size {
+			if let Self::Run(_) = self {
+				return;
+			}
+			let mut out: Vec<(u16, u16)> = Vec::with_capacity(runs);
+			for low in self.iter_keyed_from(0) {
+				let low = low as u16;
+				match out.last_mut() {
+					Some((_, last)) if *last + 1 == low => *last = low,
+					_ => out.push((low, low)),
+				}
+			}
+			*self = Self::Run(out);
+		}
+		else {
+			self.expand();
+		}
+	}
+
+	/// Counts the runs of consecutive `1` bits in the container.
+	fn count_runs(&self) -> usize {
+		match self {
+			Self::Array(vals) => {
+				vals.windows(2)
+					.filter(|pair| pair[1] != pair[0] + 1)
+					.count() + !vals.is_empty() as usize
+			},
+			Self::Bitmap { bits, .. } => {
+				let mut prev = 0u64;
+				bits.as_raw_slice()
+					.iter()
+					.map(|&word| {
+						//  A run starts at each `1` bit whose predecessor is
+						// `0`.
+						let starts = word & !(word << 1 | prev >> 63);
+						prev = word;
+						starts.count_ones() as usize
+					})
+					.sum()
+			},
+			Self::Run(runs) => runs.len(),
+		}
+	}
+
+	/// Decompresses the container.
+	fn to_dense(&self) -> Box<Dense> {
+		let mut out = Box::new(Dense::ZERO);
+		match self {
+			Self::Array(vals) => {
+				for &low in vals {
+					out.set(low as usize, true);
+				}
+			},
+			Self::Bitmap { bits, .. } => return bits.clone(),
+			Self::Run(runs) => {
+				for &(first, last) in runs {
+					out[first as usize ..= last as usize].fill(true);
+				}
+			},
+		}
+		out
+	}
+
+	/// Produces the union of two containers.
+	fn union(&self, other: &Self) -> Option<Self> {
+		if let (Self::Array(lhs), Self::Array(rhs)) = (self, other) {
+			if lhs.len() + rhs.len() <= ARRAY_MAX {
+				let mut out = Vec::with_capacity(lhs.len() + rhs.len());
+				out.extend_from_slice(lhs);
+				out.extend_from_slice(rhs);
+				out.sort_unstable();
+				out.dedup();
+				return Self::from_array(out);
+			}
+		}
+		let mut bits = self.to_dense();
+		match other {
+			Self::Array(vals) => {
+				for &low in vals {
+					bits.set(low as usize, true);
+				}
+			},
+			Self::Bitmap { bits: rhs, .. } => {
+				zip_words(&mut bits, rhs, |lhs, rhs| lhs | rhs)
+			},
+			Self::Run(runs) => {
+				for &(first, last) in runs {
+					bits[first as usize ..= last as usize].fill(true);
+				}
+			},
+		}
+		Self::from_dense(bits)
+	}
+
+	/// Produces the intersection of two containers.
+	fn intersection(&self, other: &Self) -> Option<Self> {
+		match (self, other) {
+			(Self::Array(vals), rhs) | (rhs, Self::Array(vals)) => {
+				Self::from_array(
+					vals.iter()
+						.copied()
+						.filter(|&low| rhs.contains(low))
+						.collect(),
+				)
+			},
+			_ => {
+				let mut bits = self.to_dense();
+				zip_words(&mut bits, &other.to_dense(), |lhs, rhs| lhs & rhs);
+				Self::from_dense(bits)
+			},
+		}
+	}
+
+	/// Produces the difference of two containers.
+	fn difference(&self, other: &Self) -> Option<Self> {
+		if let Self::Array(vals) = self {
+			return Self::from_array(
+				vals.iter()
+					.copied()
+					.filter(|&low| !other.contains(low))
+					.collect(),
+			);
+		}
+		let mut bits = self.to_dense();
+		match other {
+			Self::Array(vals) => {
+				for &low in vals {
+					bits.set(low as usize, false);
+				}
+			},
+			Self::Bitmap { bits: rhs, .. } => {
+				zip_words(&mut bits, rhs, |lhs, rhs| lhs & !rhs)
+			},
+			Self::Run(runs) => {
+				for &(first, last) in runs {
+					bits[first as usize ..= last as usize].fill(false);
+				}
+			},
+		}
+		Self::from_dense(bits)
+	}
+
+	/// Enumerates the full indices of each `1` bit in a keyed container.
+	fn iter_keyed(&(key, ref cont): &(usize, Self)) -> ContainerIter {
+		cont.iter_keyed_from(key * CONTAINER_BITS)
+	}
+
+	/// Enumerates the indices of each `1` bit, offset by `base`.
+	fn iter_keyed_from(&self, base: usize) -> ContainerIter {
+		let inner = match self {
+			Self::Array(vals) => Inner::Array(vals.iter()),
+			Self::Bitmap { bits, .. } => Inner::Bitmap(bits.iter_ones()),
+			Self::Run(runs) => {
+				let func: fn(&(u16, u16)) -> RangeInclusive<u16> = expand_run;
+				Inner::Run(runs.iter().flat_map(func))
+			},
+		};
+		ContainerIter { base, inner }
+	}
+}
+
+/// An iterator over the `1` bits of one container.
+#[derive(Clone)]
+struct ContainerIter<'a> {
+	/// The index of the first bit that the container covers.
+	base:  usize,
+	/// The iterator over low indices.
+	inner: Inner<'a>,
+}
+
+/// The iterators over each kind of container.
+#[derive(Clone)]
+enum Inner<'a> {
+	/// Walks an array container.
+	Array(slice::Iter<'a, u16>),
+	/// Walks a bitmap container.
+	Bitmap(crate::slice::IterOnes<'a, u64, Lsb0>),
+	/// Walks a run container.
+	Run(
+		FlatMap<
+			slice::Iter<'a, (u16, u16)>,
+			RangeInclusive<u16>,
+			fn(&(u16, u16)) -> RangeInclusive<u16>,
+		>,
+	),
+}
+
+impl Iterator for ContainerIter<'_> {
+	type Item = usize;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		match &mut self.inner {
+			Inner::Array(iter) => iter.next().map(|&low| low as usize),
+			Inner::Bitmap(iter) => iter.next(),
+			Inner::Run(iter) => iter.next().map(|low| low as usize),
+		}
+		.map(|low| self.base + low)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		match &self.inner {
+			Inner::Array(iter) => iter.size_hint(),
+			Inner::Bitmap(iter) => iter.size_hint(),
+			Inner::Run(iter) => iter.size_hint(),
+		}
+	}
+}
+
+impl DoubleEndedIterator for ContainerIter<'_> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		match &mut self.inner {
+			Inner::Array(iter) => iter.next_back().map(|&low| low as usize),
+			Inner::Bitmap(iter) => iter.next_back(),
+			Inner::Run(iter) => iter.next_back().map(|low| low as usize),
+		}
+		.map(|low| self.base + low)
+	}
+}
+
+/// Splits a bit-slice index into a container key and a low index.
+#[inline]
+fn split(index: usize) -> (usize, u16) {
+	(index / CONTAINER_BITS, index as u16)
+}
+
+/// Gets the section of a bit-slice that corresponds to a container key.
+#[inline]
+fn window<T, O>(bits: &BitSlice<T, O>, key: usize) -> Option<&BitSlice<T, O>>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	let start = key.checked_mul(CONTAINER_BITS)?;
+	let end = cmp::min(start.checked_add(CONTAINER_BITS)?, bits.len());
+	bits.get(start .. end)
+}
+
+/// Combines the storage words of two bitmap containers.
+#[inline]
+fn zip_words(lhs: &mut Dense, rhs: &Dense, func: fn(u64, u64) -> u64) {
+	for (lhs, &rhs) in lhs
+		.as_raw_mut_slice()
+		.iter_mut()
+		.zip(rhs.as_raw_slice().iter())
+	{
+		*lhs = func(*lhs, rhs);
+	}
+}
+
+/// Expands a run into the range of its low indices.
+#[inline]
+fn expand_run(&(first, last): &(u16, u16)) -> RangeInclusive<u16> {
+	first ..= last
+}
+
+#[cfg(test)]
+mod tests {
+	use rand::random;
+
+	use super::*;
+	use crate::prelude::*;
+
+	/// Builds a bit-vector with a dense section, a sparse section, and a run.
+	fn sample() -> BitVec<u32, Msb0> {
+		let mut bv = BitVec::<u32, Msb0>::repeat(false, 5 * CONTAINER_BITS + 77);
+		for (idx, mut bit) in bv[.. CONTAINER_BITS].iter_mut().enumerate() {
+			*bit = random::<bool>() || idx % 7 == 0;
+		}
+		for _ in 0 .. 100 {
+			bv.set(
+				2 * CONTAINER_BITS + random::<usize>() % CONTAINER_BITS,
+				true,
+			);
+		}
+		bv[3 * CONTAINER_BITS + 100 .. 4 * CONTAINER_BITS + 20].fill(true);
+		bv.set(5 * CONTAINER_BITS + 76, true);
+		bv
+	}
+
+	#[test]
+	fn round_trip() {
+		let bv = sample();
+		let mut rb = RoaringBitmap::from_bitslice(&bv);
+		assert_eq!(rb.len(), bv.count_ones());
+		assert!(rb.iter_ones().eq(bv.iter_ones()));
+		assert!(rb.iter_ones().rev().eq(bv.iter_ones().rev()));
+		assert_eq!(rb.to_bitvec::<u32, Msb0>(), bv);
+
+		let unopt = rb.clone();
+		rb.optimize();
+		assert!(
+			rb.containers
+				.iter()
+				.any(|(_, c)| matches!(c, Container::Run(_)))
+		);
+		assert_eq!(rb, unopt);
+		assert_eq!(rb.to_bitvec::<u8, Lsb0>(), bv);
+		assert_eq!(rb.first_one(), bv.first_one());
+		assert_eq!(rb.last_one(), bv.last_one());
+
+		for idx in (0 .. bv.len()).step_by(997) {
+			assert_eq!(rb.contains(idx), bv[idx]);
+		}
+		assert!(!rb.contains(usize::MAX));
+	}
+
+	#[test]
+	fn insert_remove() {
+		let bv = sample();
+		let mut rb = RoaringBitmap::from_bitslice(&bv);
+		rb.optimize();
+		let mut copy = bv.clone();
+
+		for _ in 0 .. 5000 {
+			let idx = random::<usize>() % bv.len();
+			let bit = random::<bool>();
+			let changed = if bit { rb.insert(idx) } else { rb.remove(idx) };
+			assert_eq!(changed, copy.replace(idx, bit) != bit);
+		}
+		assert!(rb.iter_ones().eq(copy.iter_ones()));
+
+		//  Grow an array container past its limit, then shrink it back.
+		let base = 7 * CONTAINER_BITS;
+		for idx in base ..= base + ARRAY_MAX {
+			assert!(rb.insert(idx));
+		}
+		assert!(matches!(
+			rb.containers.last(),
+			Some((7, Container::Bitmap { .. }))
+		));
+		copy.resize(8 * CONTAINER_BITS, false);
+		copy[base ..= base + ARRAY_MAX].fill(true);
+		assert!(rb.iter_ones().eq(copy.iter_ones()));
+		for idx in base ..= base + ARRAY_MAX {
+			assert!(rb.remove(idx));
+		}
+		assert!(rb.iter_ones().eq(copy[.. base].iter_ones()));
+
+		let mut rb = RoaringBitmap::new();
+		assert!(rb.insert(1 << 40));
+		assert!(rb.remove(1 << 40));
+		assert!(rb.is_empty());
+		assert!(rb.to_bitvec::<usize, Lsb0>().is_empty());
+	}
+
+	#[test]
+	fn set_ops() {
+		let a = sample();
+		let mut b = sample();
+		b.resize(3 * CONTAINER_BITS + 500, false);
+		b[CONTAINER_BITS + 10 .. CONTAINER_BITS + 20].fill(true);
+		let (ra, mut rb) =
+			(RoaringBitmap::from(&a[..]), RoaringBitmap::from(&b[..]));
+		rb.optimize();
+
+		let or = a.clone() | &b[..];
+		let and = {
+			let mut out = a.clone();
+			out &= &b[..];
+			out
+		};
+		let diff = a.and_not(&b);
+
+		assert!((&ra | &rb).iter_ones().eq(or.iter_ones()));
+		assert!((&ra & &rb).iter_ones().eq(and.iter_ones()));
+		assert!((&ra - &rb).iter_ones().eq(diff.iter_ones()));
+		assert!((&rb | &ra).iter_ones().eq(or.iter_ones()));
+		assert!((&rb & &ra).iter_ones().eq(and.iter_ones()));
+
+		assert!((&ra | &b[..]).iter_ones().eq(or.iter_ones()));
+		assert!((&ra & &b[..]).iter_ones().eq(and.iter_ones()));
+		assert!((&ra - &b[..]).iter_ones().eq(diff.iter_ones()));
+		assert!((&rb | &a[..]).iter_ones().eq(or.iter_ones()));
+
+		let mut rc = ra.clone();
+		rc |= &rb;
+		rc -= &b[..];
+		rc &= &ra;
+		assert_eq!(rc, &ra - &rb);
+	}
+}
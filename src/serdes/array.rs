@@ -34,11 +34,15 @@ use super::{
 	FIELDS,
 };
 use crate::{
-	array::BitArray,
+	array::{
+		BitArray,
+		ExactBitArray,
+	},
 	index::BitIdx,
 	mem::bits_of,
 	order::BitOrder,
 	store::BitStore,
+	view::BitView,
 };
 
 impl<T, O> Serialize for BitArray<T, O>
@@ -84,6 +88,36 @@ where
 	}
 }
 
+/** Serializes an exact bit-array with the same layout as a `BitArray`.
+
+The `bits` field records `N` rather than the full width of the buffer, and so is
+not interchangeable with a `BitArray` of the same storage.
+**/
+impl<T, O, const K: usize, const N: usize> Serialize
+	for ExactBitArray<[T; K], O, N>
+where
+	T: BitStore,
+	O: BitOrder,
+	T::Mem: Serialize,
+{
+	#[inline]
+	fn serialize<S>(&self, serializer: S) -> super::Result<S>
+	where S: Serializer {
+		let mut state = serializer.serialize_struct("BitArr", FIELDS.len())?;
+
+		state.serialize_field("order", &any::type_name::<O>())?;
+		state.serialize_field("head", &BitIdx::<T::Mem>::MIN)?;
+		state.serialize_field("bits", &(N as u64))?;
+		//  Padding bits are unspecified, so they are cleared in order to keep
+		//  the serialized form deterministic.
+		let mut this = self.clone();
+		this.inner.data.view_bits_mut::<O>()[N ..].fill(false);
+		state.serialize_field("data", Array::from_ref(&this.inner.data))?;
+
+		state.end()
+	}
+}
+
 impl<'de, T, O> Deserialize<'de> for BitArray<T, O>
 where
 	T: BitStore,
@@ -116,6 +150,26 @@ where
 	}
 }
 
+impl<'de, T, O, const K: usize, const N: usize> Deserialize<'de>
+	for ExactBitArray<[T; K], O, N>
+where
+	T: BitStore,
+	O: BitOrder,
+	T::Mem: Deserialize<'de>,
+{
+	#[inline]
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where D: Deserializer<'de> {
+		deserializer
+			.deserialize_struct(
+				"BitArr",
+				FIELDS,
+				BitArrVisitor::<T, O, K>::exact(N),
+			)
+			.map(|array| ExactBitArray::new(array.into_inner()))
+	}
+}
+
 /// Assists in deserialization of a static `BitArr`.
 struct BitArrVisitor<T, O, const N: usize>
 where
@@ -123,14 +177,18 @@ where
 	O: BitOrder,
 {
 	/// The deserialized bit-ordering string.
-	order: Option<TypeName<O>>,
+	order:    Option<TypeName<O>>,
 	/// The deserialized head-bit index. This must be zero; it is used for
 	/// consistency with `BitSeq` and to carry `T::Mem` information.
-	head:  Option<BitIdx<T::Mem>>,
-	/// The deserialized bit-count. It must be `bits_of::<[T::Mem; N]>()`.
-	bits:  Option<u64>,
+	head:     Option<BitIdx<T::Mem>>,
+	/// The deserialized bit-count. It must be equal to `expected`.
+	bits:     Option<u64>,
 	/// The deserialized data buffer.
-	data:  Option<Array<T, N>>,
+	data:     Option<Array<T, N>>,
+	/// The bit-count of the destination type. This is
+	/// `bits_of::<[T::Mem; N]>()` for `BitArray`, and the exact length for
+	/// `ExactBitArray`.
+	expected: usize,
 }
 
 impl<'de, T, O, const N: usize> BitArrVisitor<T, O, N>
@@ -141,12 +199,22 @@ where
 {
 	/// A new visitor in its ready condition.
 	const THIS: Self = Self {
-		order: None,
-		head:  None,
-		bits:  None,
-		data:  None,
+		order:    None,
+		head:     None,
+		bits:     None,
+		data:     None,
+		expected: bits_of::<[T; N]>(),
 	};
 
+	/// A new visitor for an exact bit-array of `bits` bits.
+	#[inline]
+	fn exact(bits: usize) -> Self {
+		Self {
+			expected: bits,
+			..Self::THIS
+		}
+	}
+
 	/// Attempts to assemble deserialized components into an output value.
 	#[inline]
 	fn assemble<E>(mut self) -> Result<BitArray<[T; N], O>, E>
@@ -163,7 +231,7 @@ where
 			));
 		}
 		let bits = bits as usize;
-		if bits != bits_of::<[T; N]>() {
+		if bits != self.expected {
 			return Err(E::invalid_length(bits, &self));
 		}
 
@@ -181,6 +249,16 @@ where
 
 	#[inline]
 	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		if self.expected != bits_of::<[T; N]>() {
+			return write!(
+				fmt,
+				"an `ExactBitArray<[u{}; {}], {}, {}>`",
+				bits_of::<T::Mem>(),
+				N,
+				any::type_name::<O>(),
+				self.expected,
+			);
+		}
 		write!(
 			fmt,
 			"a `BitArray<[u{}; {}], {}>`",
@@ -340,6 +418,48 @@ mod tests {
 		assert_de_tokens(&array, tokens);
 	}
 
+	#[test]
+	fn exact() {
+		type Exact = BitArr!(exact 12, in u8, Msb0);
+		let array = Exact::new([0x3C, 0xA5]);
+		let tokens = &mut [
+			Token::Struct {
+				name: "BitArr",
+				len:  4,
+			},
+			Token::Str("order"),
+			Token::Str(any::type_name::<Msb0>()),
+			Token::Str("head"),
+			Token::Struct {
+				name: "BitIdx",
+				len:  2,
+			},
+			Token::Str("width"),
+			Token::U8(8),
+			Token::Str("index"),
+			Token::U8(0),
+			Token::StructEnd,
+			Token::Str("bits"),
+			Token::U64(12),
+			Token::Str("data"),
+			Token::Tuple { len: 2 },
+			Token::U8(0x3C),
+			Token::U8(0xA0),
+			Token::TupleEnd,
+			Token::StructEnd,
+		];
+
+		assert_ser_tokens(&array, tokens);
+		assert_de_tokens(&array, tokens);
+
+		tokens[11] = Token::U64(16);
+		assert_de_tokens_error::<Exact>(
+			tokens,
+			"invalid length 16, expected an `ExactBitArray<[u8; 2], \
+			 bitvec::order::Msb0, 12>`",
+		);
+	}
+
 	#[test]
 	#[cfg(feature = "alloc")]
 	fn errors() {
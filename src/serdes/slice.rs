@@ -33,6 +33,8 @@ use super::{
 	Field,
 	FIELDS,
 };
+#[cfg(feature = "allocator")]
+use crate::vec::Allocator;
 #[cfg(feature = "alloc")]
 use crate::{
 	boxed::BitBox,
@@ -87,7 +89,8 @@ where
 }
 
 #[cfg(feature = "alloc")]
-impl<T, O> Serialize for BitVec<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator> Serialize
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
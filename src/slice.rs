@@ -21,8 +21,12 @@ use wyz::{
 };
 
 #[cfg(feature = "alloc")]
-use crate::vec::BitVec;
+use crate::vec::{
+	BitVec,
+	TryReserveError,
+};
 use crate::{
+	devel as dvl,
 	domain::{
 		BitDomain,
 		Domain,
@@ -44,15 +48,22 @@ use crate::{
 };
 
 mod api;
+mod atomic;
+mod fingerprint;
 mod iter;
 mod ops;
+mod par;
+mod search;
 mod specialization;
 mod tests;
 mod traits;
 
+#[cfg(feature = "rayon")]
+pub use self::par::*;
 pub use self::{
 	api::*,
 	iter::*,
+	search::FindIter,
 };
 
 #[repr(transparent)]
@@ -521,8 +532,10 @@ where
 	/// implementation as [`.copy_from_bitslice()`]; if you know that this will
 	/// always be the case, you should prefer to use that method directly.
 	///
-	/// Only `.copy_from_bitslice()` is *able* to perform acceleration; this
-	/// method is *always* required to perform a bit-by-bit crawl over both
+	/// If `self` and `src` each use the `Lsb0` or `Msb0` ordering, or one of
+	/// their byte- or nibble-swapped variants, the bits are transferred a
+	/// processor word at a time, rearranging each word when the orderings
+	/// differ. Otherwise, this method must perform a bit-by-bit crawl over both
 	/// bit-slices.
 	///
 	/// ## Original
@@ -563,6 +576,16 @@ where
 		if let Some(that) = src.coerce::<T, O>() {
 			self.copy_from_bitslice(that);
 		}
+		//  Move a processor word at a time between orderings that both know
+		//  how to rearrange their bits within a word.
+		else if dvl::has_word_access::<O>() && dvl::has_word_access::<O2>() {
+			let word = mem::bits_of::<usize>();
+			for (to, from) in unsafe { self.chunks_mut(word).remove_alias() }
+				.zip(src.chunks(word))
+			{
+				to.store_word(from.load_stream());
+			}
+		}
 		//  TODO(myrrlyn): Test if `<T::Mem, O>` matches `<T2::Mem, O>` and
 		//  specialize cloning.
 		else {
@@ -1186,6 +1209,35 @@ where
 		IterZeros::new(self)
 	}
 
+	/// Enumerates the maximal runs of identical bits in a bit-slice.
+	///
+	/// Each run is yielded as a `(value, start, len)` triple: the value of
+	/// every bit in the run, the index of its first bit, and its length. Runs
+	/// are never empty, adjacent runs always have different values, and the
+	/// runs together cover the entire bit-slice.
+	///
+	/// Like [`.iter_ones()`], this uses whole-element scans for the `Lsb0` and
+	/// `Msb0` orderings, so long runs are found without inspecting each bit.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 0, 1, 1, 1, 0];
+	/// let mut runs = bits.iter_runs();
+	/// assert_eq!(runs.next(), Some((false, 0, 2)));
+	/// assert_eq!(runs.next_back(), Some((false, 5, 1)));
+	/// assert_eq!(runs.next(), Some((true, 2, 3)));
+	/// assert!(runs.next().is_none());
+	/// ```
+	///
+	/// [`.iter_ones()`]: Self::iter_ones
+	#[inline]
+	pub fn iter_runs(&self) -> IterRuns<T, O> {
+		IterRuns::new(self)
+	}
+
 	/// Finds the index of the first bit in the bit-slice set to `1`.
 	///
 	/// Returns `None` if there is no `true` bit in the bit-slice.
@@ -1429,6 +1481,148 @@ where
 	}
 }
 
+/// Set relations.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Counts the number of indices at which both bit-slices have a `1` bit.
+	///
+	/// This is the population count of `self & rhs`, but it does not allocate
+	/// a bit-vector for the intermediate result. The shorter bit-slice is
+	/// treated as if it were extended with `0` bits to the length of the
+	/// longer.
+	///
+	/// The bit-slices are loaded a processor word at a time, and the
+	/// specializations for the `Lsb0` and `Msb0` orderings load whole memory
+	/// elements rather than individual bits.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![1, 1, 0, 1, 1];
+	/// let b = bits![u8, Msb0; 0, 1, 1, 1];
+	/// assert_eq!(a.count_and(b), 2);
+	/// ```
+	#[inline]
+	pub fn count_and<T2, O2>(&self, rhs: &BitSlice<T2, O2>) -> usize
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.zip_words(rhs)
+			.map(|(a, b)| (a & b).count_ones() as usize)
+			.sum()
+	}
+
+	/// Counts the number of indices at which either bit-slice has a `1` bit.
+	///
+	/// This is the population count of `self | rhs`, but it does not allocate
+	/// a bit-vector for the intermediate result. The shorter bit-slice is
+	/// treated as if it were extended with `0` bits to the length of the
+	/// longer.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![1, 0, 0, 1, 1];
+	/// let b = bits![u8, Msb0; 0, 1, 0, 1];
+	/// assert_eq!(a.count_or(b), 4);
+	/// ```
+	#[inline]
+	pub fn count_or<T2, O2>(&self, rhs: &BitSlice<T2, O2>) -> usize
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let len = self.len().min(rhs.len());
+		self.zip_words(rhs)
+			.map(|(a, b)| (a | b).count_ones() as usize)
+			.sum::<usize>()
+			+ unsafe { self.get_unchecked(len ..) }.count_ones()
+			+ unsafe { rhs.get_unchecked(len ..) }.count_ones()
+	}
+
+	/// Tests if any index has a `1` bit in both bit-slices.
+	///
+	/// This stops at the first processor word in which the bit-slices share a
+	/// `1` bit.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![1, 0, 0, 1];
+	/// assert!(a.intersects(bits![0, 0, 0, 1, 1]));
+	/// assert!(!a.intersects(bits![0, 1, 1]));
+	/// ```
+	#[inline]
+	pub fn intersects<T2, O2>(&self, rhs: &BitSlice<T2, O2>) -> bool
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.zip_words(rhs).any(|(a, b)| a & b != 0)
+	}
+
+	/// Tests if no index has a `1` bit in both bit-slices.
+	///
+	/// This is the inverse of [`.intersects()`].
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![1, 0, 0, 1];
+	/// assert!(a.is_disjoint(bits![0, 1, 1]));
+	/// assert!(!a.is_disjoint(bits![0, 0, 0, 1, 1]));
+	/// ```
+	///
+	/// [`.intersects()`]: Self::intersects
+	#[inline]
+	pub fn is_disjoint<T2, O2>(&self, rhs: &BitSlice<T2, O2>) -> bool
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		!self.intersects(rhs)
+	}
+
+	/// Tests if every index with a `1` bit in `self` also has a `1` bit in
+	/// `rhs`.
+	///
+	/// Any bits in `self` past the end of `rhs` must be `0`; any bits in `rhs`
+	/// past the end of `self` are ignored.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![0, 1, 0, 1, 0];
+	/// assert!(a.is_subset(bits![1, 1, 0, 1]));
+	/// assert!(!a.is_subset(bits![1, 1, 0, 0]));
+	/// assert!(!bits![0, 0, 1].is_subset(bits![1, 1]));
+	/// ```
+	#[inline]
+	pub fn is_subset<T2, O2>(&self, rhs: &BitSlice<T2, O2>) -> bool
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let len = self.len().min(rhs.len());
+		self.zip_words(rhs).all(|(a, b)| a & !b == 0)
+			&& unsafe { self.get_unchecked(len ..) }.not_any()
+	}
+}
+
 /// Buffer manipulation.
 impl<T, O> BitSlice<T, O>
 where
@@ -1631,6 +1825,60 @@ where
 		//  Remove the new alias layer.
 		(Self::unalias_mut(head), Self::unalias_mut(tail))
 	}
+
+	/// Stores an integer into a bit-slice of at most one processor word.
+	///
+	/// This is the inverse of [`.load_stream()`]: `self[n]` receives bit `n` of
+	/// `word`, and bits of `word` past `self.len()` are discarded.
+	///
+	/// [`.load_stream()`]: Self::load_stream
+	pub(crate) fn store_word(&mut self, word: usize) {
+		debug_assert!(
+			self.len() <= mem::bits_of::<usize>(),
+			"cannot store a processor word into {} bits",
+			self.len(),
+		);
+		if self.is_empty() {
+			return;
+		}
+		if let Some(bits) = self.coerce_mut::<T, Lsb0>() {
+			return bits.sp_store_word(word);
+		}
+		if let Some(bits) = self.coerce_mut::<T, Msb0>() {
+			return bits.sp_store_word(word);
+		}
+		if self.sp_store_word_swapped(word) {
+			return;
+		}
+		for (idx, ptr) in self.as_mut_bitptr_range().enumerate() {
+			unsafe {
+				ptr.write(word >> idx & 1 != 0);
+			}
+		}
+	}
+
+	/// Walks the common prefix of two bit-slices a processor word at a time.
+	///
+	/// Each item is a pair of integers produced by [`.load_stream()`] from the
+	/// same span of both bit-slices. Iteration stops at the end of the shorter
+	/// bit-slice.
+	///
+	/// [`.load_stream()`]: Self::load_stream
+	pub(crate) fn zip_words<'a, T2, O2>(
+		&'a self,
+		rhs: &'a BitSlice<T2, O2>,
+	) -> impl 'a + Iterator<Item = (usize, usize)>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let len = self.len().min(rhs.len());
+		let word = mem::bits_of::<usize>();
+		unsafe { self.get_unchecked(.. len) }
+			.chunks(word)
+			.zip(unsafe { rhs.get_unchecked(.. len) }.chunks(word))
+			.map(|(a, b)| (a.load_stream(), b.load_stream()))
+	}
 }
 
 /// Methods available only when `T` allows shared mutability.
@@ -1790,6 +2038,203 @@ where
 				bv.set_len(self.len());
 			})
 	}
+
+	/// Copies a bit-slice into an owned bit-vector with different type
+	/// parameters.
+	///
+	/// The produced bit-vector has the same sequence of bits as `self`, but may
+	/// use any storage type and bit-ordering. Conversions between the `Lsb0`
+	/// and `Msb0` orderings and their swapped variants, in any direction,
+	/// transfer a processor word at a time rather than walking each bit.
+	///
+	/// ## Type Parameters
+	///
+	/// - `T2`: The storage type of the produced bit-vector.
+	/// - `O2`: The bit-ordering of the produced bit-vector.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let packet = [0b1100_0101u8, 0b0011_1010];
+	/// let bits = packet.view_bits::<Msb0>();
+	///
+	/// let bv = bits.to_bitvec_with::<usize, Lsb0>();
+	/// assert_eq!(bv, bits);
+	/// assert_eq!(bv.as_raw_slice()[0], 0b0101_1100_1010_0011);
+	/// ```
+	#[inline]
+	pub fn to_bitvec_with<T2, O2>(&self) -> BitVec<T2, O2>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let mut out = BitVec::repeat(false, self.len());
+		out.clone_from_bitslice(self);
+		out
+	}
+
+	/// Copies a bit-slice into an owned bit-vector, or reports why the
+	/// allocation could not be made.
+	///
+	/// This is the fallible version of [`to_bitvec`]. The new bit-vector always
+	/// begins at the front edge of its first element.
+	///
+	/// [`to_bitvec`]: Self::to_bitvec
+	#[inline]
+	pub fn try_to_bitvec(
+		&self,
+	) -> Result<BitVec<T::Unalias, O>, TryReserveError> {
+		let mut out = BitVec::try_with_capacity(self.len())?;
+		out.extend_from_bitslice(self);
+		Ok(out)
+	}
+
+	/// Computes `self & !rhs` into a new bit-vector.
+	///
+	/// This produces a bit-vector with `1` bits only where `self` has a `1` bit
+	/// and `rhs` does not: the set difference of `self` and `rhs`.
+	///
+	/// As with the `&`, `|`, and `^` operators on bit-slice references, the
+	/// produced bit-vector is as long as the longer operand, and the shorter
+	/// operand is treated as if it were extended with `0` bits. Slice both
+	/// operands to the same length first to truncate instead.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![1, 1, 0, 0, 1];
+	/// let b = bits![0, 1, 0, 1];
+	/// assert_eq!(a.and_not(b), bits![1, 0, 0, 0, 1]);
+	/// ```
+	#[inline]
+	pub fn and_not<T2, O2>(
+		&self,
+		rhs: &BitSlice<T2, O2>,
+	) -> BitVec<T::Unalias, O>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.bitop_to_bitvec(rhs, |a, b| a & !b)
+	}
+
+	/// Computes `!(self & rhs)` into a new bit-vector.
+	///
+	/// The produced bit-vector is as long as the longer operand, and the
+	/// shorter operand is treated as if it were extended with `0` bits. The
+	/// extended region is therefore always `1`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![1, 1, 0, 0];
+	/// let b = bits![0, 1, 0, 1, 1];
+	/// assert_eq!(a.nand(b), bits![1, 0, 1, 1, 1]);
+	/// ```
+	#[inline]
+	pub fn nand<T2, O2>(&self, rhs: &BitSlice<T2, O2>) -> BitVec<T::Unalias, O>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.bitop_to_bitvec(rhs, |a, b| !(a & b))
+	}
+
+	/// Computes `!(self | rhs)` into a new bit-vector.
+	///
+	/// The produced bit-vector is as long as the longer operand, and the
+	/// shorter operand is treated as if it were extended with `0` bits.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![1, 1, 0, 0];
+	/// let b = bits![0, 1, 0, 1, 0];
+	/// assert_eq!(a.nor(b), bits![0, 0, 1, 0, 1]);
+	/// ```
+	#[inline]
+	pub fn nor<T2, O2>(&self, rhs: &BitSlice<T2, O2>) -> BitVec<T::Unalias, O>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.bitop_to_bitvec(rhs, |a, b| !(a | b))
+	}
+
+	/// Encodes the bit-slice as a sequence of run lengths.
+	///
+	/// The lengths alternate between runs of `0` bits and runs of `1` bits,
+	/// beginning with `0`. If the bit-slice begins with a `1` bit, the first
+	/// length is zero; no other length is zero. An empty bit-slice produces an
+	/// empty sequence.
+	///
+	/// [`BitVec::from_runs`] decodes this sequence.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 1, 0, 0, 0, 1];
+	/// let runs = bits.to_runs();
+	/// assert_eq!(runs, [0, 2, 3, 1]);
+	/// assert_eq!(BitVec::<usize, Lsb0>::from_runs(runs), bits);
+	/// ```
+	///
+	/// [`BitVec::from_runs`]: crate::vec::BitVec::from_runs
+	#[inline]
+	pub fn to_runs(&self) -> Vec<usize> {
+		let mut out = Vec::new();
+		if self.first().map_or(false, |bit| *bit) {
+			out.push(0);
+		}
+		out.extend(self.iter_runs().map(|(_, _, len)| len));
+		out
+	}
+
+	/// Applies a Boolean-arithmetic function across two bit-slices, writing
+	/// the results into a new bit-vector.
+	///
+	/// The produced bit-vector is as long as the longer operand. Both operands
+	/// are loaded with [`.load_stream()`], zero-extended past their ends, and
+	/// the result of `op` is stored a processor word at a time.
+	///
+	/// [`.load_stream()`]: Self::load_stream
+	pub(crate) fn bitop_to_bitvec<T2, O2>(
+		&self,
+		rhs: &BitSlice<T2, O2>,
+		op: fn(usize, usize) -> usize,
+	) -> BitVec<T::Unalias, O>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let word = mem::bits_of::<usize>();
+		let len = self.len().max(rhs.len());
+		let mut out = BitVec::<T::Unalias, O>::repeat(false, len);
+		for (idx, chunk) in
+			unsafe { out.chunks_mut(word).remove_alias() }.enumerate()
+		{
+			let start = idx * word;
+			let end = start + chunk.len();
+			let a = self
+				.get(start .. end.min(self.len()))
+				.map_or(0, BitSlice::load_stream);
+			let b = rhs
+				.get(start .. end.min(rhs.len()))
+				.map_or(0, BitSlice::load_stream);
+			chunk.store_word(op(a, b));
+		}
+		out
+	}
 }
 
 #[inline]
@@ -45,7 +45,10 @@ use super::{
 	Windows,
 };
 #[cfg(feature = "alloc")]
-use crate::vec::BitVec;
+use crate::vec::{
+	BitVec,
+	TryReserveError,
+};
 use crate::{
 	array::BitArray,
 	domain::Domain,
@@ -1809,10 +1812,9 @@ where
 
 	/// Tests if the bit-slice contains the given sequence anywhere within it.
 	///
-	/// This scans over `self.windows(other.len())` until one of the windows
-	/// matches. The search key does not need to share type parameters with the
-	/// bit-slice being tested, as the comparison is bit-wise. However, sharing
-	/// type parameters will accelerate the comparison.
+	/// This is equivalent to `self.find(other).is_some()`, and uses the same
+	/// word-wise comparison as [`.find()`]. The search key does not need to
+	/// share type parameters with the bit-slice being tested.
 	///
 	/// ## Original
 	///
@@ -1827,14 +1829,15 @@ where
 	/// assert!( bits.contains(bits![0, 1, 1, 0]));
 	/// assert!(!bits.contains(bits![1, 0, 0, 1]));
 	/// ```
+	///
+	/// [`.find()`]: Self::find
 	#[inline]
 	pub fn contains<T2, O2>(&self, other: &BitSlice<T2, O2>) -> bool
 	where
 		T2: BitStore,
 		O2: BitOrder,
 	{
-		self.len() >= other.len()
-			&& self.windows(other.len()).any(|window| window == other)
+		self.find(other).is_some()
 	}
 
 	/// Tests if the bit-slice begins with the given sequence.
@@ -2409,6 +2412,37 @@ where
 		out
 	}
 
+	/// Creates a bit-vector by repeating a bit-slice `n` times, or reports why
+	/// the allocation could not be made.
+	///
+	/// This is the fallible version of [`repeat`].
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// assert_eq!(bits![0, 1].try_repeat(2).unwrap(), bits![0, 1, 0, 1]);
+	/// assert!(bits![0, 1].try_repeat(usize::MAX).is_err());
+	/// ```
+	///
+	/// [`repeat`]: Self::repeat
+	#[inline]
+	pub fn try_repeat(
+		&self,
+		n: usize,
+	) -> Result<BitVec<T::Unalias, O>, TryReserveError> {
+		let total = self
+			.len()
+			.checked_mul(n)
+			.ok_or_else(TryReserveError::capacity_overflow)?;
+		let mut out = BitVec::try_with_capacity(total)?;
+		for _ in 0 .. n {
+			out.extend_from_bitslice(self);
+		}
+		Ok(out)
+	}
+
 	/* As of 1.56, the `concat` and `join` methods use still-unstable traits
 	 * to govern the collection of multiple subslices into one vector. These
 	 * are possible to copy over and redefine locally, but unless a user asks
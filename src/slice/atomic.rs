@@ -0,0 +1,419 @@
+#![doc = include_str!("../../doc/slice/atomic.md")]
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{
+	ops::RangeBounds,
+	sync::atomic::Ordering,
+};
+
+use funty::Integral;
+use radium::Radium;
+use wyz::range::RangeExt;
+
+use super::BitSlice;
+#[cfg(feature = "alloc")]
+use crate::vec::BitVec;
+use crate::{
+	access::BitAccess,
+	index::{
+		BitEnd,
+		BitIdx,
+	},
+	mem,
+	order::BitOrder,
+	store::BitStore,
+};
+
+/// Ordered shared-mutable access.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore + Radium<Item = <T as BitStore>::Mem>,
+	O: BitOrder,
+{
+	/// Reads a single bit, using the given memory ordering.
+	///
+	/// This is [`.get()`] for bit-slices whose storage can be modified through
+	/// shared references, when the load must synchronize with writes made by
+	/// other threads.
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `index`: The bit index to read. It must be in `0 .. self.len()`.
+	/// - `order`: The memory ordering of the load. This must not be [`Release`]
+	///   or [`AcqRel`].
+	///
+	/// ## Returns
+	///
+	/// The value of the bit at `index`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is out of bounds, or if `order` is not valid for
+	/// a load.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::sync::atomic::{AtomicU32, Ordering};
+	///
+	/// let data = [AtomicU32::new(4), AtomicU32::new(0)];
+	/// let bits = data.view_bits::<Lsb0>();
+	/// assert!(bits.get_ordered(2, Ordering::Acquire));
+	/// assert!(!bits.get_ordered(34, Ordering::Acquire));
+	/// ```
+	///
+	/// [`AcqRel`]: core::sync::atomic::Ordering::AcqRel
+	/// [`Release`]: core::sync::atomic::Ordering::Release
+	/// [`.get()`]: Self::get
+	#[inline]
+	pub fn get_ordered(&self, index: usize, order: Ordering) -> bool {
+		self.assert_in_bounds(index, 0 .. self.len());
+		let (elem, bit) = unsafe { self.element_at(index) };
+		elem.load(order) & bit.select::<O>().into_inner()
+			!= <T::Mem as Integral>::ZERO
+	}
+
+	/// Sets a bit to `1`, using the given memory ordering, and returns its
+	/// previous value.
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `index`: The bit index to set. It must be in `0 .. self.len()`.
+	/// - `order`: The memory ordering of the read-modify-write operation.
+	///
+	/// ## Returns
+	///
+	/// The value of the bit at `index` immediately before it was set.
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is out of bounds.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::sync::atomic::{AtomicU8, Ordering};
+	///
+	/// let data = AtomicU8::new(0);
+	/// let bits = data.view_bits::<Msb0>();
+	/// assert!(!bits.fetch_set(1, Ordering::AcqRel));
+	/// assert!(bits.fetch_set(1, Ordering::AcqRel));
+	/// assert_eq!(data.load(Ordering::Relaxed), 0b0100_0000);
+	/// ```
+	#[inline]
+	pub fn fetch_set(&self, index: usize, order: Ordering) -> bool {
+		self.fetch_write(index, true, order)
+	}
+
+	/// Clears a bit to `0`, using the given memory ordering, and returns its
+	/// previous value.
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `index`: The bit index to clear. It must be in `0 .. self.len()`.
+	/// - `order`: The memory ordering of the read-modify-write operation.
+	///
+	/// ## Returns
+	///
+	/// The value of the bit at `index` immediately before it was cleared.
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is out of bounds.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::sync::atomic::{AtomicU8, Ordering};
+	///
+	/// let data = AtomicU8::new(!0);
+	/// let bits = data.view_bits::<Lsb0>();
+	/// assert!(bits.fetch_clear(0, Ordering::Release));
+	/// assert!(!bits.fetch_clear(0, Ordering::Release));
+	/// assert_eq!(data.load(Ordering::Relaxed), 0xFE);
+	/// ```
+	#[inline]
+	pub fn fetch_clear(&self, index: usize, order: Ordering) -> bool {
+		self.fetch_write(index, false, order)
+	}
+
+	/// Inverts a bit, using the given memory ordering, and returns its
+	/// previous value.
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `index`: The bit index to invert. It must be in `0 .. self.len()`.
+	/// - `order`: The memory ordering of the read-modify-write operation.
+	///
+	/// ## Returns
+	///
+	/// The value of the bit at `index` immediately before it was inverted.
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is out of bounds.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::sync::atomic::{AtomicU8, Ordering};
+	///
+	/// let data = AtomicU8::new(0);
+	/// let bits = data.view_bits::<Lsb0>();
+	/// assert!(!bits.fetch_toggle(3, Ordering::AcqRel));
+	/// assert!(bits.fetch_toggle(3, Ordering::AcqRel));
+	/// assert_eq!(data.load(Ordering::Relaxed), 0);
+	/// ```
+	#[inline]
+	pub fn fetch_toggle(&self, index: usize, order: Ordering) -> bool {
+		self.assert_in_bounds(index, 0 .. self.len());
+		let (elem, bit) = unsafe { self.element_at(index) };
+		let select = bit.select::<O>().into_inner();
+		elem.fetch_xor(select, order) & select != <T::Mem as Integral>::ZERO
+	}
+
+	/// Sets a bit to `1` and reports whether it was already set.
+	///
+	/// This is [`.fetch_set()`] under its conventional name. Exactly one of any
+	/// number of racing callers observes `false`, so it can be used to claim a
+	/// slot or acquire a flag.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::sync::atomic::{AtomicUsize, Ordering};
+	///
+	/// let data = [AtomicUsize::new(0), AtomicUsize::new(0)];
+	/// let slots = data.view_bits::<Lsb0>();
+	/// if !slots.test_and_set(70, Ordering::Acquire) {
+	///   // this thread now owns slot 70
+	/// }
+	/// assert!(slots.test_and_set(70, Ordering::Acquire));
+	/// ```
+	///
+	/// [`.fetch_set()`]: Self::fetch_set
+	#[inline]
+	pub fn test_and_set(&self, index: usize, order: Ordering) -> bool {
+		self.fetch_set(index, order)
+	}
+
+	/// Replaces the contents of a range of bits if they hold an expected
+	/// value.
+	///
+	/// The comparison and replacement are a single atomic operation on the
+	/// memory element containing the range, so the range must not cross an
+	/// element boundary. Bits in that element outside the range are neither
+	/// compared nor modified, and changes to them by other threads do not cause
+	/// the exchange to fail.
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `range`: The range of bits to compare and replace.
+	/// - `current`: The value that the range must hold for the exchange to
+	///   succeed.
+	/// - `new`: The value to write into the range.
+	/// - `success`: The memory ordering of the read-modify-write operation if
+	///   the exchange succeeds.
+	/// - `failure`: The memory ordering of the load if the exchange fails.
+	///
+	/// `current`, `new`, and the returned value all place the first bit of the
+	/// range in their least significant bit, the same layout that
+	/// [`.load_le()`] uses for an `Lsb0` bit-slice. Any bits in `current` or
+	/// `new` above the length of the range are ignored.
+	///
+	/// ## Returns
+	///
+	/// `Ok` with the previous contents of the range if they were equal to
+	/// `current` and have been replaced by `new`; otherwise, `Err` with the
+	/// contents that were found instead.
+	///
+	/// ## Panics
+	///
+	/// This panics if `range` is out of bounds or is not contained within a
+	/// single memory element, or if `failure` is not valid for a load.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::sync::atomic::{AtomicU16, Ordering::*};
+	///
+	/// let data = AtomicU16::new(0);
+	/// let bits = data.view_bits::<Msb0>();
+	/// assert_eq!(bits.compare_exchange_bits(4 .. 8, 0, 0b1001, AcqRel, Acquire), Ok(0));
+	/// assert_eq!(bits.compare_exchange_bits(4 .. 8, 0, 0b0110, AcqRel, Acquire), Err(0b1001));
+	/// assert_eq!(bits[4 .. 8], bits![1, 0, 0, 1]);
+	/// ```
+	///
+	/// [`.load_le()`]: crate::field::BitField::load_le
+	#[inline]
+	pub fn compare_exchange_bits<R>(
+		&self,
+		range: R,
+		current: T::Mem,
+		new: T::Mem,
+		success: Ordering,
+		failure: Ordering,
+	) -> Result<T::Mem, T::Mem>
+	where
+		R: RangeBounds<usize>,
+	{
+		let range = range.normalize(0, self.len());
+		self.assert_in_bounds(range.end, 0 ..= self.len());
+		assert!(
+			range.start <= range.end,
+			"range {:?} is not well-formed",
+			range,
+		);
+		let len = range.end - range.start;
+		if len == 0 {
+			return Ok(<T::Mem as Integral>::ZERO);
+		}
+		let (elem, head) = unsafe { self.element_at(range.start) };
+		let (elts, tail) = head.span(len);
+		assert!(
+			elts <= 1,
+			"range {:?} crosses a memory element boundary",
+			range,
+		);
+
+		let mut old = elem.load(failure);
+		loop {
+			let found = gather::<T::Mem, O>(old, head, tail);
+			if found != truncate(current, len) {
+				return Err(found);
+			}
+			let mask =
+				scatter::<T::Mem, O>(!<T::Mem as Integral>::ZERO, head, tail);
+			let next = old & !mask | scatter::<T::Mem, O>(new, head, tail);
+			match elem.compare_exchange_weak(old, next, success, failure) {
+				Ok(_) => return Ok(found),
+				Err(actual) => old = actual,
+			}
+		}
+	}
+
+	/// Copies the bit-slice into a bit-vector, loading each memory element with
+	/// the given ordering.
+	///
+	/// This is a bulk form of [`.get_ordered()`]. Each element is loaded
+	/// atomically, but the bit-slice as a whole is not: when other threads are
+	/// writing to it, the copy may observe some elements before and others
+	/// after a given write.
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `order`: The memory ordering of each load. This must not be
+	///   [`Release`] or [`AcqRel`].
+	///
+	/// ## Returns
+	///
+	/// A bit-vector of plain integers, with the same length, contents, and
+	/// starting index within its first element as `self`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `order` is not valid for a load.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::sync::atomic::{AtomicU8, Ordering};
+	///
+	/// let data = [AtomicU8::new(0x0F), AtomicU8::new(0xF0)];
+	/// let bits = &data.view_bits::<Lsb0>()[2 .. 14];
+	/// let copy: BitVec<u8, Lsb0> = bits.load_ordered(Ordering::Acquire);
+	/// assert_eq!(copy, bits![1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1]);
+	/// ```
+	///
+	/// [`AcqRel`]: core::sync::atomic::Ordering::AcqRel
+	/// [`Release`]: core::sync::atomic::Ordering::Release
+	/// [`.get_ordered()`]: Self::get_ordered
+	#[inline]
+	#[cfg(feature = "alloc")]
+	pub fn load_ordered(&self, order: Ordering) -> BitVec<T::Mem, O> {
+		let bitspan = self.as_bitspan();
+		let base = bitspan.address().to_const();
+		let elements = (0 .. bitspan.elements())
+			.map(|idx| unsafe { &*base.add(idx) }.load(order))
+			.collect::<Vec<_>>();
+		let mut out = BitVec::from_vec(elements);
+		unsafe {
+			out.set_len_unchecked(bitspan.len());
+			out.set_head(bitspan.head());
+		}
+		out
+	}
+
+	/// Writes a bit with the given ordering, returning its previous value.
+	#[inline]
+	fn fetch_write(&self, index: usize, value: bool, order: Ordering) -> bool {
+		self.assert_in_bounds(index, 0 .. self.len());
+		let (elem, bit) = unsafe { self.element_at(index) };
+		elem.write_bit_ordered::<O>(bit, value, order)
+	}
+
+	/// Gets the memory element that holds a bit, and the bit’s index within
+	/// it.
+	///
+	/// ## Safety
+	///
+	/// `index` must be in `0 .. self.len()`.
+	#[inline]
+	unsafe fn element_at(&self, index: usize) -> (&T, BitIdx<T::Mem>) {
+		let (addr, bit) = self.as_bitptr().add(index).raw_parts();
+		(&*addr.to_const(), bit)
+	}
+}
+
+/// Collects the bits of `elem` in `head .. tail` into the low bits of an
+/// integer, with the first bit of the range least significant.
+fn gather<M, O>(elem: M, head: BitIdx<M>, tail: BitEnd<M>) -> M
+where
+	M: mem::BitRegister,
+	O: BitOrder,
+{
+	head.range(tail)
+		.enumerate()
+		.filter(|(_, idx)| elem & idx.select::<O>().into_inner() != M::ZERO)
+		.fold(M::ZERO, |out, (bit, _)| out | M::ONE << bit)
+}
+
+/// Spreads the low bits of `value` into the positions `head .. tail` of an
+/// element; the inverse of [`gather`].
+fn scatter<M, O>(value: M, head: BitIdx<M>, tail: BitEnd<M>) -> M
+where
+	M: mem::BitRegister,
+	O: BitOrder,
+{
+	head.range(tail)
+		.enumerate()
+		.filter(|(bit, _)| value & M::ONE << *bit != M::ZERO)
+		.fold(M::ZERO, |out, (_, idx)| {
+			out | idx.select::<O>().into_inner()
+		})
+}
+
+/// Clears all but the low `len` bits of `value`.
+fn truncate<M>(value: M, len: usize) -> M
+where M: mem::BitRegister {
+	if len >= mem::bits_of::<M>() {
+		value
+	}
+	else {
+		value & !(!M::ZERO << len)
+	}
+}
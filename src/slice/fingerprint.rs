@@ -0,0 +1,159 @@
+#![doc = include_str!("../../doc/slice/fingerprint.md")]
+
+use super::BitSlice;
+use crate::{
+	order::BitOrder,
+	store::BitStore,
+};
+
+/// The first multiplier.
+const P1: u64 = 0x9E37_79B1_85EB_CA87;
+/// The second multiplier.
+const P2: u64 = 0xC2B2_AE3D_27D4_EB4F;
+/// The third multiplier.
+const P3: u64 = 0x1656_67B1_9E37_79F9;
+
+/// Content fingerprints.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Computes a stable 64-bit fingerprint of the bit-slice’s contents.
+	///
+	/// The fingerprint depends only on the length and the sequence of bits. It
+	/// does not depend on the storage type, the ordering, the starting index
+	/// within memory, or the target platform, and its algorithm is fixed, so it
+	/// is suitable for persisting.
+	///
+	/// This is not a cryptographic hash, and must not be used where collisions
+	/// can be deliberately constructed.
+	///
+	/// ## Algorithm
+	///
+	/// The bit-slice is divided into 64-bit words. Bit `n` of the bit-slice is
+	/// bit `n % 64` (counting from the least significant bit) of word `n / 64`,
+	/// and the unused high bits of the last word are zero. With the constants
+	///
+	/// - `P1 = 0x9E37_79B1_85EB_CA87`
+	/// - `P2 = 0xC2B2_AE3D_27D4_EB4F`
+	/// - `P3 = 0x1656_67B1_9E37_79F9`
+	///
+	/// and all arithmetic wrapping modulo 2<sup>64</sup>, a lane begins at
+	/// `seed ^ (len * P3)`, where `len` is the length in bits. Each word `w` is
+	/// folded in by
+	///
+	/// ```text
+	/// w = rotl(w * P2, 31) * P1
+	/// state = rotl(state ^ w, 27) * P1 + P3
+	/// ```
+	///
+	/// and the final state is mixed by
+	///
+	/// ```text
+	/// state ^= state >> 33; state *= P2;
+	/// state ^= state >> 29; state *= P3;
+	/// state ^= state >> 32;
+	/// ```
+	///
+	/// The 64-bit fingerprint is a single lane with `seed = P2`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![u8, Msb0; 1, 0, 1, 1];
+	/// let b = &0b1101_0000u16.view_bits::<Lsb0>()[4 .. 8];
+	/// assert_eq!(a.fingerprint64(), b.fingerprint64());
+	/// assert_eq!(a.fingerprint64(), 0xC1C3_A5A7_D710_B918);
+	/// ```
+	#[inline]
+	pub fn fingerprint64(&self) -> u64 {
+		avalanche(self.fold_words(P2))
+	}
+
+	/// Computes a stable 128-bit fingerprint of the bit-slice’s contents.
+	///
+	/// This has the same stability guarantees as [`.fingerprint64()`], and
+	/// makes accidental collisions far less likely in very large collections.
+	///
+	/// ## Algorithm
+	///
+	/// Two lanes, `a` with `seed = P2` and `b` with `seed = P1`, run over the
+	/// words exactly as described for [`.fingerprint64()`], but their final
+	/// states are not mixed. Instead, with `mix` the final mixing step,
+	///
+	/// ```text
+	/// lo = mix(a + b)
+	/// hi = mix(b ^ rotl(lo, 32))
+	/// ```
+	///
+	/// and the fingerprint is `(hi << 64) | lo`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![u8, Msb0; 1, 0, 1, 1];
+	/// assert_eq!(
+	///   a.fingerprint128(),
+	///   0xE6B5_DF39_B74D_98FF_535E_6933_DF57_CBB2,
+	/// );
+	/// ```
+	///
+	/// [`.fingerprint64()`]: Self::fingerprint64
+	#[inline]
+	pub fn fingerprint128(&self) -> u128 {
+		let (a, b) = self.fold_words_pair(P2, P1);
+		let lo = avalanche(a.wrapping_add(b));
+		let hi = avalanche(b ^ lo.rotate_left(32));
+		(hi as u128) << 64 | lo as u128
+	}
+
+	/// Runs one lane of the fingerprint over the bit-slice.
+	fn fold_words(&self, seed: u64) -> u64 {
+		self.chunks(64)
+			.fold(start(seed, self.len()), |state, chunk| {
+				step(state, chunk.load_stream::<u64>())
+			})
+	}
+
+	/// Runs two lanes of the fingerprint over the bit-slice, loading each word
+	/// only once.
+	fn fold_words_pair(&self, seed_a: u64, seed_b: u64) -> (u64, u64) {
+		let len = self.len();
+		self.chunks(64).fold(
+			(start(seed_a, len), start(seed_b, len)),
+			|(a, b), chunk| {
+				let word = chunk.load_stream::<u64>();
+				(step(a, word), step(b, word))
+			},
+		)
+	}
+}
+
+/// Initializes a lane from its seed and the bit-slice length.
+fn start(seed: u64, len: usize) -> u64 {
+	seed ^ (len as u64).wrapping_mul(P3)
+}
+
+/// Folds one 64-bit word into a lane.
+fn step(state: u64, word: u64) -> u64 {
+	let word = word.wrapping_mul(P2).rotate_left(31).wrapping_mul(P1);
+	(state ^ word)
+		.rotate_left(27)
+		.wrapping_mul(P1)
+		.wrapping_add(P3)
+}
+
+/// Mixes the final state of a lane so that every input bit affects every
+/// output bit.
+fn avalanche(mut state: u64) -> u64 {
+	state ^= state >> 33;
+	state = state.wrapping_mul(P2);
+	state ^= state >> 29;
+	state = state.wrapping_mul(P3);
+	state ^ (state >> 32)
+}
@@ -2362,6 +2362,149 @@ where
 {
 }
 
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[doc = include_str!("../../doc/slice/iter/IterRuns.md")]
+pub struct IterRuns<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	/// The remaining bit-slice whose runs are to be found.
+	inner: &'a BitSlice<T, O>,
+	/// The offset from the front of the original bit-slice to the current
+	/// `.inner`.
+	front: usize,
+}
+
+impl<'a, T, O> IterRuns<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+	pub(super) fn new(slice: &'a BitSlice<T, O>) -> Self {
+		Self {
+			inner: slice,
+			front: 0,
+		}
+	}
+
+	/// Finds the first bit in `.inner` that differs from `value`.
+	#[inline]
+	fn first_not(&self, value: bool) -> Option<usize> {
+		if let Some(bits) = self.inner.coerce::<T, Lsb0>() {
+			if value {
+				bits.sp_first_zero()
+			}
+			else {
+				bits.sp_first_one()
+			}
+		}
+		else if let Some(bits) = self.inner.coerce::<T, Msb0>() {
+			if value {
+				bits.sp_first_zero()
+			}
+			else {
+				bits.sp_first_one()
+			}
+		}
+		else {
+			self.inner.iter().by_vals().position(|b| b != value)
+		}
+	}
+
+	/// Finds the last bit in `.inner` that differs from `value`.
+	#[inline]
+	fn last_not(&self, value: bool) -> Option<usize> {
+		if let Some(bits) = self.inner.coerce::<T, Lsb0>() {
+			if value {
+				bits.sp_last_zero()
+			}
+			else {
+				bits.sp_last_one()
+			}
+		}
+		else if let Some(bits) = self.inner.coerce::<T, Msb0>() {
+			if value {
+				bits.sp_last_zero()
+			}
+			else {
+				bits.sp_last_one()
+			}
+		}
+		else {
+			self.inner.iter().by_vals().rposition(|b| b != value)
+		}
+	}
+}
+
+impl<T, O> Default for IterRuns<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn default() -> Self {
+		Self {
+			inner: Default::default(),
+			front: 0,
+		}
+	}
+}
+
+impl<T, O> Iterator for IterRuns<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Item = (bool, usize, usize);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let value = *self.inner.first()?;
+		let len = self.first_not(value).unwrap_or_else(|| self.inner.len());
+		let (_, rest) = unsafe { self.inner.split_at_unchecked(len) };
+		self.inner = rest;
+		let start = self.front;
+		self.front += len;
+		Some((value, start, len))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.inner.len();
+		((len != 0) as usize, Some(len))
+	}
+
+	#[inline]
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
+	}
+}
+
+impl<T, O> DoubleEndedIterator for IterRuns<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let value = *self.inner.last()?;
+		let start = self.last_not(value).map_or(0, |idx| idx + 1);
+		let (rest, run) = unsafe { self.inner.split_at_unchecked(start) };
+		self.inner = rest;
+		Some((value, self.front + start, run.len()))
+	}
+}
+
+impl<T, O> FusedIterator for IterRuns<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+}
+
 /* This macro has some very obnoxious call syntax that is necessary to handle
 the different iteration protocols used above.
 
@@ -16,6 +16,8 @@ use core::ops::{
 	RangeInclusive,
 	RangeTo,
 	RangeToInclusive,
+	ShlAssign,
+	ShrAssign,
 };
 
 use super::{
@@ -122,6 +124,36 @@ where
 	}
 }
 
+impl<T, O> ShlAssign<usize> for BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	#[doc = include_str!("../../doc/slice/shift_assign.md")]
+	fn shl_assign(&mut self, by: usize) {
+		if by >= self.len() {
+			return self.fill(false);
+		}
+		self.shift_left(by);
+	}
+}
+
+impl<T, O> ShrAssign<usize> for BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	#[doc = include_str!("../../doc/slice/shift_assign.md")]
+	fn shr_assign(&mut self, by: usize) {
+		if by >= self.len() {
+			return self.fill(false);
+		}
+		self.shift_right(by);
+	}
+}
+
 impl<T, O> Index<usize> for BitSlice<T, O>
 where
 	T: BitStore,
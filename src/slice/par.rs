@@ -0,0 +1,647 @@
+#![cfg(feature = "rayon")]
+#![doc = include_str!("../../doc/slice/par.md")]
+
+use core::cmp;
+
+use rayon::iter::{
+	plumbing::{
+		bridge,
+		bridge_unindexed,
+		Consumer,
+		Folder,
+		Producer,
+		ProducerCallback,
+		UnindexedConsumer,
+		UnindexedProducer,
+	},
+	IndexedParallelIterator,
+	IntoParallelIterator,
+	ParallelIterator,
+};
+
+use super::{
+	BitSlice,
+	BitValIter,
+	Chunks,
+	ChunksMutNoAlias,
+};
+use crate::{
+	mem,
+	order::BitOrder,
+	store::BitStore,
+};
+
+/// The length, in bits, below which the parallel operations stop splitting
+/// their bit-slices and run sequentially.
+const MIN_SPLIT: usize = 1 << 14;
+
+/// Parallel operations.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore + Sync,
+	O: BitOrder,
+{
+	/// Produces a parallel iterator over each bit in the bit-slice.
+	///
+	/// ## Original
+	///
+	/// [`rayon::slice::ParallelSlice::par_iter`](https://docs.rs/rayon/latest/rayon/iter/trait.IntoParallelRefIterator.html#tymethod.par_iter)
+	///
+	/// ## API Differences
+	///
+	/// This yields each bit as a `bool`, like [`.iter().by_vals()`], rather
+	/// than as a proxy reference.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use rayon::prelude::*;
+	///
+	/// let bits = bits![0, 1, 1, 0, 1];
+	/// assert_eq!(bits.par_iter().filter(|&bit| bit).count(), 3);
+	/// ```
+	///
+	/// [`.iter().by_vals()`]: crate::slice::Iter::by_vals
+	#[inline]
+	pub fn par_iter(&self) -> ParIter<T, O> {
+		ParIter { slice: self }
+	}
+
+	/// Produces a parallel iterator over non-overlapping chunks of the
+	/// bit-slice.
+	///
+	/// This yields the same chunks as [`.chunks()`], in the same order. The
+	/// last chunk is shorter than `chunk_size` when `chunk_size` does not
+	/// divide the length of the bit-slice.
+	///
+	/// ## Original
+	///
+	/// [`rayon::slice::ParallelSlice::par_chunks`](https://docs.rs/rayon/latest/rayon/slice/trait.ParallelSlice.html#method.par_chunks)
+	///
+	/// ## Panics
+	///
+	/// This panics if `chunk_size` is `0`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use rayon::prelude::*;
+	///
+	/// let bits = bits![0, 1, 1, 0, 1];
+	/// let counts = bits
+	///   .par_chunks(2)
+	///   .map(|chunk| chunk.count_ones())
+	///   .collect::<Vec<_>>();
+	/// assert_eq!(counts, [1, 1, 1]);
+	/// ```
+	///
+	/// [`.chunks()`]: Self::chunks
+	#[inline]
+	pub fn par_chunks(&self, chunk_size: usize) -> ParChunks<T, O> {
+		assert_ne!(chunk_size, 0, "Chunk width cannot be 0");
+		ParChunks {
+			slice: self,
+			width: chunk_size,
+		}
+	}
+
+	/// Produces a parallel iterator over non-overlapping mutable chunks of the
+	/// bit-slice.
+	///
+	/// Unlike [`.chunks_mut()`], this only splits the bit-slice on element
+	/// boundaries, so that no two chunks share a memory element. As such, the
+	/// chunks do not need to be marked as aliased, and retain the `T` storage
+	/// parameter of the source bit-slice rather than `T::Alias`.
+	///
+	/// ## Original
+	///
+	/// [`rayon::slice::ParallelSliceMut::par_chunks_mut`](https://docs.rs/rayon/latest/rayon/slice/trait.ParallelSliceMut.html#method.par_chunks_mut)
+	///
+	/// ## Panics
+	///
+	/// This panics if `chunk_size` is `0` or is not a multiple of the bit-width
+	/// of `T`, or if the bit-slice does not begin at the start of a memory
+	/// element.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use rayon::prelude::*;
+	///
+	/// let mut data = [0u8; 5];
+	/// let bits = &mut data.view_bits_mut::<Lsb0>()[.. 36];
+	/// bits.par_chunks_mut(16).for_each(|chunk| {
+	///   let chunk: &mut BitSlice<u8, Lsb0> = chunk;
+	///   chunk.set(0, true);
+	/// });
+	/// assert_eq!(data, [1, 0, 1, 0, 1]);
+	/// ```
+	///
+	/// [`.chunks_mut()`]: Self::chunks_mut
+	#[inline]
+	pub fn par_chunks_mut(&mut self, chunk_size: usize) -> ParChunksMut<T, O> {
+		assert_ne!(chunk_size, 0, "Chunk width cannot be 0");
+		let bits = mem::bits_of::<T::Mem>();
+		assert_eq!(
+			chunk_size % bits,
+			0,
+			"Chunk width {} must be a multiple of the element width {}",
+			chunk_size,
+			bits,
+		);
+		assert_eq!(
+			self.as_bitspan().head().into_inner(),
+			0,
+			"Parallel mutable chunks must begin at an element boundary",
+		);
+		ParChunksMut {
+			slice: self,
+			width: chunk_size,
+		}
+	}
+
+	/// Produces a parallel iterator over the indices of each bit set to `1`
+	/// in the bit-slice.
+	///
+	/// The bit-slice is searched by many threads at once, so consumers such as
+	/// `.for_each()` observe the indices in no particular order. Consumers
+	/// that preserve order, such as `.collect()`, produce them in ascending
+	/// order.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use rayon::prelude::*;
+	///
+	/// let bits = bits![0, 1, 0, 0, 1, 1];
+	/// let ones = bits.par_iter_ones().collect::<Vec<_>>();
+	/// assert_eq!(ones, [1, 4, 5]);
+	/// ```
+	#[inline]
+	pub fn par_iter_ones(&self) -> ParIterOnes<T, O> {
+		ParIterOnes {
+			slice:  self,
+			offset: 0,
+		}
+	}
+
+	/// Counts the number of bits set to `1` in the bit-slice, in parallel.
+	///
+	/// This has the same result as [`.count_ones()`].
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1; 100_000];
+	/// assert_eq!(bits.par_count_ones(), 100_000);
+	/// ```
+	///
+	/// [`.count_ones()`]: Self::count_ones
+	#[inline]
+	pub fn par_count_ones(&self) -> usize {
+		let len = self.len();
+		if len <= MIN_SPLIT {
+			return self.count_ones();
+		}
+		let (left, right) = unsafe { self.split_at_unchecked(len / 2) };
+		let (left, right) =
+			rayon::join(|| left.par_count_ones(), || right.par_count_ones());
+		left + right
+	}
+
+	/// Merges another bit-slice into `self` with Boolean AND, in parallel.
+	///
+	/// This has the same behavior as the `&=` operator: if `rhs` is shorter
+	/// than `self`, the excess bits of `self` are cleared.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 1, 1, 1, 1];
+	/// bits.par_bitand_assign(bits![0, 1, 0]);
+	/// assert_eq!(bits, bits![0, 1, 0, 0]);
+	/// ```
+	#[inline]
+	pub fn par_bitand_assign<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>)
+	where
+		T2: BitStore + Sync,
+		O2: BitOrder,
+	{
+		self.par_zip_mut(rhs, |lhs, rhs| *lhs &= rhs);
+	}
+
+	/// Merges another bit-slice into `self` with Boolean OR, in parallel.
+	///
+	/// This has the same behavior as the `|=` operator: if `rhs` is shorter
+	/// than `self`, the excess bits of `self` are unchanged.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 0, 0, 1, 1];
+	/// bits.par_bitor_assign(bits![0, 1, 0]);
+	/// assert_eq!(bits, bits![0, 1, 1, 1]);
+	/// ```
+	#[inline]
+	pub fn par_bitor_assign<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>)
+	where
+		T2: BitStore + Sync,
+		O2: BitOrder,
+	{
+		self.par_zip_mut(rhs, |lhs, rhs| *lhs |= rhs);
+	}
+
+	/// Merges another bit-slice into `self` with Boolean XOR, in parallel.
+	///
+	/// This has the same behavior as the `^=` operator: if `rhs` is shorter
+	/// than `self`, the excess bits of `self` are unchanged.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 0, 0, 1, 1];
+	/// bits.par_bitxor_assign(bits![0, 1, 0]);
+	/// assert_eq!(bits, bits![0, 1, 1, 1]);
+	/// ```
+	#[inline]
+	pub fn par_bitxor_assign<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>)
+	where
+		T2: BitStore + Sync,
+		O2: BitOrder,
+	{
+		self.par_zip_mut(rhs, |lhs, rhs| *lhs ^= rhs);
+	}
+
+	/// Applies a mutating operation to `self` and `rhs` in parallel.
+	///
+	/// `self` is only ever split on element boundaries, so that each thread
+	/// has exclusive access to the memory elements of its half. `rhs` is split
+	/// at the same bit index; once it is exhausted, the remaining halves of
+	/// `self` receive an empty bit-slice.
+	fn par_zip_mut<T2, O2>(
+		&mut self,
+		rhs: &BitSlice<T2, O2>,
+		op: fn(&mut Self, &BitSlice<T2, O2>),
+	) where
+		T2: BitStore + Sync,
+		O2: BitOrder,
+	{
+		let len = self.len();
+		let bits = mem::bits_of::<T::Mem>();
+		let head = self.as_bitspan().head().into_inner() as usize;
+		let mid = (head + len / 2) / bits * bits;
+		if len <= MIN_SPLIT || mid <= head {
+			return op(self, rhs);
+		}
+		let mid = mid - head;
+		let (left, right) = unsafe { self.split_at_unchecked_mut_noalias(mid) };
+		let (rhs_left, rhs_right) =
+			unsafe { rhs.split_at_unchecked(cmp::min(mid, rhs.len())) };
+		rayon::join(
+			|| left.par_zip_mut(rhs_left, op),
+			|| right.par_zip_mut(rhs_right, op),
+		);
+	}
+}
+
+impl<'a, T, O> IntoParallelIterator for &'a BitSlice<T, O>
+where
+	T: 'a + BitStore + Sync,
+	O: BitOrder,
+{
+	type Item = bool;
+	type Iter = ParIter<'a, T, O>;
+
+	#[inline]
+	fn into_par_iter(self) -> Self::Iter {
+		self.par_iter()
+	}
+}
+
+#[derive(Clone, Debug)]
+/// A parallel iterator over each bit in a bit-slice.
+///
+/// This is created by [`BitSlice::par_iter`].
+pub struct ParIter<'a, T, O>
+where
+	T: 'a + BitStore + Sync,
+	O: BitOrder,
+{
+	/// The source bit-slice.
+	slice: &'a BitSlice<T, O>,
+}
+
+impl<'a, T, O> ParallelIterator for ParIter<'a, T, O>
+where
+	T: 'a + BitStore + Sync,
+	O: BitOrder,
+{
+	type Item = bool;
+
+	#[inline]
+	fn drive_unindexed<C>(self, consumer: C) -> C::Result
+	where C: UnindexedConsumer<Self::Item> {
+		bridge(self, consumer)
+	}
+
+	#[inline]
+	fn opt_len(&self) -> Option<usize> {
+		Some(self.slice.len())
+	}
+}
+
+impl<'a, T, O> IndexedParallelIterator for ParIter<'a, T, O>
+where
+	T: 'a + BitStore + Sync,
+	O: BitOrder,
+{
+	#[inline]
+	fn len(&self) -> usize {
+		self.slice.len()
+	}
+
+	#[inline]
+	fn drive<C>(self, consumer: C) -> C::Result
+	where C: Consumer<Self::Item> {
+		bridge(self, consumer)
+	}
+
+	#[inline]
+	fn with_producer<CB>(self, callback: CB) -> CB::Output
+	where CB: ProducerCallback<Self::Item> {
+		callback.callback(self)
+	}
+}
+
+impl<'a, T, O> Producer for ParIter<'a, T, O>
+where
+	T: 'a + BitStore + Sync,
+	O: BitOrder,
+{
+	type IntoIter = BitValIter<'a, T, O>;
+	type Item = bool;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.slice.iter().by_vals()
+	}
+
+	#[inline]
+	fn split_at(self, index: usize) -> (Self, Self) {
+		let (left, right) = self.slice.split_at(index);
+		(Self { slice: left }, Self { slice: right })
+	}
+}
+
+#[derive(Clone, Debug)]
+/// A parallel iterator over non-overlapping chunks of a bit-slice.
+///
+/// This is created by [`BitSlice::par_chunks`].
+pub struct ParChunks<'a, T, O>
+where
+	T: 'a + BitStore + Sync,
+	O: BitOrder,
+{
+	/// The source bit-slice.
+	slice: &'a BitSlice<T, O>,
+	/// The width of the produced chunks.
+	width: usize,
+}
+
+impl<'a, T, O> ParallelIterator for ParChunks<'a, T, O>
+where
+	T: 'a + BitStore + Sync,
+	O: BitOrder,
+{
+	type Item = &'a BitSlice<T, O>;
+
+	#[inline]
+	fn drive_unindexed<C>(self, consumer: C) -> C::Result
+	where C: UnindexedConsumer<Self::Item> {
+		bridge(self, consumer)
+	}
+
+	#[inline]
+	fn opt_len(&self) -> Option<usize> {
+		Some(IndexedParallelIterator::len(self))
+	}
+}
+
+impl<'a, T, O> IndexedParallelIterator for ParChunks<'a, T, O>
+where
+	T: 'a + BitStore + Sync,
+	O: BitOrder,
+{
+	#[inline]
+	fn len(&self) -> usize {
+		chunk_count(self.slice.len(), self.width)
+	}
+
+	#[inline]
+	fn drive<C>(self, consumer: C) -> C::Result
+	where C: Consumer<Self::Item> {
+		bridge(self, consumer)
+	}
+
+	#[inline]
+	fn with_producer<CB>(self, callback: CB) -> CB::Output
+	where CB: ProducerCallback<Self::Item> {
+		callback.callback(self)
+	}
+}
+
+impl<'a, T, O> Producer for ParChunks<'a, T, O>
+where
+	T: 'a + BitStore + Sync,
+	O: BitOrder,
+{
+	type IntoIter = Chunks<'a, T, O>;
+	type Item = &'a BitSlice<T, O>;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.slice.chunks(self.width)
+	}
+
+	#[inline]
+	fn split_at(self, index: usize) -> (Self, Self) {
+		let mid = cmp::min(index * self.width, self.slice.len());
+		let (left, right) = self.slice.split_at(mid);
+		let width = self.width;
+		(Self { slice: left, width }, Self {
+			slice: right,
+			width,
+		})
+	}
+}
+
+#[derive(Debug)]
+/// A parallel iterator over non-overlapping mutable chunks of a bit-slice.
+///
+/// This is created by [`BitSlice::par_chunks_mut`]. Each chunk begins on an
+/// element boundary, so the chunks do not carry an alias marker.
+pub struct ParChunksMut<'a, T, O>
+where
+	T: 'a + BitStore + Sync,
+	O: BitOrder,
+{
+	/// The source bit-slice.
+	slice: &'a mut BitSlice<T, O>,
+	/// The width of the produced chunks. This is a multiple of the element
+	/// width.
+	width: usize,
+}
+
+impl<'a, T, O> ParallelIterator for ParChunksMut<'a, T, O>
+where
+	T: 'a + BitStore + Sync,
+	O: BitOrder,
+{
+	type Item = &'a mut BitSlice<T, O>;
+
+	#[inline]
+	fn drive_unindexed<C>(self, consumer: C) -> C::Result
+	where C: UnindexedConsumer<Self::Item> {
+		bridge(self, consumer)
+	}
+
+	#[inline]
+	fn opt_len(&self) -> Option<usize> {
+		Some(IndexedParallelIterator::len(self))
+	}
+}
+
+impl<'a, T, O> IndexedParallelIterator for ParChunksMut<'a, T, O>
+where
+	T: 'a + BitStore + Sync,
+	O: BitOrder,
+{
+	#[inline]
+	fn len(&self) -> usize {
+		chunk_count(self.slice.len(), self.width)
+	}
+
+	#[inline]
+	fn drive<C>(self, consumer: C) -> C::Result
+	where C: Consumer<Self::Item> {
+		bridge(self, consumer)
+	}
+
+	#[inline]
+	fn with_producer<CB>(self, callback: CB) -> CB::Output
+	where CB: ProducerCallback<Self::Item> {
+		callback.callback(self)
+	}
+}
+
+impl<'a, T, O> Producer for ParChunksMut<'a, T, O>
+where
+	T: 'a + BitStore + Sync,
+	O: BitOrder,
+{
+	type IntoIter = ChunksMutNoAlias<'a, T, O>;
+	type Item = &'a mut BitSlice<T, O>;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		//  The chunk width is a multiple of the element width, and the slice
+		//  begins at an element boundary, so no two chunks share an element.
+		unsafe { self.slice.chunks_mut(self.width).remove_alias() }
+	}
+
+	#[inline]
+	fn split_at(self, index: usize) -> (Self, Self) {
+		let mid = cmp::min(index * self.width, self.slice.len());
+		let width = self.width;
+		//  `mid` is a multiple of the element width, so the halves are
+		//  disjoint in memory.
+		let (left, right) =
+			unsafe { self.slice.split_at_unchecked_mut_noalias(mid) };
+		(Self { slice: left, width }, Self {
+			slice: right,
+			width,
+		})
+	}
+}
+
+#[derive(Clone, Debug)]
+/// A parallel iterator over the indices of each `1` bit in a bit-slice.
+///
+/// This is created by [`BitSlice::par_iter_ones`].
+pub struct ParIterOnes<'a, T, O>
+where
+	T: 'a + BitStore + Sync,
+	O: BitOrder,
+{
+	/// The section of the source bit-slice still to be searched.
+	slice:  &'a BitSlice<T, O>,
+	/// The index of `slice` within the source bit-slice.
+	offset: usize,
+}
+
+impl<'a, T, O> ParallelIterator for ParIterOnes<'a, T, O>
+where
+	T: 'a + BitStore + Sync,
+	O: BitOrder,
+{
+	type Item = usize;
+
+	#[inline]
+	fn drive_unindexed<C>(self, consumer: C) -> C::Result
+	where C: UnindexedConsumer<Self::Item> {
+		bridge_unindexed(self, consumer)
+	}
+}
+
+impl<'a, T, O> UnindexedProducer for ParIterOnes<'a, T, O>
+where
+	T: 'a + BitStore + Sync,
+	O: BitOrder,
+{
+	type Item = usize;
+
+	#[inline]
+	fn split(self) -> (Self, Option<Self>) {
+		let len = self.slice.len();
+		if len <= MIN_SPLIT {
+			return (self, None);
+		}
+		let mid = len / 2;
+		let (left, right) = unsafe { self.slice.split_at_unchecked(mid) };
+		(
+			Self {
+				slice:  left,
+				offset: self.offset,
+			},
+			Some(Self {
+				slice:  right,
+				offset: self.offset + mid,
+			}),
+		)
+	}
+
+	#[inline]
+	fn fold_with<F>(self, folder: F) -> F
+	where F: Folder<Self::Item> {
+		let offset = self.offset;
+		folder.consume_iter(self.slice.iter_ones().map(|idx| idx + offset))
+	}
+}
+
+/// Counts the chunks of `width` bits needed to cover `len` bits.
+#[inline]
+fn chunk_count(len: usize, width: usize) -> usize {
+	len / width + (len % width != 0) as usize
+}
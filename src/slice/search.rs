@@ -0,0 +1,336 @@
+#![doc = include_str!("../../doc/slice/search.md")]
+
+use core::{
+	cmp,
+	iter::FusedIterator,
+};
+
+use super::BitSlice;
+use crate::{
+	mem,
+	order::BitOrder,
+	store::BitStore,
+};
+
+/// The number of bits compared in each step of a search.
+const WORD: usize = mem::bits_of::<usize>();
+
+/// Pattern searching.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Finds the first occurrence of a bit-sequence within the bit-slice.
+	///
+	/// The search key does not need to share type parameters with the bit-slice
+	/// being searched. Each candidate position is tested by comparing whole
+	/// `usize` words loaded from the bit-slice and the search key, so long keys
+	/// are rejected without inspecting each bit.
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `needle`: The bit-sequence to find.
+	///
+	/// ## Returns
+	///
+	/// The index of the first bit of the earliest match, if any. An empty
+	/// `needle` always matches at index `0`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 0, 1, 0, 1, 1, 0, 1, 1];
+	/// assert_eq!(bits.find(bits![1, 1]), Some(4));
+	/// assert_eq!(bits.find(bits![1, 1, 1]), None);
+	/// assert_eq!(bits.find(bits![]), Some(0));
+	/// ```
+	#[inline]
+	pub fn find<T2, O2>(&self, needle: &BitSlice<T2, O2>) -> Option<usize>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		Searcher::new(needle).find_in(self, 0)
+	}
+
+	/// Finds the last occurrence of a bit-sequence within the bit-slice.
+	///
+	/// This is the reverse of [`.find()`], and uses the same word-wise
+	/// comparison.
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `needle`: The bit-sequence to find.
+	///
+	/// ## Returns
+	///
+	/// The index of the first bit of the latest match, if any. An empty
+	/// `needle` always matches at index `self.len()`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 0, 1, 0, 1, 1, 0, 1, 1];
+	/// assert_eq!(bits.rfind(bits![1, 1]), Some(7));
+	/// assert_eq!(bits.rfind(bits![0, 0]), Some(0));
+	/// assert_eq!(bits.rfind(bits![]), Some(9));
+	/// ```
+	///
+	/// [`.find()`]: Self::find
+	#[inline]
+	pub fn rfind<T2, O2>(&self, needle: &BitSlice<T2, O2>) -> Option<usize>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		Searcher::new(needle).rfind_in(self)
+	}
+
+	/// Iterates over the starting index of every occurrence of a bit-sequence
+	/// within the bit-slice, including occurrences that overlap each other.
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `needle`: The bit-sequence to find.
+	///
+	/// ## Returns
+	///
+	/// An iterator over the index of the first bit of each match, in ascending
+	/// order. Use [`.match_indices()`] to skip over overlapping matches.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 1, 1, 0, 1, 1];
+	/// let found = bits.find_iter(bits![1, 1]).collect::<Vec<_>>();
+	/// assert_eq!(found, [0, 1, 4]);
+	/// ```
+	///
+	/// [`.match_indices()`]: Self::match_indices
+	#[inline]
+	pub fn find_iter<'a, 'b, T2, O2>(
+		&'a self,
+		needle: &'b BitSlice<T2, O2>,
+	) -> FindIter<'a, 'b, T, O, T2, O2>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		FindIter::new(self, needle, true)
+	}
+
+	/// Iterates over the starting index of each non-overlapping occurrence of a
+	/// bit-sequence within the bit-slice.
+	///
+	/// Once a match is found, the search resumes at the first bit after it, so
+	/// no two yielded matches share any bits.
+	///
+	/// ## Original
+	///
+	/// [`str::match_indices`](https://doc.rust-lang.org/std/primitive.str.html#method.match_indices)
+	///
+	/// ## API Differences
+	///
+	/// The matched region is always equal to `needle`, so this only yields its
+	/// index rather than an `(index, match)` pair.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 1, 1, 0, 1, 1];
+	/// let found = bits.match_indices(bits![1, 1]).collect::<Vec<_>>();
+	/// assert_eq!(found, [0, 4]);
+	/// ```
+	#[inline]
+	pub fn match_indices<'a, 'b, T2, O2>(
+		&'a self,
+		needle: &'b BitSlice<T2, O2>,
+	) -> FindIter<'a, 'b, T, O, T2, O2>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		FindIter::new(self, needle, false)
+	}
+}
+
+#[derive(Clone, Debug)]
+#[doc = include_str!("../../doc/slice/search/FindIter.md")]
+pub struct FindIter<'a, 'b, T1, O1, T2, O2>
+where
+	T1: BitStore,
+	O1: BitOrder,
+	T2: BitStore,
+	O2: BitOrder,
+{
+	/// The bit-slice being searched.
+	haystack:    &'a BitSlice<T1, O1>,
+	/// The search key.
+	searcher:    Searcher<'b, T2, O2>,
+	/// The index at which the next search begins.
+	///
+	/// This is greater than `haystack.len()` once the iterator is exhausted.
+	next:        usize,
+	/// Whether the next search begins one bit after the previous match, rather
+	/// than after its end.
+	overlapping: bool,
+}
+
+impl<'a, 'b, T1, O1, T2, O2> FindIter<'a, 'b, T1, O1, T2, O2>
+where
+	T1: BitStore,
+	O1: BitOrder,
+	T2: BitStore,
+	O2: BitOrder,
+{
+	/// Begins a search for `needle` within `haystack`.
+	#[inline]
+	fn new(
+		haystack: &'a BitSlice<T1, O1>,
+		needle: &'b BitSlice<T2, O2>,
+		overlapping: bool,
+	) -> Self {
+		Self {
+			haystack,
+			searcher: Searcher::new(needle),
+			next: 0,
+			overlapping,
+		}
+	}
+}
+
+impl<T1, O1, T2, O2> Iterator for FindIter<'_, '_, T1, O1, T2, O2>
+where
+	T1: BitStore,
+	O1: BitOrder,
+	T2: BitStore,
+	O2: BitOrder,
+{
+	type Item = usize;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		match self.searcher.find_in(self.haystack, self.next) {
+			Some(idx) => {
+				let step = if self.overlapping {
+					1
+				}
+				else {
+					self.searcher.needle.len()
+				};
+				self.next = idx + cmp::max(step, 1);
+				Some(idx)
+			},
+			None => {
+				self.next = self.haystack.len() + 1;
+				None
+			},
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0, Some((self.haystack.len() + 1).saturating_sub(self.next)))
+	}
+}
+
+impl<T1, O1, T2, O2> FusedIterator for FindIter<'_, '_, T1, O1, T2, O2>
+where
+	T1: BitStore,
+	O1: BitOrder,
+	T2: BitStore,
+	O2: BitOrder,
+{
+}
+
+/// A search key, with its leading word loaded ahead of time.
+#[derive(Clone, Copy, Debug)]
+struct Searcher<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The bit-sequence being sought.
+	needle: &'a BitSlice<T, O>,
+	/// The first `WORD` bits of `needle`, as loaded by `.load_stream()`.
+	first:  usize,
+}
+
+impl<'a, T, O> Searcher<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Prepares a search key.
+	#[inline]
+	fn new(needle: &'a BitSlice<T, O>) -> Self {
+		let first = needle[.. cmp::min(needle.len(), WORD)].load_stream();
+		Self { needle, first }
+	}
+
+	/// Tests whether the search key occurs in `haystack` at `idx`.
+	///
+	/// The leading word of the window is compared against the preloaded
+	/// leading word of the key before any further words are loaded.
+	///
+	/// ## Panics
+	///
+	/// This panics if the key does not fit in `haystack` at `idx`.
+	#[inline]
+	fn matches_at<T2, O2>(&self, haystack: &BitSlice<T2, O2>, idx: usize) -> bool
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let len = self.needle.len();
+		let window = &haystack[idx .. idx + len];
+		let head = cmp::min(len, WORD);
+		window[.. head].load_stream::<usize>() == self.first
+			&& window[head ..]
+				.chunks(WORD)
+				.zip(self.needle[head ..].chunks(WORD))
+				.all(|(hay, key)| {
+					hay.load_stream::<usize>() == key.load_stream::<usize>()
+				})
+	}
+
+	/// Finds the first occurrence of the key in `haystack` at or after `from`.
+	#[inline]
+	fn find_in<T2, O2>(
+		&self,
+		haystack: &BitSlice<T2, O2>,
+		from: usize,
+	) -> Option<usize>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let last = haystack.len().checked_sub(self.needle.len())?;
+		(from ..= last).find(|&idx| self.matches_at(haystack, idx))
+	}
+
+	/// Finds the last occurrence of the key in `haystack`.
+	#[inline]
+	fn rfind_in<T2, O2>(&self, haystack: &BitSlice<T2, O2>) -> Option<usize>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let last = haystack.len().checked_sub(self.needle.len())?;
+		(0 ..= last)
+			.rev()
+			.find(|&idx| self.matches_at(haystack, idx))
+	}
+}
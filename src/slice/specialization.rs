@@ -1,17 +1,26 @@
 #![doc = include_str!("../../doc/slice/specialization.md")]
 
-use funty::Integral;
+use funty::{
+	Integral,
+	Unsigned,
+};
 
 use super::BitSlice;
 use crate::{
 	devel as dvl,
+	field::BitField,
 	mem,
-	order::BitOrder,
+	order::{
+		BitOrder,
+		Lsb0,
+		Msb0,
+	},
 	store::BitStore,
 };
 
 mod lsb0;
 mod msb0;
+mod swapped;
 
 /// Processor width, used for chunking.
 const WORD_BITS: usize = mem::bits_of::<usize>();
@@ -63,6 +72,50 @@ where
 		}
 	}
 
+	/// Loads the bit-slice into an integer, in a layout that is independent of
+	/// the bit-slice’s type parameters.
+	///
+	/// The bit at index `n` in the bit-slice is placed at bit `n` of the
+	/// returned integer (counting from the least significant bit), and any
+	/// remaining high bits are zero. Two bit-slices are equal exactly when
+	/// their loaded integers are equal. `Lsb0`, `Msb0`, and their swapped
+	/// variants use their `BitField` implementations; other orderings are
+	/// loaded bit by bit.
+	///
+	/// ## Panics
+	///
+	/// This panics if the bit-slice is wider than `M`.
+	pub(crate) fn load_stream<M>(&self) -> M
+	where M: Unsigned {
+		let len = self.len();
+		let width = mem::bits_of::<M>();
+		assert!(
+			len <= width,
+			"cannot load {} bits into a {}-bit integer",
+			len,
+			width
+		);
+		if len == 0 {
+			M::ZERO
+		}
+		else if let Some(bits) = self.coerce::<T, Lsb0>() {
+			bits.load_le::<M>()
+		}
+		else if let Some(bits) = self.coerce::<T, Msb0>() {
+			bits.load_be::<M>().reverse_bits() >> (width - len)
+		}
+		else if let Some(bits) = self.sp_load_swapped::<M>() {
+			bits
+		}
+		else {
+			self.iter()
+				.by_vals()
+				.enumerate()
+				.filter(|(_, bit)| *bit)
+				.fold(M::ZERO, |word, (idx, _)| word | (M::ONE << idx))
+		}
+	}
+
 	/// See [`.coerce()`].
 	///
 	/// [`.coerce()`]: Self::coerce
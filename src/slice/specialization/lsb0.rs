@@ -307,4 +307,9 @@ where T: BitStore
 			that.store_le(a);
 		}
 	}
+
+	/// Accelerates storing a processor word.
+	pub(crate) fn sp_store_word(&mut self, word: usize) {
+		self.store_le(word);
+	}
 }
@@ -302,4 +302,10 @@ where T: BitStore
 			that.store_be(a);
 		}
 	}
+
+	/// Accelerates storing a processor word.
+	pub(crate) fn sp_store_word(&mut self, word: usize) {
+		let len = self.len();
+		self.store_be(word.reverse_bits() >> (WORD_BITS - len));
+	}
 }
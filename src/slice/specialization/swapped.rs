@@ -0,0 +1,107 @@
+//! Specializations for the byte- and nibble-swapped orderings.
+//!
+//! These orderings do not have their own batched algorithms, but they do have
+//! `BitField` implementations, so they can exchange processor words with the
+//! rest of the crate instead of falling back to bit-by-bit access.
+
+use funty::Unsigned;
+
+use super::WORD_BITS;
+use crate::{
+	field::BitField,
+	mem::bits_of,
+	order::{
+		BitOrder,
+		Lsb0ByteSwap,
+		Lsb0NibbleSwap,
+		Msb0ByteSwap,
+		Msb0NibbleSwap,
+	},
+	slice::BitSlice,
+	store::BitStore,
+};
+
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Accelerates loading a bit-slice into an integer, if its ordering is one
+	/// of the swapped orderings.
+	///
+	/// The bit-slice must not be empty. Bit `n` of the produced integer is
+	/// `self[n]`.
+	pub(crate) fn sp_load_swapped<M>(&self) -> Option<M>
+	where M: Unsigned {
+		if let Some(bits) = self.coerce::<T, Lsb0ByteSwap>() {
+			return Some(load_ascending(bits));
+		}
+		if let Some(bits) = self.coerce::<T, Lsb0NibbleSwap>() {
+			return Some(load_ascending(bits));
+		}
+		if let Some(bits) = self.coerce::<T, Msb0ByteSwap>() {
+			return Some(load_descending(bits));
+		}
+		if let Some(bits) = self.coerce::<T, Msb0NibbleSwap>() {
+			return Some(load_descending(bits));
+		}
+		None
+	}
+
+	/// Accelerates storing a processor word, if the bit-slice’s ordering is one
+	/// of the swapped orderings.
+	///
+	/// The bit-slice must not be empty. Returns `false`, having written
+	/// nothing, for all other orderings.
+	pub(crate) fn sp_store_word_swapped(&mut self, word: usize) -> bool {
+		if let Some(bits) = self.coerce_mut::<T, Lsb0ByteSwap>() {
+			bits.store_le(word);
+		}
+		else if let Some(bits) = self.coerce_mut::<T, Lsb0NibbleSwap>() {
+			bits.store_le(word);
+		}
+		else if let Some(bits) = self.coerce_mut::<T, Msb0ByteSwap>() {
+			store_descending(bits, word);
+		}
+		else if let Some(bits) = self.coerce_mut::<T, Msb0NibbleSwap>() {
+			store_descending(bits, word);
+		}
+		else {
+			return false;
+		}
+		true
+	}
+}
+
+/// Loads from an ordering whose indices rise in significance, as in `Lsb0`.
+fn load_ascending<T, O, M>(bits: &BitSlice<T, O>) -> M
+where
+	T: BitStore,
+	O: BitOrder,
+	M: Unsigned,
+	BitSlice<T, O>: BitField,
+{
+	bits.load_le::<M>()
+}
+
+/// Loads from an ordering whose indices fall in significance, as in `Msb0`.
+fn load_descending<T, O, M>(bits: &BitSlice<T, O>) -> M
+where
+	T: BitStore,
+	O: BitOrder,
+	M: Unsigned,
+	BitSlice<T, O>: BitField,
+{
+	bits.load_be::<M>().reverse_bits() >> (bits_of::<M>() - bits.len())
+}
+
+/// Stores into an ordering whose indices fall in significance, as in `Msb0`.
+fn store_descending<T, O>(bits: &mut BitSlice<T, O>, word: usize)
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	let len = bits.len();
+	bits.store_be(word.reverse_bits() >> (WORD_BITS - len));
+}
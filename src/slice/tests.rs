@@ -271,3 +271,78 @@ fn cooking() {
 		);
 	}
 }
+
+#[test]
+#[cfg(all(feature = "std", feature = "atomic"))]
+fn ordered_atomics() {
+	use std::{
+		sync::{
+			Arc,
+			atomic::{
+				AtomicU16,
+				AtomicUsize,
+				Ordering,
+			},
+		},
+		thread,
+	};
+
+	let slots = Arc::new([
+		AtomicUsize::new(0),
+		AtomicUsize::new(0),
+		AtomicUsize::new(0),
+		AtomicUsize::new(0),
+	]);
+	let claimed = (0 .. 4)
+		.map(|_| {
+			let slots = slots.clone();
+			thread::spawn(move || {
+				let bits = slots.view_bits::<Msb0>();
+				(0 .. bits.len())
+					.filter(|&idx| !bits.test_and_set(idx, Ordering::AcqRel))
+					.count()
+			})
+		})
+		.collect::<Vec<_>>()
+		.into_iter()
+		.map(|handle| handle.join().unwrap())
+		.sum::<usize>();
+	let bits = slots.view_bits::<Msb0>();
+	assert_eq!(claimed, bits.len());
+	assert!(bits.load_ordered(Ordering::Acquire).all());
+
+	assert!(bits.fetch_clear(5, Ordering::Release));
+	assert!(!bits.get_ordered(5, Ordering::Acquire));
+	assert!(!bits.fetch_toggle(5, Ordering::AcqRel));
+	assert!(bits.get_ordered(5, Ordering::Acquire));
+
+	let counter = Arc::new(AtomicU16::new(0xFFFF));
+	(0 .. 4)
+		.map(|_| {
+			let counter = counter.clone();
+			thread::spawn(move || {
+				let field = &counter.view_bits::<HiLo>()[3 .. 11];
+				for _ in 0 .. 30 {
+					let mut seen = 0;
+					while let Err(found) = field.compare_exchange_bits(
+						..,
+						seen,
+						seen.wrapping_add(1) & 0xFF,
+						Ordering::AcqRel,
+						Ordering::Acquire,
+					) {
+						seen = found;
+					}
+				}
+			})
+		})
+		.collect::<Vec<_>>()
+		.into_iter()
+		.for_each(|handle| handle.join().unwrap());
+	let bits = counter.view_bits::<HiLo>();
+	assert_eq!(
+		bits[3 .. 11].load_ordered(Ordering::Acquire),
+		(0xFFu16 + 120).view_bits::<Lsb0>()[.. 8],
+	);
+	assert!(bits[.. 3].all() && bits[11 ..].all());
+}
@@ -137,3 +137,68 @@ fn inspectors() {
 	assert!(bits.ends_with(bits![1, 0, 1]));
 	assert!(!bits.ends_with(bits![0, 0, 1]));
 }
+
+#[test]
+fn search() {
+	use crate::order::HiLo;
+
+	fn naive<'a, T1, O1, T2, O2>(
+		haystack: &'a BitSlice<T1, O1>,
+		needle: &'a BitSlice<T2, O2>,
+	) -> impl 'a + Iterator<Item = usize>
+	where
+		T1: BitStore,
+		O1: BitOrder,
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		haystack
+			.windows(needle.len())
+			.enumerate()
+			.filter(move |(_, window)| *window == needle)
+			.map(|(idx, _)| idx)
+	}
+
+	let mut data = [0u16; 40];
+	for (idx, elem) in data.iter_mut().enumerate() {
+		*elem = if idx % 7 == 3 { 0xA5C3 } else { rand::random() };
+	}
+	let msb = &data.view_bits::<Msb0>()[3 ..];
+	let lsb = &data.view_bits::<Lsb0>()[5 ..];
+	let hilo = &data.view_bits::<HiLo>()[1 ..];
+
+	for len in &[1, 3, 16, 40, 70, 100, 150] {
+		let mut buf = BitArray::<[u16; 10], Msb0>::ZERO;
+		let needle = &mut buf[.. *len];
+		needle.copy_from_bitslice(&msb[200 .. 200 + len]);
+		let needle = &*needle;
+		let lsb_needle = &lsb[100 .. 100 + len];
+		let hilo_needle = &hilo[300 .. 300 + len];
+
+		assert!(msb.find_iter(needle).eq(naive(msb, needle)));
+		assert_eq!(msb.find(needle), naive(msb, needle).next());
+		assert_eq!(msb.rfind(needle), naive(msb, needle).last());
+
+		assert!(msb.find_iter(lsb_needle).eq(naive(msb, lsb_needle)));
+
+		assert!(lsb.find_iter(hilo_needle).eq(naive(lsb, hilo_needle)));
+		assert_eq!(lsb.rfind(hilo_needle), naive(lsb, hilo_needle).last());
+
+		assert!(hilo.find_iter(needle).eq(naive(hilo, needle)));
+		assert_eq!(hilo.contains(needle), naive(hilo, needle).next().is_some());
+	}
+
+	let bits = bits![1, 1, 1, 1, 1, 0, 1, 1];
+	assert!(
+		bits.find_iter(bits![1, 1])
+			.eq([0, 1, 2, 3, 6].iter().copied())
+	);
+	assert!(
+		bits.match_indices(bits![1, 1])
+			.eq([0, 2, 6].iter().copied())
+	);
+	assert!(bits.match_indices(bits![]).eq(0 ..= 8));
+	assert_eq!(bits.find(bits![0; 2]), None);
+	assert_eq!(bits![0; 3].rfind(bits![0; 4]), None);
+	assert_eq!(BitSlice::<u8, Lsb0>::empty().find(bits![]), Some(0));
+}
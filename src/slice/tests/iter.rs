@@ -701,3 +701,137 @@ fn trait_impls() {
 		FusedIterator
 	);
 }
+
+#[test]
+fn iter_runs() {
+	use crate::order::HiLo;
+
+	let mut data = [0u32; 4];
+	let bits = data.view_bits_mut::<Msb0>();
+	bits[5 .. 70].fill(true);
+	bits[71 .. 72].fill(true);
+	bits[100 ..].fill(true);
+	let bits = &bits[3 ..];
+	let expected = [
+		(false, 0, 2),
+		(true, 2, 65),
+		(false, 67, 1),
+		(true, 68, 1),
+		(false, 69, 28),
+		(true, 97, 28),
+	];
+
+	assert!(bits.iter_runs().eq(expected.iter().copied()));
+	assert!(bits.iter_runs().rev().eq(expected.iter().rev().copied()));
+
+	let mut runs = bits.iter_runs();
+	assert_eq!(runs.next(), Some(expected[0]));
+	assert_eq!(runs.next_back(), Some(expected[5]));
+	assert_eq!(runs.next(), Some(expected[1]));
+	assert_eq!(runs.next_back(), Some(expected[4]));
+	assert_eq!(runs.next_back(), Some(expected[3]));
+	assert_eq!(runs.next(), Some(expected[2]));
+	assert!(runs.next().is_none());
+	assert!(runs.next_back().is_none());
+
+	let mut copy = [0u32; 4];
+	let copy_bits = copy.view_bits_mut::<Lsb0>();
+	copy_bits.clone_from_bitslice(data.view_bits::<Msb0>());
+	let copy_bits = &copy_bits[3 ..];
+	assert!(copy_bits.iter_runs().eq(expected.iter().copied()));
+	assert!(
+		copy_bits
+			.iter_runs()
+			.rev()
+			.eq(expected.iter().rev().copied())
+	);
+
+	let mut other = [0u16; 8];
+	let other_bits = other.view_bits_mut::<HiLo>();
+	other_bits.clone_from_bitslice(data.view_bits::<Msb0>());
+	let other_bits = &other_bits[3 ..];
+	assert!(other_bits.iter_runs().eq(expected.iter().copied()));
+	assert!(
+		other_bits
+			.iter_runs()
+			.rev()
+			.eq(expected.iter().rev().copied())
+	);
+
+	assert!(BitSlice::<u8, Lsb0>::empty().iter_runs().next().is_none());
+	assert_eq!(bits![1; 5].iter_runs().last(), Some((true, 0, 5)));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn runs_round_trip() {
+	let mut data = [0x5Au8, 0x00, 0xFF, 0x81];
+	let bits = data.view_bits_mut::<Lsb0>();
+	let runs = bits.to_runs();
+	assert_eq!(runs[.. 3], [1, 1, 1]);
+	assert_eq!(runs.iter().sum::<usize>(), 32);
+	assert_eq!(BitVec::<u16, Msb0>::from_runs(runs), bits);
+
+	bits.set(0, true);
+	let runs = bits.to_runs();
+	assert_eq!(runs[0], 0);
+	assert_eq!(BitVec::<u8, Lsb0>::from_runs(runs), bits);
+
+	assert!(BitSlice::<u8, Lsb0>::empty().to_runs().is_empty());
+	assert!(BitVec::<u8, Lsb0>::from_runs(None).is_empty());
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn parallel() {
+	use rayon::prelude::*;
+
+	let data = (0 .. 4096).map(|_| rand::random::<u32>()).collect::<Vec<_>>();
+	let bits = &data.view_bits::<Msb0>()[5 .. 130_000];
+
+	assert_eq!(bits.par_count_ones(), bits.count_ones());
+	assert_eq!(bits.par_iter().filter(|&bit| bit).count(), bits.count_ones());
+	assert_eq!(
+		bits.par_iter_ones().collect::<Vec<_>>(),
+		bits.iter_ones().collect::<Vec<_>>(),
+	);
+	assert!(
+		bits.par_chunks(1000)
+			.zip(bits.chunks(1000).collect::<Vec<_>>())
+			.all(|(a, b)| a == b)
+	);
+	assert_eq!(bits.par_chunks(1000).len(), 130);
+
+	let mut copy = data.clone();
+	copy.view_bits_mut::<Msb0>()
+		.par_chunks_mut(64)
+		.enumerate()
+		.for_each(|(idx, chunk)| chunk.store_be(idx as u64));
+	assert_eq!(copy[3], 1);
+	assert_eq!(copy[2047 * 2 + 1], 2047);
+
+	let mut lhs = data.clone();
+	let rhs = bits![1; 100_000];
+	let mut expected = data.clone();
+	expected.view_bits_mut::<Msb0>()[3 ..] &= rhs;
+	lhs.view_bits_mut::<Msb0>()[3 ..].par_bitand_assign(rhs);
+	assert_eq!(lhs, expected);
+
+	let mut lhs = data.clone();
+	let rhs = &data.view_bits::<Msb0>()[7 .. 100_007];
+	let mut expected = data.clone();
+	expected.view_bits_mut::<Msb0>()[3 ..] ^= rhs;
+	lhs.view_bits_mut::<Msb0>()[3 ..].par_bitxor_assign(rhs);
+	assert_eq!(lhs, expected);
+	expected.view_bits_mut::<Msb0>()[3 ..] |= rhs;
+	lhs.view_bits_mut::<Msb0>()[3 ..].par_bitor_assign(rhs);
+	assert_eq!(lhs, expected);
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+#[should_panic(expected = "must be a multiple of the element width")]
+fn par_chunks_mut_unaligned() {
+	let mut data = [0u8; 4];
+	let _ = data.view_bits_mut::<Lsb0>().par_chunks_mut(12);
+}
@@ -108,6 +108,89 @@ fn bit_not() {
 	assert_eq!(c, [0xFF_FF_00_00, 0xFF_00_00_FF, 0x00_00_FF_FF]);
 }
 
+#[test]
+fn shifts() {
+	let a = random::<[u32; 3]>();
+
+	let mut b = a;
+	*b.view_bits_mut::<Lsb0>() <<= 40;
+	assert_eq!(b, [a[1] >> 8 | a[2] << 24, a[2] >> 8, 0]);
+
+	let mut b = a;
+	*b.view_bits_mut::<Msb0>() >>= 40;
+	assert_eq!(b, [0, a[0] >> 8, a[0] << 24 | a[1] >> 8]);
+
+	let mut c = a;
+	let bits = &mut c.view_bits_mut::<Msb0>()[5 .. 90];
+	*bits <<= 90;
+	assert!(bits.not_any());
+	let mut d = a;
+	*d.view_bits_mut::<Lsb0>() >>= 0;
+	assert_eq!(a, d);
+}
+
+#[test]
+fn set_relations() {
+	let a = random::<[u32; 4]>();
+	let b = random::<[u8; 15]>();
+	let a = &a.view_bits::<Lsb0>()[3 .. 125];
+	let b = &b.view_bits::<Msb0>()[5 .. 100];
+	let pairs = || a.iter().by_vals().zip(b.iter().by_vals());
+
+	let and = pairs().filter(|&(a, b)| a & b).count();
+	assert_eq!(a.count_and(b), and);
+	assert_eq!(b.count_and(a), and);
+	let or =
+		pairs().filter(|&(a, b)| a | b).count() + a[b.len() ..].count_ones();
+	assert_eq!(a.count_or(b), or);
+	assert_eq!(b.count_or(a), or);
+	assert_eq!(a.intersects(b), and > 0);
+	assert_eq!(a.is_disjoint(b), and == 0);
+
+	let c = bits![u16, crate::order::HiLo; 0, 1, 1, 0, 0, 1, 0, 1, 1, 1];
+	let d = bits![u8, Msb0; 1, 1, 1, 0, 0, 1, 0, 1, 1, 1, 0, 1, 1];
+	assert!(c.is_subset(d));
+	assert!(!d.is_subset(c));
+	assert!(!c.is_subset(&d[.. 9]));
+	assert!(!c.is_subset(&d[.. 3]));
+	assert_eq!(c.count_and(d), 6);
+	assert_eq!(c.count_or(d), 9);
+	assert!(!bits![].intersects(d));
+	assert!(bits![].is_subset(bits![]));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn bitop_to_bitvec() {
+	let a = random::<[u32; 4]>();
+	let b = random::<[u8; 15]>();
+	let a = &a.view_bits::<Lsb0>()[3 .. 125];
+	let b = &b.view_bits::<Msb0>()[5 .. 100];
+	let reference = |op: fn(bool, bool) -> bool| {
+		a.iter()
+			.by_vals()
+			.zip(b.iter().by_vals().chain(core::iter::repeat(false)))
+			.map(|(a, b)| op(a, b))
+			.collect::<BitVec>()
+	};
+
+	assert_eq!(a & b, reference(|a, b| a & b));
+	assert_eq!(a | b, reference(|a, b| a | b));
+	assert_eq!(a ^ b, reference(|a, b| a ^ b));
+	assert_eq!(a.and_not(b), reference(|a, b| a & !b));
+	assert_eq!(a.nand(b), reference(|a, b| !(a & b)));
+	assert_eq!(a.nor(b), reference(|a, b| !(a | b)));
+	assert_eq!((b & a).len(), a.len());
+	assert_eq!(b & a, a & b);
+
+	let c = bits![u16, crate::order::HiLo; 1, 0, 1, 1, 0, 0, 1, 0, 1];
+	let d = bits![u8, Msb0; 1, 1, 0, 0];
+	assert_eq!(c & d, bits![1, 0, 0, 0, 0, 0, 0, 0, 0]);
+	assert_eq!(d | c, bits![1, 1, 1, 1, 0, 0, 1, 0, 1]);
+	assert_eq!(c.nor(d), bits![0, 0, 0, 0, 1, 1, 0, 1, 0]);
+	assert!((bits![] & bits![]).is_empty());
+}
+
 #[test]
 fn indexing() {
 	let bits = bits![mut 0, 1, 0, 0, 1];
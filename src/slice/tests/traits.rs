@@ -347,3 +347,51 @@ mod format {
 		);
 	}
 }
+
+#[test]
+#[cfg(feature = "std")]
+fn hashing() {
+	use std::{
+		collections::hash_map::DefaultHasher,
+		hash::{
+			Hash,
+			Hasher,
+		},
+	};
+
+	fn hash_of<T>(value: &T) -> u64
+	where T: Hash + ?Sized {
+		let mut hasher = DefaultHasher::new();
+		value.hash(&mut hasher);
+		hasher.finish()
+	}
+
+	let data = rand::random::<[u32; 8]>();
+	let msb = &data.view_bits::<Msb0>()[3 .. 203];
+	let lsb = msb.iter().by_vals().collect::<BitVec<u8, Lsb0>>();
+	let mut shifted = bitvec![u16, Msb0; 0; 5];
+	shifted.extend_from_bitslice(msb);
+
+	assert_eq!(msb, lsb);
+	assert_eq!(hash_of(msb), hash_of(&lsb));
+	assert_eq!(hash_of(msb), hash_of(&shifted[5 ..]));
+	assert_eq!(hash_of(msb), hash_of(&msb.to_bitvec()));
+	assert_ne!(hash_of(&msb[.. 199]), hash_of(&msb[1 ..]));
+	assert_ne!(hash_of(bits![0; 3]), hash_of(bits![0; 4]));
+
+	assert_eq!(msb.fingerprint64(), lsb.fingerprint64());
+	assert_eq!(msb.fingerprint128(), shifted[5 ..].fingerprint128());
+
+	let empty = BitSlice::<u8, Lsb0>::empty();
+	assert_eq!(empty.fingerprint64(), 0xB48B_4CD0_2006_72EE);
+	assert_eq!(
+		empty.fingerprint128(),
+		0xEFB3_AE5A_D787_8060_C610_7730_C166_2C77
+	);
+	let ones = bits![u32, Msb0; 1; 100];
+	assert_eq!(ones.fingerprint64(), 0x5139_FA83_CB40_9749);
+	assert_eq!(
+		ones.fingerprint128(),
+		0xC68D_A63E_1B7E_CBA6_A0C2_07E0_8B8D_6C70
+	);
+}
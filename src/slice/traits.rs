@@ -367,7 +367,7 @@ where
 {
 	#[inline]
 	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
-		self.as_bitspan().render(fmt, "Slice", None)?;
+		self.as_bitspan().render(fmt, "BitSlice", None)?;
 		fmt.write_str(" ")?;
 		Display::fmt(self, fmt)
 	}
@@ -527,8 +527,15 @@ fmt! {
 	UpperHex: b'A', b'x', 4;
 }
 
-/// [Original](https://doc.rust-lang.org/std/primitive.slice.html#impl-Hash)
-#[cfg(not(tarpaulin_include))]
+/** Hashes the bit-slice’s length and contents.
+
+The contents are fed to the hasher as `usize` words in a layout that depends
+only on the bit sequence, not on the storage type, ordering, or starting index
+of the bit-slice. Bit-slices that compare equal under the cross-type
+[`PartialEq`] implementation therefore always hash equally.
+
+[Original](https://doc.rust-lang.org/std/primitive.slice.html#impl-Hash)
+**/
 impl<T, O> Hash for BitSlice<T, O>
 where
 	T: BitStore,
@@ -537,7 +544,10 @@ where
 	#[inline]
 	fn hash<H>(&self, hasher: &mut H)
 	where H: Hasher {
-		self.iter().by_vals().for_each(|bit| bit.hash(hasher));
+		hasher.write_usize(self.len());
+		for chunk in self.chunks(mem::bits_of::<usize>()) {
+			hasher.write_usize(chunk.load_stream::<usize>());
+		}
 	}
 }
 
@@ -4,12 +4,13 @@
 #[cfg(not(feature = "std"))]
 use alloc::vec;
 use alloc::vec::Vec;
+#[cfg(feature = "allocator")]
+use core::ptr;
 use core::{
 	mem::{
 		self,
 		ManuallyDrop,
 	},
-	ptr,
 	slice,
 };
 
@@ -19,18 +20,38 @@ use wyz::comu::{
 	Mut,
 };
 
-pub use self::iter::{
-	Drain,
-	Splice,
+#[cfg(feature = "allocator")]
+pub use self::allocator::{
+	AllocError,
+	Allocator,
+	Global,
+};
+pub use self::{
+	fallible::{
+		TryReserveError,
+		TryReserveErrorKind,
+	},
+	iter::{
+		Drain,
+		Splice,
+	},
+	small::SmallBitVec,
 };
 pub use crate::boxed::IntoIter;
 use crate::{
 	boxed::BitBox,
+	devel as dvl,
 	index::BitIdx,
 	mem::bits_of,
 	order::{
 		BitOrder,
 		Lsb0,
+		Msb0,
+	},
+	parse::{
+		self,
+		ParseBitsError,
+		Radix,
 	},
 	ptr::{
 		AddressExt,
@@ -43,16 +64,22 @@ use crate::{
 	view::BitView,
 };
 
+mod allocator;
 mod api;
+mod fallible;
 mod iter;
 mod ops;
+mod small;
 mod tests;
 mod traits;
 
 #[repr(C)]
 #[doc = include_str!("../doc/vec/BitVec.md")]
-pub struct BitVec<T = usize, O = Lsb0>
-where
+pub struct BitVec<
+	T = usize,
+	O = Lsb0,
+	#[cfg(feature = "allocator")] A: Allocator = Global,
+> where
 	T: BitStore,
 	O: BitOrder,
 {
@@ -60,6 +87,9 @@ where
 	bitspan:  BitSpan<Mut, T, O>,
 	/// Allocation capacity, measured in `T` elements.
 	capacity: usize,
+	/// The allocator that owns the memory.
+	#[cfg(feature = "allocator")]
+	alloc:    A,
 }
 
 /// Constructors.
@@ -72,6 +102,8 @@ where
 	pub const EMPTY: Self = Self {
 		bitspan:  BitSpan::EMPTY,
 		capacity: 0,
+		#[cfg(feature = "allocator")]
+		alloc:    Global,
 	};
 
 	/// Creates a new bit-vector by repeating a bit for the desired length.
@@ -135,7 +167,43 @@ where
 			)
 		};
 		let capacity = vec.capacity();
-		Self { bitspan, capacity }
+		Self {
+			bitspan,
+			capacity,
+			#[cfg(feature = "allocator")]
+			alloc: Global,
+		}
+	}
+
+	/// Decodes a sequence of run lengths into a bit-vector.
+	///
+	/// The lengths alternate between runs of `0` bits and runs of `1` bits,
+	/// beginning with `0`. Any length may be zero, so a leading zero produces a
+	/// bit-vector that begins with `1`.
+	///
+	/// This is the inverse of [`BitSlice::to_runs`].
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = BitVec::<u8, Msb0>::from_runs([0, 2, 3, 1].iter().copied());
+	/// assert_eq!(bv, bits![1, 1, 0, 0, 0, 1]);
+	/// assert_eq!(bv.to_runs(), [0, 2, 3, 1]);
+	/// ```
+	///
+	/// [`BitSlice::to_runs`]: crate::slice::BitSlice::to_runs
+	#[inline]
+	pub fn from_runs<I>(runs: I) -> Self
+	where I: IntoIterator<Item = usize> {
+		let mut out = Self::new();
+		let mut value = false;
+		for len in runs {
+			out.resize(out.len() + len, value);
+			value = !value;
+		}
+		out
 	}
 
 	/// Constructs a new bit-vector from a single element.
@@ -246,10 +314,100 @@ where
 
 		BitPtr::from_mut_slice(vec.as_mut_slice())
 			.span(vec.len() * bits_of::<T::Mem>())
-			.map(|bitspan| Self { bitspan, capacity })
+			.map(|bitspan| Self {
+				bitspan,
+				capacity,
+				#[cfg(feature = "allocator")]
+				alloc: Global,
+			})
 			.map_err(|_| ManuallyDrop::into_inner(vec))
 	}
 
+	/// Parses a bit-vector from binary text.
+	///
+	/// Every word in the text is read as binary digits, and may carry a `0b`
+	/// prefix. See the [`parse`] module for the accepted grammar.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = BitVec::<u8, Msb0>::from_bin_str("[0b0110, 0b1_1]").unwrap();
+	/// assert_eq!(bv, bits![0, 1, 1, 0, 1, 1]);
+	/// ```
+	///
+	/// [`parse`]: crate::parse
+	#[inline]
+	pub fn from_bin_str(src: &str) -> Result<Self, ParseBitsError> {
+		Self::parse_radix(src, Some(Radix::Bin))
+	}
+
+	/// Parses a bit-vector from octal text.
+	///
+	/// Every word in the text is read as octal digits, and may carry a `0o`
+	/// prefix. Each digit produces three bits. See the [`parse`] module for the
+	/// accepted grammar.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = BitVec::<u8, Lsb0>::from_oct_str("0o52").unwrap();
+	/// assert_eq!(bv, bits![1, 0, 1, 0, 1, 0]);
+	/// ```
+	///
+	/// [`parse`]: crate::parse
+	#[inline]
+	pub fn from_oct_str(src: &str) -> Result<Self, ParseBitsError> {
+		Self::parse_radix(src, Some(Radix::Oct))
+	}
+
+	/// Parses a bit-vector from hexadecimal text.
+	///
+	/// Every word in the text is read as hexadecimal digits, in either case,
+	/// and may carry a `0x` prefix. Each digit produces four bits. See the
+	/// [`parse`] module for the accepted grammar.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = bitvec![u8, Msb0; 1, 0, 1, 1, 0, 1, 0, 0];
+	/// let text = format!("{:x}", bv);
+	/// assert_eq!(text, "[b4]");
+	/// assert_eq!(BitVec::<u8, Msb0>::from_hex_str(&text).unwrap(), bv);
+	/// ```
+	///
+	/// [`parse`]: crate::parse
+	#[inline]
+	pub fn from_hex_str(src: &str) -> Result<Self, ParseBitsError> {
+		Self::parse_radix(src, Some(Radix::Hex))
+	}
+
+	/// Parses a bit-vector from text, in a fixed radix or by prefix detection.
+	#[inline]
+	fn parse_radix(
+		src: &str,
+		radix: Option<Radix>,
+	) -> Result<Self, ParseBitsError> {
+		let mut out = Self::new();
+		parse::parse(src, radix, |bit| {
+			out.push(bit);
+			true
+		})?;
+		Ok(out)
+	}
+}
+
+/// Appending.
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator> in_alloc!(BitVec<T, O>, A)
+where
+	T: BitStore,
+	O: BitOrder,
+{
 	/// Appends the contents of a bit-slice to a bit-vector.
 	///
 	/// This can extend from a bit-slice of any type parameters; it is not
@@ -305,7 +463,7 @@ where
 }
 
 /// Converters.
-impl<T, O> BitVec<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator> in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -370,7 +528,14 @@ where
 	pub fn as_mut_bitptr(&mut self) -> BitPtr<Mut, T, O> {
 		self.bitspan.to_bitptr()
 	}
+}
 
+/// Conversions into standard-library containers.
+impl<T, O> BitVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
 	/// Converts a bit-vector into a boxed bit-slice.
 	///
 	/// This may cause a reällocation to drop any excess capacity.
@@ -433,7 +598,7 @@ where
 }
 
 /// Utilities.
-impl<T, O> BitVec<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator> in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -566,6 +731,213 @@ where
 		self.bitspan = bitspan;
 	}
 
+	/// Shifts the contents of a bit-vector “left” (towards the zero-index),
+	/// shrinking it rather than zero-filling the vacated bits.
+	///
+	/// This is the resizing counterpart to [`.shift_left()`] and the `<<=`
+	/// operator, which both keep the length unchanged and fill the end of the
+	/// bit-vector with `0`. Instead, this removes the first `by` bits and
+	/// reduces the length by `by`. When `by` is at least `self.len()`, the
+	/// bit-vector is emptied.
+	///
+	/// The allocation is unaffected; use [`.shrink_to_fit()`] to release it.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![1, 1, 0, 1, 0];
+	/// bv.shift_left_shrink(2);
+	/// assert_eq!(bv, bits![0, 1, 0]);
+	///
+	/// bv.shift_left_shrink(5);
+	/// assert!(bv.is_empty());
+	/// ```
+	///
+	/// [`.shift_left()`]: crate::slice::BitSlice::shift_left
+	/// [`.shrink_to_fit()`]: Self::shrink_to_fit
+	#[inline]
+	pub fn shift_left_shrink(&mut self, by: usize) {
+		let len = self.len();
+		if by >= len {
+			return self.clear();
+		}
+		self.shift_left(by);
+		self.truncate(len - by);
+	}
+
+	/// Shifts the contents of a bit-vector “right” (away from the zero-index),
+	/// growing it rather than discarding the bits that move past the end.
+	///
+	/// This is the resizing counterpart to [`.shift_right()`] and the `>>=`
+	/// operator, which both keep the length unchanged and discard the bits that
+	/// are moved out of the bit-vector. Instead, this inserts `by` bits, all
+	/// `0`, at the front of the bit-vector and increases the length by `by`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.len() + by` exceeds the maximum length of a
+	/// bit-vector. See [`BitSlice::MAX_BITS`].
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![1, 1, 0, 1];
+	/// bv.shift_right_grow(2);
+	/// assert_eq!(bv, bits![0, 0, 1, 1, 0, 1]);
+	/// ```
+	///
+	/// [`BitSlice::MAX_BITS`]: crate::slice::BitSlice::MAX_BITS
+	/// [`.shift_right()`]: crate::slice::BitSlice::shift_right
+	#[inline]
+	pub fn shift_right_grow(&mut self, by: usize) {
+		let len = self.len();
+		self.resize(len + by, false);
+		self.shift_right(by);
+	}
+
+	/// Replaces every non-overlapping occurrence of a bit-sequence with
+	/// another.
+	///
+	/// Matches are found as by [`.match_indices()`]. The replacement does not
+	/// need to have the same length as the pattern: when the lengths are equal,
+	/// each match is overwritten in place; otherwise, the bit-vector is rebuilt
+	/// with its new length.
+	///
+	/// ## Original
+	///
+	/// [`str::replace`](https://doc.rust-lang.org/std/primitive.str.html#method.replace)
+	///
+	/// ## API Differences
+	///
+	/// This modifies the bit-vector in place, rather than returning a new one.
+	///
+	/// ## Parameters
+	///
+	/// - `&mut self`
+	/// - `pattern`: The bit-sequence to find.
+	/// - `with`: The bit-sequence written in place of each match.
+	///
+	/// ## Returns
+	///
+	/// The number of matches that were replaced.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![0, 1, 1, 0, 1, 1, 1];
+	/// assert_eq!(bv.replace_all(bits![1, 1], bits![0]), 2);
+	/// assert_eq!(bv, bits![0, 0, 0, 0, 1]);
+	///
+	/// assert_eq!(bv.replace_all(bits![0, 0], bits![1, 0, 1]), 2);
+	/// assert_eq!(bv, bits![1, 0, 1, 1, 0, 1, 1]);
+	/// ```
+	///
+	/// [`.match_indices()`]: crate::slice::BitSlice::match_indices
+	#[inline]
+	pub fn replace_all<T2, O2, T3, O3>(
+		&mut self,
+		pattern: &BitSlice<T2, O2>,
+		with: &BitSlice<T3, O3>,
+	) -> usize
+	where
+		T2: BitStore,
+		O2: BitOrder,
+		T3: BitStore,
+		O3: BitOrder,
+	{
+		let width = pattern.len();
+		if width == with.len() {
+			let found = self.match_indices(pattern).collect::<Vec<_>>();
+			for &idx in &found {
+				self[idx .. idx + width].clone_from_bitslice(with);
+			}
+			return found.len();
+		}
+
+		let mut out = BitVec::<T, O>::new();
+		let mut count = 0;
+		let mut from = 0;
+		for idx in self.match_indices(pattern) {
+			out.extend_from_bitslice(&self[from .. idx]);
+			out.extend_from_bitslice(with);
+			from = idx + width;
+			count += 1;
+		}
+		if count != 0 {
+			out.extend_from_bitslice(&self[from ..]);
+			self.clear();
+			self.extend_from_bitslice(&out);
+		}
+		count
+	}
+
+	/// Converts the bit-vector to a different bit-ordering, preserving its
+	/// sequence of bits.
+	///
+	/// The existing allocation is always reused. Conversions between the
+	/// `Lsb0` and `Msb0` orderings reverse the bits of each element, so that
+	/// every index refers to the same bit as before. All other conversions
+	/// copy the bits out, as [`.to_bitvec_with()`] does, and then write them
+	/// back under the new ordering.
+	///
+	/// ## Type Parameters
+	///
+	/// - `O2`: The bit-ordering of the produced bit-vector.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = bitvec![u8, Msb0; 1, 1, 0, 1];
+	/// let bv = bv.convert_order::<Lsb0>();
+	/// assert_eq!(bv, bits![1, 1, 0, 1]);
+	/// assert_eq!(bv.as_raw_slice()[0] & 0x0F, 0b1011);
+	/// ```
+	///
+	/// [`.to_bitvec_with()`]: crate::slice::BitSlice::to_bitvec_with
+	#[inline]
+	pub fn convert_order<O2>(mut self) -> in_alloc!(BitVec<T, O2>, A)
+	where O2: BitOrder {
+		if dvl::match_order::<O, O2>() {
+			return unsafe { self.cast_order() };
+		}
+		if dvl::is_specialized_order::<O>() && dvl::is_specialized_order::<O2>()
+		{
+			dvl::reverse_element_bits(self.as_raw_mut_slice());
+			return unsafe { self.cast_order() };
+		}
+		let bits = self.as_bitslice().to_bitvec_with::<T::Unalias, O2>();
+		let mut out = unsafe { self.cast_order::<O2>() };
+		out.clone_from_bitslice(&bits);
+		out
+	}
+
+	/// Changes the bit-ordering type parameter without modifying memory.
+	///
+	/// ## Safety
+	///
+	/// The bits of the buffer are reinterpreted under the new ordering. The
+	/// caller must ensure that this produces the desired sequence of bits.
+	unsafe fn cast_order<O2>(self) -> in_alloc!(BitVec<T, O2>, A)
+	where O2: BitOrder {
+		let this = ManuallyDrop::new(self);
+		let (addr, head) = this.bitspan.to_bitptr().raw_parts();
+		BitVec {
+			bitspan: BitPtr::new_unchecked(addr, head)
+				.span_unchecked(this.bitspan.len()),
+			capacity: this.capacity,
+			#[cfg(feature = "allocator")]
+			alloc: ptr::read(&this.alloc),
+		}
+	}
+
 	/// Sets the starting-bit index of the span descriptor.
 	///
 	/// ## Safety
@@ -601,66 +973,85 @@ where
 		);
 	}
 
-	/// Reserves some memory through the underlying vector.
+	/// Reserves memory for at least `additional` more bits.
 	///
 	/// ## Parameters
 	///
 	/// - `&mut self`
 	/// - `additional`: The amount of additional space required after
 	///   `self.len()` in the allocation.
-	/// - `func`: A function that manipulates the memory reservation of the
-	///   underlying vector.
+	/// - `exact`: Whether to allocate only as much memory as is required,
+	///   rather than growing the allocation geometrically.
 	///
 	/// ## Behavior
 	///
-	/// `func` should perform the appropriate action to allocate space for at
-	/// least `additional` more bits. After it returns, the underlying vector is
-	/// extended with zero-initialized elements until `self.len() + additional`
-	/// bits have been given initialized memory.
+	/// After the allocation is grown, the buffer is extended with
+	/// zero-initialized elements until `self.len() + additional` bits have been
+	/// given initialized memory.
+	///
+	/// ## Panics
+	///
+	/// This panics if the new length exceeds the bit-vector capacity, and
+	/// calls [`handle_alloc_error`] if the allocator refuses the request.
+	///
+	/// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
 	#[inline]
-	fn do_reservation(
-		&mut self,
-		additional: usize,
-		func: impl FnOnce(&mut Vec<T>, usize),
-	) {
-		let len = self.len();
-		let new_len = len.saturating_add(additional);
-		Self::assert_len_encodable(new_len);
-
-		let (head, elts) = (self.bitspan.head(), self.bitspan.elements());
-		let new_elts =
-			crate::mem::elts::<T>(head.into_inner() as usize + new_len);
-
-		let extra_elts = new_elts - elts;
-		self.with_vec(|vec| {
-			func(&mut **vec, extra_elts);
-			//  Ensure that any new elements are initialized.
-			vec.resize_with(new_elts, || <T as BitStore>::ZERO);
-		});
+	fn do_reservation(&mut self, additional: usize, exact: bool) {
+		Self::assert_len_encodable(self.len().saturating_add(additional));
+		if let Err(err) = self.try_do_reservation(additional, exact) {
+			err.handle();
+		}
 	}
+}
 
-	/// Briefly constructs an ordinary `Vec` controlling the buffer, allowing
-	/// operations to be applied to the memory allocation.
+/// Bit-ordering conversion.
+impl<T, #[cfg(feature = "allocator")] A: Allocator> in_alloc!(BitVec<T, Lsb0>, A)
+where T: BitStore
+{
+	/// Converts the bit-vector to the `Msb0` ordering in place, preserving its
+	/// sequence of bits.
 	///
-	/// ## Parameters
+	/// Each element of the buffer has its bits reversed, so that every index
+	/// refers to the same bit as before. This does not reällocate.
 	///
-	/// - `&mut self`
-	/// - `func`: A function which may interact with the memory allocation.
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
 	///
-	/// After `func` runs, `self` is updated with the temporary `Vec`’s address
-	/// and capacity.
+	/// let bv = bitvec![u8, Lsb0; 1, 0, 0, 1, 1];
+	/// let bv: BitVec<u8, Msb0> = bv.reinterpret_order();
+	/// assert_eq!(bv, bits![1, 0, 0, 1, 1]);
+	/// assert_eq!(bv.as_raw_slice()[0] & 0xF8, 0b1001_1000);
+	/// ```
 	#[inline]
-	fn with_vec<F, R>(&mut self, func: F) -> R
-	where F: FnOnce(&mut ManuallyDrop<Vec<T>>) -> R {
-		let mut vec = unsafe { ptr::read(self) }
-			.into_vec()
-			.pipe(ManuallyDrop::new);
-		let out = func(&mut vec);
+	pub fn reinterpret_order(self) -> in_alloc!(BitVec<T, Msb0>, A) {
+		self.convert_order()
+	}
+}
 
-		unsafe {
-			self.bitspan.set_address(vec.as_mut_ptr().into_address());
-		}
-		self.capacity = vec.capacity();
-		out
+/// Bit-ordering conversion.
+impl<T, #[cfg(feature = "allocator")] A: Allocator> in_alloc!(BitVec<T, Msb0>, A)
+where T: BitStore
+{
+	/// Converts the bit-vector to the `Lsb0` ordering in place, preserving its
+	/// sequence of bits.
+	///
+	/// Each element of the buffer has its bits reversed, so that every index
+	/// refers to the same bit as before. This does not reällocate.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = bitvec![u8, Msb0; 1, 0, 0, 1, 1];
+	/// let bv: BitVec<u8, Lsb0> = bv.reinterpret_order();
+	/// assert_eq!(bv, bits![1, 0, 0, 1, 1]);
+	/// assert_eq!(bv.as_raw_slice()[0] & 0x1F, 0b1_1001);
+	/// ```
+	#[inline]
+	pub fn reinterpret_order(self) -> in_alloc!(BitVec<T, Lsb0>, A) {
+		self.convert_order()
 	}
 }
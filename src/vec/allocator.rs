@@ -0,0 +1,695 @@
+#![doc = include_str!("../../doc/vec/allocator.md")]
+
+use alloc::alloc::{
+	Layout,
+	alloc,
+	dealloc,
+	handle_alloc_error,
+	realloc,
+};
+#[cfg(feature = "allocator")]
+use alloc::vec::Vec;
+#[cfg(feature = "allocator")]
+use core::mem::ManuallyDrop;
+use core::{
+	cmp,
+	fmt::{
+		self,
+		Display,
+		Formatter,
+	},
+	ptr::{
+		self,
+		NonNull,
+	},
+};
+
+#[cfg(feature = "allocator")]
+use wyz::comu::Mut;
+
+#[cfg(feature = "allocator")]
+use super::BitVec;
+use super::TryReserveError;
+#[cfg(feature = "allocator")]
+use crate::{
+	mem,
+	ptr::{
+		BitPtr,
+		BitSpan,
+	},
+};
+use crate::{
+	order::BitOrder,
+	ptr::AddressExt,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+/// A source of memory for bit-vectors.
+///
+/// This is a stable stand-in for the unstable standard-library trait of the
+/// same name, and carries only the methods that `bitvec` uses.
+///
+/// ## Original
+///
+/// [`Allocator`](https://doc.rust-lang.org/alloc/alloc/trait.Allocator.html)
+///
+/// ## Safety
+///
+/// Blocks returned by an allocator must remain valid, and must not be handed
+/// out again, until they are passed to [`.deallocate()`]. Moving or cloning
+/// the allocator value must not invalidate the blocks it has already produced.
+///
+/// [`.deallocate()`]: Self::deallocate
+pub unsafe trait Allocator {
+	/// Attempts to allocate a block of memory.
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `layout`: The size and alignment of the requested block. `bitvec`
+	///   never requests a zero-sized block.
+	///
+	/// ## Returns
+	///
+	/// A pointer to the start of a block that fits `layout`, or an error if
+	/// the allocator cannot provide one. The contents of the block are
+	/// uninitialized.
+	fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError>;
+
+	/// Releases a block of memory.
+	///
+	/// ## Safety
+	///
+	/// `ptr` must have been produced by this allocator, and `layout` must be
+	/// the layout with which it was allocated.
+	unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout);
+
+	/// Attempts to extend a block of memory.
+	///
+	/// The default implementation allocates a new block, copies the contents
+	/// of the old block into it, and then releases the old block.
+	///
+	/// ## Safety
+	///
+	/// `ptr` must have been produced by this allocator, `old_layout` must be
+	/// the layout with which it was allocated, and `new_layout` must be at
+	/// least as large and have the same alignment. On success, the old block
+	/// must no longer be used; on failure, it is unchanged.
+	#[inline]
+	unsafe fn grow(
+		&self,
+		ptr: NonNull<u8>,
+		old_layout: Layout,
+		new_layout: Layout,
+	) -> Result<NonNull<u8>, AllocError> {
+		let new_ptr = self.allocate(new_layout)?;
+		ptr::copy_nonoverlapping(
+			ptr.as_ptr(),
+			new_ptr.as_ptr(),
+			old_layout.size(),
+		);
+		self.deallocate(ptr, old_layout);
+		Ok(new_ptr)
+	}
+
+	/// Attempts to reduce a block of memory.
+	///
+	/// The default implementation allocates a new block, copies the front of
+	/// the old block into it, and then releases the old block.
+	///
+	/// ## Safety
+	///
+	/// `ptr` must have been produced by this allocator, `old_layout` must be
+	/// the layout with which it was allocated, and `new_layout` must be no
+	/// larger, not zero-sized, and have the same alignment. On success, the old
+	/// block must no longer be used; on failure, it is unchanged.
+	#[inline]
+	unsafe fn shrink(
+		&self,
+		ptr: NonNull<u8>,
+		old_layout: Layout,
+		new_layout: Layout,
+	) -> Result<NonNull<u8>, AllocError> {
+		let new_ptr = self.allocate(new_layout)?;
+		ptr::copy_nonoverlapping(
+			ptr.as_ptr(),
+			new_ptr.as_ptr(),
+			new_layout.size(),
+		);
+		self.deallocate(ptr, old_layout);
+		Ok(new_ptr)
+	}
+}
+
+unsafe impl<A> Allocator for &A
+where A: Allocator + ?Sized
+{
+	#[inline]
+	fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+		(**self).allocate(layout)
+	}
+
+	#[inline]
+	unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+		(**self).deallocate(ptr, layout)
+	}
+
+	#[inline]
+	unsafe fn grow(
+		&self,
+		ptr: NonNull<u8>,
+		old_layout: Layout,
+		new_layout: Layout,
+	) -> Result<NonNull<u8>, AllocError> {
+		(**self).grow(ptr, old_layout, new_layout)
+	}
+
+	#[inline]
+	unsafe fn shrink(
+		&self,
+		ptr: NonNull<u8>,
+		old_layout: Layout,
+		new_layout: Layout,
+	) -> Result<NonNull<u8>, AllocError> {
+		(**self).shrink(ptr, old_layout, new_layout)
+	}
+}
+
+/// The global memory allocator.
+///
+/// This is the default allocator of [`BitVec`], and forwards to the allocator
+/// registered with `#[global_allocator]`. Bit-vectors that use it are able to
+/// convert to and from the standard-library `Vec` and `Box` types.
+///
+/// ## Original
+///
+/// [`Global`](https://doc.rust-lang.org/alloc/alloc/struct.Global.html)
+///
+/// [`BitVec`]: crate::vec::BitVec
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Global;
+
+unsafe impl Allocator for Global {
+	#[inline]
+	fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+		NonNull::new(unsafe { alloc(layout) }).ok_or(AllocError)
+	}
+
+	#[inline]
+	unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+		dealloc(ptr.as_ptr(), layout)
+	}
+
+	#[inline]
+	unsafe fn grow(
+		&self,
+		ptr: NonNull<u8>,
+		old_layout: Layout,
+		new_layout: Layout,
+	) -> Result<NonNull<u8>, AllocError> {
+		NonNull::new(realloc(ptr.as_ptr(), old_layout, new_layout.size()))
+			.ok_or(AllocError)
+	}
+
+	#[inline]
+	unsafe fn shrink(
+		&self,
+		ptr: NonNull<u8>,
+		old_layout: Layout,
+		new_layout: Layout,
+	) -> Result<NonNull<u8>, AllocError> {
+		NonNull::new(realloc(ptr.as_ptr(), old_layout, new_layout.size()))
+			.ok_or(AllocError)
+	}
+}
+
+/// Marks that an [`Allocator`] could not provide the requested memory.
+///
+/// ## Original
+///
+/// [`AllocError`](https://doc.rust-lang.org/alloc/alloc/struct.AllocError.html)
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct AllocError;
+
+#[cfg(not(tarpaulin_include))]
+impl Display for AllocError {
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("memory allocation failed")
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AllocError {}
+
+/// Allocation management.
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator> in_alloc!(BitVec<T, O>, A)
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Gets the allocator that owns the bit-vector’s buffer.
+	#[cfg(feature = "allocator")]
+	#[inline]
+	fn buffer_allocator(&self) -> &A {
+		&self.alloc
+	}
+
+	/// Gets the allocator that owns the bit-vector’s buffer.
+	#[cfg(not(feature = "allocator"))]
+	#[inline]
+	fn buffer_allocator(&self) -> &Global {
+		&Global
+	}
+
+	/// Gets the memory layout of the bit-vector’s buffer.
+	#[inline]
+	fn buffer_layout(&self) -> Layout {
+		Layout::array::<T>(self.capacity).expect("bit-vector capacity exceeded")
+	}
+
+	/// Gets the bit-vector’s buffer as an untyped allocator block.
+	#[inline]
+	fn buffer_block(&self) -> NonNull<u8> {
+		unsafe { NonNull::new_unchecked(self.bitspan.address().to_mut()) }
+			.cast::<u8>()
+	}
+
+	/// Grows the buffer to hold at least `min_elts` memory elements, or reports
+	/// why it could not.
+	///
+	/// ## Parameters
+	///
+	/// - `&mut self`
+	/// - `min_elts`: The number of elements that the buffer must be able to
+	///   hold. This must be greater than the current capacity.
+	/// - `exact`: Whether to request exactly `min_elts` elements, rather than
+	///   growing geometrically.
+	///
+	/// ## Behavior
+	///
+	/// Empty buffers are allocated fresh, and live buffers are passed to
+	/// [`Allocator::grow`]. The new elements are uninitialized. The bit-vector
+	/// is unchanged when this returns an error.
+	pub(super) fn try_grow(
+		&mut self,
+		min_elts: usize,
+		exact: bool,
+	) -> Result<(), TryReserveError> {
+		let new_cap = if exact {
+			min_elts
+		}
+		else {
+			cmp::max(min_elts, self.capacity.saturating_mul(2))
+		};
+		let layout = Layout::array::<T>(new_cap)
+			.ok()
+			.filter(|layout| layout.size() <= isize::MAX as usize)
+			.ok_or_else(TryReserveError::capacity_overflow)?;
+
+		let alloc = self.buffer_allocator();
+		let block = if self.capacity == 0 {
+			alloc.allocate(layout)
+		}
+		else {
+			unsafe {
+				alloc.grow(self.buffer_block(), self.buffer_layout(), layout)
+			}
+		}
+		.map_err(|_| TryReserveError::alloc_error(layout))?;
+
+		unsafe {
+			self.bitspan
+				.set_address(block.cast::<T>().as_ptr().into_address());
+		}
+		self.capacity = new_cap;
+		Ok(())
+	}
+
+	/// Shrinks the buffer to fit the live elements, releasing it entirely when
+	/// the bit-vector is empty.
+	///
+	/// ## Panics
+	///
+	/// If the allocator fails, this calls [`handle_alloc_error`], which aborts
+	/// by default.
+	///
+	/// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+	pub(super) fn shrink_buffer(&mut self) {
+		let elts = self.bitspan.elements();
+		if elts >= self.capacity {
+			return;
+		}
+		if elts == 0 {
+			self.deallocate();
+			unsafe {
+				self.bitspan.set_address(
+					NonNull::<T>::dangling().as_ptr().into_address(),
+				);
+			}
+			self.capacity = 0;
+			return;
+		}
+
+		let layout =
+			Layout::array::<T>(elts).expect("bit-vector capacity exceeded");
+		let block = match unsafe {
+			self.buffer_allocator().shrink(
+				self.buffer_block(),
+				self.buffer_layout(),
+				layout,
+			)
+		} {
+			Ok(block) => block,
+			Err(_) => handle_alloc_error(layout),
+		};
+		unsafe {
+			self.bitspan
+				.set_address(block.cast::<T>().as_ptr().into_address());
+		}
+		self.capacity = elts;
+	}
+
+	/// Releases the bit-vector’s buffer back to its allocator.
+	///
+	/// This is the destructor of all bit-vectors. It does not change the
+	/// bit-vector’s fields.
+	pub(super) fn deallocate(&mut self) {
+		if self.capacity == 0 {
+			return;
+		}
+		unsafe {
+			self.buffer_allocator()
+				.deallocate(self.buffer_block(), self.buffer_layout());
+		}
+	}
+}
+
+/// Allocation management.
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator + Clone>
+	in_alloc!(BitVec<T, O>, A)
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Copies a bit-slice into a new bit-vector that allocates from the same
+	/// allocator as `self`.
+	#[cfg(feature = "allocator")]
+	#[inline]
+	pub(super) fn sibling_from_bitslice(&self, slice: &BitSlice<T, O>) -> Self {
+		Self::from_bitslice_in(slice, self.alloc.clone())
+	}
+
+	/// Copies a bit-slice into a new bit-vector that allocates from the same
+	/// allocator as `self`.
+	#[cfg(not(feature = "allocator"))]
+	#[inline]
+	pub(super) fn sibling_from_bitslice(&self, slice: &BitSlice<T, O>) -> Self {
+		Self::from_bitslice(slice)
+	}
+}
+
+/// Allocation management.
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator + Default>
+	in_alloc!(BitVec<T, O>, A)
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Constructs a new, empty, bit-vector in the default allocator.
+	#[cfg(feature = "allocator")]
+	#[inline]
+	pub(super) fn new_default() -> Self {
+		Self::new_in(A::default())
+	}
+
+	/// Constructs a new, empty, bit-vector in the default allocator.
+	#[cfg(not(feature = "allocator"))]
+	#[inline]
+	pub(super) fn new_default() -> Self {
+		Self::new()
+	}
+}
+
+/// Allocator-aware construction.
+#[cfg(feature = "allocator")]
+impl<T, O, A> BitVec<T, O, A>
+where
+	T: BitStore,
+	O: BitOrder,
+	A: Allocator,
+{
+	/// Constructs a new, empty, bit-vector that will allocate from `alloc`.
+	///
+	/// This does not allocate.
+	///
+	/// ## Original
+	///
+	/// [`Vec::new_in`](https://doc.rust-lang.org/alloc/vec/struct.Vec.html#method.new_in)
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::vec::Global;
+	///
+	/// let bv = BitVec::<u8, Msb0, _>::new_in(Global);
+	/// assert!(bv.is_empty());
+	/// ```
+	#[inline]
+	pub fn new_in(alloc: A) -> Self {
+		Self {
+			bitspan: BitSpan::EMPTY,
+			capacity: 0,
+			alloc,
+		}
+	}
+
+	/// Allocates a new, empty, bit-vector from `alloc`, with space for at
+	/// least `capacity` bits.
+	///
+	/// ## Original
+	///
+	/// [`Vec::with_capacity_in`](https://doc.rust-lang.org/alloc/vec/struct.Vec.html#method.with_capacity_in)
+	///
+	/// ## Panics
+	///
+	/// This panics if the requested capacity is longer than what the bit-vector
+	/// can represent. See [`BitSlice::MAX_BITS`]. If the allocator fails, this
+	/// calls [`handle_alloc_error`], which aborts by default.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::vec::Global;
+	///
+	/// let bv = BitVec::<u16, Lsb0, _>::with_capacity_in(40, Global);
+	/// assert!(bv.is_empty());
+	/// assert_eq!(bv.into_raw_parts_with_alloc().2, 48);
+	/// ```
+	///
+	/// [`BitSlice::MAX_BITS`]: crate::slice::BitSlice::MAX_BITS
+	/// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+	#[inline]
+	pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+		BitVec::<T, O>::assert_len_encodable(capacity);
+		let mut out = Self::new_in(alloc);
+		let elts = mem::elts::<T>(capacity);
+		if elts > 0 {
+			if let Err(err) = out.try_grow(elts, true) {
+				err.handle();
+			}
+		}
+		out
+	}
+
+	/// Creates a new bit-vector in `alloc` by repeating a bit for the desired
+	/// length.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::vec::Global;
+	///
+	/// let bv = BitVec::<u8, Lsb0, _>::repeat_in(true, 12, Global);
+	/// assert_eq!(bv.count_ones(), 12);
+	/// ```
+	#[inline]
+	pub fn repeat_in(bit: bool, len: usize, alloc: A) -> Self {
+		let mut out = Self::with_capacity_in(len, alloc);
+		let fill = if bit { !<T::Mem>::ZERO } else { <T::Mem>::ZERO };
+		let addr = out.bitspan.address().to_mut();
+		for idx in 0 .. out.capacity {
+			unsafe {
+				addr.add(idx).write(T::new(fill));
+			}
+		}
+		unsafe {
+			out.bitspan.set_len(len);
+		}
+		out
+	}
+
+	/// Copies the contents of a bit-slice into a new allocation from `alloc`.
+	///
+	/// As with [`::from_bitslice()`], the produced bit-vector uses the same
+	/// memory layout as the originating bit-slice, and dead bits in the copied
+	/// elements are zeroed.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::vec::Global;
+	///
+	/// let bits = bits![0, 1, 0, 0, 1];
+	/// let bv = BitVec::from_bitslice_in(&bits[1 ..], Global);
+	/// assert_eq!(bv, bits[1 ..]);
+	/// ```
+	///
+	/// [`::from_bitslice()`]: crate::vec::BitVec::from_bitslice
+	#[inline]
+	pub fn from_bitslice_in(slice: &BitSlice<T, O>, alloc: A) -> Self {
+		let bitspan = slice.as_bitspan();
+		let mut out = Self::with_capacity_in(
+			bitspan.elements() * mem::bits_of::<T>(),
+			alloc,
+		);
+		let addr = out.bitspan.address().to_mut();
+		for (idx, elem) in slice.domain().enumerate() {
+			unsafe {
+				addr.add(idx).write(T::new(elem));
+			}
+		}
+		unsafe {
+			out.bitspan.set_head(bitspan.head());
+			out.bitspan.set_len(bitspan.len());
+		}
+		out
+	}
+
+	/// Copies a vector of memory elements into a new bit-vector allocated from
+	/// `alloc`.
+	///
+	/// The produced bit-vector spans every bit in the original vector. The
+	/// standard-library `Vec` can only hold memory from the global allocator,
+	/// so unlike [`::from_vec()`], this copies the elements into the new
+	/// allocation and releases the original vector.
+	///
+	/// ## Panics
+	///
+	/// This panics if the vector is too long to convert into a bit-vector.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::vec::Global;
+	///
+	/// let bv = BitVec::<_, Msb0, _>::from_vec_in(vec![0x3Cu8, 0xA5], Global);
+	/// assert_eq!(bv.len(), 16);
+	/// assert_eq!(bv[.. 8].load::<u8>(), 0x3C);
+	/// ```
+	///
+	/// [`::from_vec()`]: crate::vec::BitVec::from_vec
+	#[inline]
+	pub fn from_vec_in(vec: Vec<T>, alloc: A) -> Self {
+		let bits = BitSlice::<T, O>::from_slice(&vec);
+		Self::from_bitslice_in(bits, alloc)
+	}
+
+	/// Constructs a bit-vector handle from its constituent fields and its
+	/// allocator.
+	///
+	/// ## Original
+	///
+	/// [`Vec::from_raw_parts_in`](https://doc.rust-lang.org/alloc/vec/struct.Vec.html#method.from_raw_parts_in)
+	///
+	/// ## Safety
+	///
+	/// The **only** acceptable argument values for this function are those that
+	/// were previously produced by calling [`.into_raw_parts_with_alloc()`],
+	/// and you may only call this **at most once** on any set of arguments.
+	///
+	/// [`.into_raw_parts_with_alloc()`]: Self::into_raw_parts_with_alloc
+	#[inline]
+	pub unsafe fn from_raw_parts_in(
+		bitptr: BitPtr<Mut, T, O>,
+		length: usize,
+		capacity: usize,
+		alloc: A,
+	) -> Self {
+		let bitspan = bitptr.span_unchecked(length);
+		Self {
+			bitspan,
+			capacity: mem::elts::<T>(
+				capacity.saturating_add(bitspan.head().into_inner() as usize),
+			),
+			alloc,
+		}
+	}
+
+	/// Decomposes a bit-vector into its constituent member fields and its
+	/// allocator.
+	///
+	/// This disarms the destructor. In order to prevent a memory leak, you must
+	/// pass **these exact values** back into [`::from_raw_parts_in()`], or
+	/// release the memory through the allocator yourself.
+	///
+	/// ## Original
+	///
+	/// [`Vec::into_raw_parts_with_alloc`](https://doc.rust-lang.org/alloc/vec/struct.Vec.html#method.into_raw_parts_with_alloc)
+	///
+	/// ## Returns
+	///
+	/// - A pointer to the first live bit.
+	/// - The length of the bit-vector, in bits.
+	/// - The capacity of the allocation, in bits, counted from the first live
+	///   bit.
+	/// - The allocator that owns the memory.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::vec::Global;
+	///
+	/// let bv = BitVec::<u8, Lsb0, _>::repeat_in(false, 10, Global);
+	/// let (bitptr, len, capa, alloc) = bv.into_raw_parts_with_alloc();
+	/// assert_eq!((len, capa), (10, 16));
+	/// let bv = unsafe { BitVec::from_raw_parts_in(bitptr, len, capa, alloc) };
+	/// assert_eq!(bv, bits![0; 10]);
+	/// ```
+	///
+	/// [`::from_raw_parts_in()`]: Self::from_raw_parts_in
+	#[inline]
+	pub fn into_raw_parts_with_alloc(
+		self,
+	) -> (BitPtr<Mut, T, O>, usize, usize, A) {
+		let this = ManuallyDrop::new(self);
+		let capacity = this
+			.capacity
+			.checked_mul(mem::bits_of::<T>())
+			.expect("bit-vector capacity exceeded")
+			.saturating_sub(this.bitspan.head().into_inner() as usize);
+		(
+			this.bitspan.to_bitptr(),
+			this.bitspan.len(),
+			capacity,
+			unsafe { ptr::read(&this.alloc) },
+		)
+	}
+
+	/// Gets a reference to the allocator that owns the bit-vector’s memory.
+	///
+	/// ## Original
+	///
+	/// [`Vec::allocator`](https://doc.rust-lang.org/alloc/vec/struct.Vec.html#method.allocator)
+	#[inline]
+	pub fn allocator(&self) -> &A {
+		&self.alloc
+	}
+}
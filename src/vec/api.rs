@@ -15,6 +15,11 @@ use wyz::{
 	range::RangeExt,
 };
 
+#[cfg(feature = "allocator")]
+use super::{
+	Allocator,
+	Global,
+};
 use super::{
 	BitVec,
 	Drain,
@@ -108,7 +113,12 @@ where
 			.pipe(ManuallyDrop::new);
 		let (addr, capacity) = (vec.as_mut_ptr(), vec.capacity());
 		let bitspan = BitSpan::uninhabited(unsafe { addr.into_address() });
-		Self { bitspan, capacity }
+		Self {
+			bitspan,
+			capacity,
+			#[cfg(feature = "allocator")]
+			alloc: Global,
+		}
 	}
 
 	/// Constructs a bit-vector handle from its constituent fields.
@@ -154,6 +164,8 @@ where
 			capacity: mem::elts::<T>(
 				capacity.saturating_add(bitspan.head().into_inner() as usize),
 			),
+			#[cfg(feature = "allocator")]
+			alloc: Global,
 		}
 	}
 
@@ -183,6 +195,57 @@ where
 		)
 	}
 
+	#[inline]
+	#[cfg(not(tarpaulin_include))]
+	#[deprecated = "prefer `.into_boxed_bitslice() instead"]
+	#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+	pub fn into_boxed_slice(self) -> BitBox<T, O> {
+		self.into_boxed_bitslice()
+	}
+
+	/// Destroys the `BitVec` handle without destroying the bit-vector
+	/// allocation. The allocation is returned as an `&mut BitSlice` that lasts
+	/// for the remaining program lifetime.
+	///
+	/// You *may* call [`BitBox::from_raw`] on this slice handle exactly once in
+	/// order to reap the allocation before program exit. That function takes a
+	/// mutable pointer, not a mutable reference, so you must ensure that the
+	/// returned reference is never used again after restoring the allocation
+	/// handle.
+	///
+	/// ## Original
+	///
+	/// [`Vec::leak`](alloc::vec::Vec::leak)
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = bitvec![0, 0, 1];
+	/// let static_bits: &'static mut BitSlice = bv.leak();
+	/// static_bits.set(0, true);
+	/// assert_eq!(static_bits, bits![1, 0, 1]);
+	///
+	/// let bb = unsafe { BitBox::from_raw(static_bits) };
+	/// // static_bits may no longer be used.
+	/// drop(bb); // explicitly reap memory before program exit
+	/// ```
+	///
+	/// [`BitBox::leak`]: crate::boxed::BitBox::leak
+	#[inline]
+	#[cfg(not(tarpaulin_include))]
+	pub fn leak<'a>(self) -> &'a mut BitSlice<T, O> {
+		self.into_boxed_bitslice().pipe(BitBox::leak)
+	}
+}
+
+/// Port of the `Vec<T>` inherent API.
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator> in_alloc!(BitVec<T, O>, A)
+where
+	T: BitStore,
+	O: BitOrder,
+{
 	/// Gets the allocation capacity, measured in bits.
 	///
 	/// This counts how many total bits the bit-vector can store before it must
@@ -244,8 +307,7 @@ where
 	/// ```
 	#[inline]
 	pub fn reserve(&mut self, additional: usize) {
-		Self::assert_len_encodable(self.len() + additional);
-		self.do_reservation(additional, Vec::<T>::reserve);
+		self.do_reservation(additional, false);
 	}
 
 	/// Ensures that the bit-vector has allocation capacity for *at least*
@@ -282,7 +344,7 @@ where
 	/// [`.reserve()`]: Self::reserve
 	#[inline]
 	pub fn reserve_exact(&mut self, additional: usize) {
-		self.do_reservation(additional, Vec::<T>::reserve_exact);
+		self.do_reservation(additional, true);
 	}
 
 	/// Releases excess capacity back to the allocator.
@@ -307,15 +369,7 @@ where
 	/// [`.reserve_exact()`]: Self::reserve_exact
 	#[inline]
 	pub fn shrink_to_fit(&mut self) {
-		self.with_vec(|vec| vec.shrink_to_fit());
-	}
-
-	#[inline]
-	#[cfg(not(tarpaulin_include))]
-	#[deprecated = "prefer `.into_boxed_bitslice() instead"]
-	#[allow(missing_docs, clippy::missing_docs_in_private_items)]
-	pub fn into_boxed_slice(self) -> BitBox<T, O> {
-		self.into_boxed_bitslice()
+		self.shrink_buffer();
 	}
 
 	/// Shortens the bit-vector, keeping the first `new_len` bits and discarding
@@ -617,10 +671,9 @@ where
 	pub fn push(&mut self, value: bool) {
 		let len = self.len();
 		let new_len = len + 1;
-		Self::assert_len_encodable(new_len);
 		//  Push a new `T` into the underlying buffer if needed.
 		if len == 0 || self.bitspan.tail() == BitEnd::MAX {
-			self.with_vec(|vec| vec.push(T::ZERO));
+			self.do_reservation(1, false);
 		}
 		//  Write `value` into the now-safely-allocated `len` slot.
 		unsafe {
@@ -693,8 +746,10 @@ where
 	/// assert!(bv2.is_empty());
 	/// ```
 	#[inline]
-	pub fn append<T2, O2>(&mut self, other: &mut BitVec<T2, O2>)
-	where
+	pub fn append<T2, O2, #[cfg(feature = "allocator")] A2: Allocator>(
+		&mut self,
+		other: &mut in_alloc!(BitVec<T2, O2>, A2),
+	) where
 		T2: BitStore,
 		O2: BitOrder,
 	{
@@ -733,7 +788,7 @@ where
 	/// assert!(bv.is_empty());
 	/// ```
 	#[inline]
-	pub fn drain<R>(&mut self, range: R) -> Drain<T, O>
+	pub fn drain<R>(&mut self, range: R) -> in_alloc!(Drain<'_, T, O>, A)
 	where R: RangeBounds<usize> {
 		Drain::new(self, range)
 	}
@@ -790,35 +845,6 @@ where
 		self.bitspan.len() == 0
 	}
 
-	/// Splits the bit-vector in half at an index, moving `self[at ..]` out into
-	/// a new bit-vector.
-	///
-	/// ## Original
-	///
-	/// [`Vec::split_off`](alloc::vec::Vec::split_off)
-	///
-	/// ## Examples
-	///
-	/// ```rust
-	/// use bitvec::prelude::*;
-	///
-	/// let mut bv = bitvec![0, 1, 0, 0, 1];
-	/// let bv2 = bv.split_off(2);
-	/// assert_eq!((&*bv, &*bv2), (bits![0, 1], bits![0, 0, 1]));
-	/// ```
-	#[inline]
-	pub fn split_off(&mut self, at: usize) -> Self {
-		let len = self.len();
-		self.assert_in_bounds(at, 0 ..= len);
-		let (this, that) = unsafe {
-			self.bitspan
-				.into_bitslice_mut()
-				.split_at_unchecked_mut_noalias(at)
-		};
-		self.bitspan = this.as_mut_bitspan();
-		Self::from_bitslice(that)
-	}
-
 	/// Resizes the bit-vector to a new length, using a function to produce each
 	/// inserted bit.
 	///
@@ -860,42 +886,6 @@ where
 		}
 	}
 
-	/// Destroys the `BitVec` handle without destroying the bit-vector
-	/// allocation. The allocation is returned as an `&mut BitSlice` that lasts
-	/// for the remaining program lifetime.
-	///
-	/// You *may* call [`BitBox::from_raw`] on this slice handle exactly once in
-	/// order to reap the allocation before program exit. That function takes a
-	/// mutable pointer, not a mutable reference, so you must ensure that the
-	/// returned reference is never used again after restoring the allocation
-	/// handle.
-	///
-	/// ## Original
-	///
-	/// [`Vec::leak`](alloc::vec::Vec::leak)
-	///
-	/// ## Examples
-	///
-	/// ```rust
-	/// use bitvec::prelude::*;
-	///
-	/// let bv = bitvec![0, 0, 1];
-	/// let static_bits: &'static mut BitSlice = bv.leak();
-	/// static_bits.set(0, true);
-	/// assert_eq!(static_bits, bits![1, 0, 1]);
-	///
-	/// let bb = unsafe { BitBox::from_raw(static_bits) };
-	/// // static_bits may no longer be used.
-	/// drop(bb); // explicitly reap memory before program exit
-	/// ```
-	///
-	/// [`BitBox::leak`]: crate::boxed::BitBox::leak
-	#[inline]
-	#[cfg(not(tarpaulin_include))]
-	pub fn leak<'a>(self) -> &'a mut BitSlice<T, O> {
-		self.into_boxed_bitslice().pipe(BitBox::leak)
-	}
-
 	/// Resizes the bit-vector to a new length. New bits are initialized to
 	/// `value`.
 	///
@@ -1021,7 +1011,7 @@ where
 		&mut self,
 		range: R,
 		replace_with: I,
-	) -> Splice<T, O, I::IntoIter>
+	) -> in_alloc!(Splice<'_, T, O, I::IntoIter>, A)
 	where
 		R: RangeBounds<usize>,
 		I: IntoIterator<Item = bool>,
@@ -1029,3 +1019,40 @@ where
 		Splice::new(self.drain(range), replace_with)
 	}
 }
+
+/// Port of the `Vec<T>` inherent API.
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator + Clone>
+	in_alloc!(BitVec<T, O>, A)
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Splits the bit-vector in half at an index, moving `self[at ..]` out into
+	/// a new bit-vector.
+	///
+	/// ## Original
+	///
+	/// [`Vec::split_off`](alloc::vec::Vec::split_off)
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![0, 1, 0, 0, 1];
+	/// let bv2 = bv.split_off(2);
+	/// assert_eq!((&*bv, &*bv2), (bits![0, 1], bits![0, 0, 1]));
+	/// ```
+	#[inline]
+	pub fn split_off(&mut self, at: usize) -> Self {
+		let len = self.len();
+		self.assert_in_bounds(at, 0 ..= len);
+		let (this, that) = unsafe {
+			self.bitspan
+				.into_bitslice_mut()
+				.split_at_unchecked_mut_noalias(at)
+		};
+		self.bitspan = this.as_mut_bitspan();
+		self.sibling_from_bitslice(that)
+	}
+}
@@ -0,0 +1,326 @@
+#![doc = include_str!("../../doc/vec/fallible.md")]
+
+use alloc::alloc::{
+	Layout,
+	handle_alloc_error,
+};
+use core::fmt::{
+	self,
+	Display,
+	Formatter,
+};
+
+use wyz::comu::Const;
+
+use super::BitVec;
+#[cfg(feature = "allocator")]
+use super::allocator::Allocator;
+use crate::{
+	order::BitOrder,
+	ptr::BitSpan,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+/// Fallible allocation.
+impl<T, O> BitVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Constructs a new, empty, bit-vector with room for at least `capacity`
+	/// bits, or reports why the allocation could not be made.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = BitVec::<u8, Lsb0>::try_with_capacity(100).unwrap();
+	/// assert!(bv.is_empty());
+	/// assert!(bv.capacity() >= 100);
+	///
+	/// assert!(BitVec::<u8, Lsb0>::try_with_capacity(usize::MAX).is_err());
+	/// ```
+	#[inline]
+	pub fn try_with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+		let mut out = Self::EMPTY;
+		out.try_reserve_exact(capacity)?;
+		Ok(out)
+	}
+
+	/// Copies the contents of a bit-slice into a new heap allocation, or
+	/// reports why the allocation could not be made.
+	///
+	/// Unlike [`from_bitslice`], the new bit-vector always begins at the front
+	/// edge of its first element.
+	///
+	/// [`from_bitslice`]: Self::from_bitslice
+	#[inline]
+	pub fn try_from_bitslice(
+		slice: &BitSlice<T, O>,
+	) -> Result<Self, TryReserveError> {
+		let mut out = Self::try_with_capacity(slice.len())?;
+		out.extend_from_bitslice(slice);
+		Ok(out)
+	}
+
+	/// Creates a new bit-vector by repeating a bit for the desired length, or
+	/// reports why the allocation could not be made.
+	///
+	/// This is the fallible version of [`repeat`].
+	///
+	/// [`repeat`]: Self::repeat
+	#[inline]
+	pub fn try_repeat(bit: bool, len: usize) -> Result<Self, TryReserveError> {
+		let mut out = Self::try_with_capacity(len)?;
+		out.resize(len, bit);
+		Ok(out)
+	}
+}
+
+/// Fallible allocation.
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator> in_alloc!(BitVec<T, O>, A)
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Ensures that the bit-vector has allocation capacity for *at least*
+	/// `additional` more bits to be appended to it, or reports why it could
+	/// not.
+	///
+	/// This has the same behavior as [`reserve`], including the
+	/// zero-initialization of new memory, except that failures are returned
+	/// rather than causing a panic or an abort. The bit-vector is unchanged
+	/// when this returns an error.
+	///
+	/// ## Original
+	///
+	/// [`Vec::try_reserve`](https://doc.rust-lang.org/alloc/vec/struct.Vec.html#method.try_reserve)
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::vec::TryReserveErrorKind;
+	///
+	/// let mut bv = bitvec![0, 1];
+	/// bv.try_reserve(100).unwrap();
+	/// assert!(bv.capacity() >= 102);
+	///
+	/// let err = bv.try_reserve(usize::MAX).unwrap_err();
+	/// assert_eq!(err.kind(), TryReserveErrorKind::CapacityOverflow);
+	/// ```
+	///
+	/// [`reserve`]: Self::reserve
+	#[inline]
+	pub fn try_reserve(
+		&mut self,
+		additional: usize,
+	) -> Result<(), TryReserveError> {
+		self.try_do_reservation(additional, false)
+	}
+
+	/// Ensures that the bit-vector has allocation capacity for *at least*
+	/// `additional` more bits to be appended to it, requesting no more memory
+	/// than that, or reports why it could not.
+	///
+	/// This is the fallible version of [`reserve_exact`].
+	///
+	/// ## Original
+	///
+	/// [`Vec::try_reserve_exact`](https://doc.rust-lang.org/alloc/vec/struct.Vec.html#method.try_reserve_exact)
+	///
+	/// [`reserve_exact`]: Self::reserve_exact
+	#[inline]
+	pub fn try_reserve_exact(
+		&mut self,
+		additional: usize,
+	) -> Result<(), TryReserveError> {
+		self.try_do_reservation(additional, true)
+	}
+
+	/// Appends a single bit to the vector, or reports why the allocation it
+	/// needed could not be made.
+	///
+	/// This is the fallible version of [`push`].
+	///
+	/// [`push`]: Self::push
+	#[inline]
+	pub fn try_push(&mut self, value: bool) -> Result<(), TryReserveError> {
+		self.try_reserve(1)?;
+		self.push(value);
+		Ok(())
+	}
+
+	/// Copies all bits in a bit-slice into the end of the bit-vector, or
+	/// reports why the allocation it needed could not be made.
+	///
+	/// This is the fallible version of [`extend_from_bitslice`]. The
+	/// bit-vector is unchanged when this returns an error.
+	///
+	/// [`extend_from_bitslice`]: Self::extend_from_bitslice
+	#[inline]
+	pub fn try_extend_from_bitslice<T2, O2>(
+		&mut self,
+		other: &BitSlice<T2, O2>,
+	) -> Result<(), TryReserveError>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.try_reserve(other.len())?;
+		self.extend_from_bitslice(other);
+		Ok(())
+	}
+
+	/// Resizes the bit-vector to a new length, filling any new bits with
+	/// `value`, or reports why the allocation it needed could not be made.
+	///
+	/// This is the fallible version of [`resize`]. The bit-vector is
+	/// unchanged when this returns an error.
+	///
+	/// [`resize`]: Self::resize
+	#[inline]
+	pub fn try_resize(
+		&mut self,
+		new_len: usize,
+		value: bool,
+	) -> Result<(), TryReserveError> {
+		if let Some(additional) = new_len.checked_sub(self.len()) {
+			self.try_reserve(additional)?;
+		}
+		self.resize(new_len, value);
+		Ok(())
+	}
+
+	/// Fallibly reserves memory for at least `additional` more bits.
+	///
+	/// ## Parameters
+	///
+	/// - `&mut self`
+	/// - `additional`: The amount of additional space required after
+	///   `self.len()` in the allocation.
+	/// - `exact`: Whether to request exactly the needed memory, rather than
+	///   growing geometrically.
+	///
+	/// ## Behavior
+	///
+	/// This has the same effects as the infallible `do_reservation`, but checks
+	/// the new length and the allocation instead of panicking or aborting.
+	#[inline]
+	pub(super) fn try_do_reservation(
+		&mut self,
+		additional: usize,
+		exact: bool,
+	) -> Result<(), TryReserveError> {
+		let new_len = self
+			.len()
+			.checked_add(additional)
+			.filter(|&len| BitSpan::<Const, T, O>::len_encodable(len))
+			.ok_or_else(TryReserveError::capacity_overflow)?;
+
+		let (head, elts) = (self.bitspan.head(), self.bitspan.elements());
+		let new_elts =
+			crate::mem::elts::<T>(head.into_inner() as usize + new_len);
+		if new_elts <= elts {
+			return Ok(());
+		}
+
+		if new_elts > self.capacity {
+			self.try_grow(new_elts, exact)?;
+		}
+		//  Ensure that any new elements are initialized.
+		let base = self.bitspan.address().to_mut();
+		for idx in elts .. new_elts {
+			unsafe {
+				base.add(idx).write(<T as BitStore>::ZERO);
+			}
+		}
+		Ok(())
+	}
+}
+
+#[doc = include_str!("../../doc/vec/TryReserveError.md")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TryReserveError {
+	/// The reason the reservation failed.
+	kind: TryReserveErrorKind,
+}
+
+impl TryReserveError {
+	/// Produces an error for a request that exceeds the maximum capacity.
+	#[inline]
+	pub(crate) fn capacity_overflow() -> Self {
+		Self {
+			kind: TryReserveErrorKind::CapacityOverflow,
+		}
+	}
+
+	/// Produces an error for an allocation that the allocator refused.
+	#[inline]
+	pub(crate) fn alloc_error(layout: Layout) -> Self {
+		Self {
+			kind: TryReserveErrorKind::AllocError { layout },
+		}
+	}
+
+	/// Reports a failed infallible reservation.
+	///
+	/// Capacity overflows panic, and refused allocations are passed to
+	/// [`handle_alloc_error`], as `Vec` does.
+	///
+	/// [`handle_alloc_error`]: alloc::alloc::handle_alloc_error
+	#[cold]
+	pub(super) fn handle(self) -> ! {
+		match self.kind {
+			TryReserveErrorKind::CapacityOverflow => panic!("capacity overflow"),
+			TryReserveErrorKind::AllocError { layout } => {
+				handle_alloc_error(layout)
+			},
+		}
+	}
+
+	/// Gets the reason the reservation failed.
+	#[inline]
+	pub fn kind(&self) -> TryReserveErrorKind {
+		self.kind
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Display for TryReserveError {
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("memory allocation failed")?;
+		match self.kind {
+			TryReserveErrorKind::CapacityOverflow => fmt.write_str(
+				" because the computed capacity exceeded the bit-vector’s \
+				 maximum",
+			),
+			TryReserveErrorKind::AllocError { .. } => {
+				fmt.write_str(" because the memory allocator returned an error")
+			},
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryReserveError {}
+
+/// The reasons that a fallible allocation can fail.
+///
+/// ## Original
+///
+/// [`TryReserveErrorKind`](https://doc.rust-lang.org/alloc/collections/enum.TryReserveErrorKind.html)
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TryReserveErrorKind {
+	/// The requested length exceeds the maximum that a bit-vector can hold,
+	/// or its memory exceeds the maximum that can be allocated.
+	CapacityOverflow,
+	/// The memory allocator refused the request.
+	AllocError {
+		/// The layout of the allocation that was refused.
+		layout: Layout,
+	},
+}
@@ -1,6 +1,5 @@
 #![doc = include_str!("../../doc/vec/iter.md")]
 
-use alloc::vec::Vec;
 use core::{
 	fmt::{
 		self,
@@ -29,6 +28,11 @@ use wyz::{
 };
 
 use super::BitVec;
+#[cfg(feature = "allocator")]
+use super::{
+	Allocator,
+	Global,
+};
 use crate::{
 	boxed::BitBox,
 	mem::bits_of,
@@ -43,7 +47,8 @@ use crate::{
 };
 
 #[doc = include_str!("../../doc/vec/iter/Extend_bool.md")]
-impl<T, O> Extend<bool> for BitVec<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator> Extend<bool>
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -79,7 +84,8 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<'a, T, O> Extend<&'a bool> for BitVec<T, O>
+impl<'a, T, O, #[cfg(feature = "allocator")] A: Allocator> Extend<&'a bool>
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -93,7 +99,8 @@ where
 
 #[cfg(not(tarpaulin_include))]
 #[doc = include_str!("../../doc/vec/iter/Extend_BitRef.md")]
-impl<'a, M, T1, T2, O1, O2> Extend<BitRef<'a, M, T2, O2>> for BitVec<T1, O1>
+impl<'a, M, T1, T2, O1, O2, #[cfg(feature = "allocator")] A: Allocator>
+	Extend<BitRef<'a, M, T2, O2>> for in_alloc!(BitVec<T1, O1>, A)
 where
 	M: Mutability,
 	T1: BitStore,
@@ -108,7 +115,8 @@ where
 	}
 }
 
-impl<T, O> Extend<T> for BitVec<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator> Extend<T>
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -126,7 +134,8 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<'a, T, O> Extend<&'a T> for BitVec<T, O>
+impl<'a, T, O, #[cfg(feature = "allocator")] A: Allocator> Extend<&'a T>
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -144,7 +153,8 @@ where
 
 #[cfg(not(tarpaulin_include))]
 #[doc = include_str!("../../doc/vec/iter/FromIterator_bool.md")]
-impl<T, O> FromIterator<bool> for BitVec<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator + Default>
+	FromIterator<bool> for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -152,12 +162,13 @@ where
 	#[inline]
 	fn from_iter<I>(iter: I) -> Self
 	where I: IntoIterator<Item = bool> {
-		Self::new().tap_mut(|bv| bv.extend(iter))
+		Self::new_default().tap_mut(|bv| bv.extend(iter))
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<'a, T, O> FromIterator<&'a bool> for BitVec<T, O>
+impl<'a, T, O, #[cfg(feature = "allocator")] A: Allocator + Default>
+	FromIterator<&'a bool> for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -171,8 +182,8 @@ where
 
 #[cfg(not(tarpaulin_include))]
 #[doc = include_str!("../../doc/vec/iter/FromIterator_BitRef.md")]
-impl<'a, M, T1, T2, O1, O2> FromIterator<BitRef<'a, M, T2, O2>>
-	for BitVec<T1, O1>
+impl<'a, M, T1, T2, O1, O2, #[cfg(feature = "allocator")] A: Allocator + Default>
+	FromIterator<BitRef<'a, M, T2, O2>> for in_alloc!(BitVec<T1, O1>, A)
 where
 	M: Mutability,
 	T1: BitStore,
@@ -188,7 +199,8 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> FromIterator<T> for BitVec<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator + Default> FromIterator<T>
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -196,12 +208,13 @@ where
 	#[inline]
 	fn from_iter<I>(iter: I) -> Self
 	where I: IntoIterator<Item = T> {
-		iter.into_iter().collect::<Vec<T>>().pipe(Self::from_vec)
+		Self::new_default().tap_mut(|bv| bv.extend(iter))
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<'a, T, O> FromIterator<&'a T> for BitVec<T, O>
+impl<'a, T, O, #[cfg(feature = "allocator")] A: Allocator + Default>
+	FromIterator<&'a T> for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -233,7 +246,8 @@ where
 
 #[cfg(not(tarpaulin_include))]
 /// [Original](https://doc.rust-lang.org/alloc/vec/struct.Vec.html#impl-IntoIterator-1)
-impl<'a, T, O> IntoIterator for &'a BitVec<T, O>
+impl<'a, T, O, #[cfg(feature = "allocator")] A: Allocator> IntoIterator
+	for &'a in_alloc!(BitVec<T, O>, A)
 where
 	O: BitOrder,
 	T: 'a + BitStore,
@@ -249,7 +263,8 @@ where
 
 #[cfg(not(tarpaulin_include))]
 /// [Original](https://doc.rust-lang.org/alloc/vec/struct.Vec.html#impl-IntoIterator-2)
-impl<'a, T, O> IntoIterator for &'a mut BitVec<T, O>
+impl<'a, T, O, #[cfg(feature = "allocator")] A: Allocator> IntoIterator
+	for &'a mut in_alloc!(BitVec<T, O>, A)
 where
 	O: BitOrder,
 	T: 'a + BitStore,
@@ -264,13 +279,17 @@ where
 }
 
 #[doc = include_str!("../../doc/vec/iter/Drain.md")]
-pub struct Drain<'a, T, O>
-where
+pub struct Drain<
+	'a,
+	T,
+	O,
+	#[cfg(feature = "allocator")] A: 'a + Allocator = Global,
+> where
 	O: BitOrder,
 	T: 'a + BitStore,
 {
 	/// Exclusive reference to the handle that created the drain.
-	source: &'a mut BitVec<T, O>,
+	source: &'a mut in_alloc!(BitVec<T, O>, A),
 	/// The range of the source bit-vector’s buffer that is being drained.
 	drain:  BitPtrRange<Mut, T, O>,
 	/// The range of the source bit-vector’s preserved back section. This runs
@@ -279,14 +298,20 @@ where
 	tail:   Range<usize>,
 }
 
-impl<'a, T, O> Drain<'a, T, O>
+impl<'a, T, O, #[cfg(feature = "allocator")] A: Allocator>
+	in_alloc!(Drain<'a, T, O>, A)
 where
 	O: BitOrder,
 	T: 'a + BitStore,
 {
 	/// Produces a new drain over a region of a bit-vector.
-	pub(super) fn new<R>(source: &'a mut BitVec<T, O>, range: R) -> Self
-	where R: RangeExt<usize> {
+	pub(super) fn new<R>(
+		source: &'a mut in_alloc!(BitVec<T, O>, A),
+		range: R,
+	) -> Self
+	where
+		R: RangeExt<usize>,
+	{
 		let len = source.len();
 		let region = range.normalize(None, len);
 		assert!(
@@ -408,7 +433,8 @@ where
 
 /// [Original](https://doc.rust-lang.org/alloc/vec/struct.Drain.html#impl-AsRef%3C%5BT%5D%3E)
 #[cfg(not(tarpaulin_include))]
-impl<T, O> AsRef<BitSlice<T, O>> for Drain<'_, T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator> AsRef<BitSlice<T, O>>
+	for in_alloc!(Drain<'_, T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -420,7 +446,8 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> Debug for Drain<'_, T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator> Debug
+	for in_alloc!(Drain<'_, T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -433,7 +460,8 @@ where
 
 /// [Original](https://doc.rust-lang.org/alloc/vec/struct.Drain.html#impl-Iterator)
 #[cfg(not(tarpaulin_include))]
-impl<T, O> Iterator for Drain<'_, T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator> Iterator
+	for in_alloc!(Drain<'_, T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -455,7 +483,8 @@ where
 
 /// [Original](https://doc.rust-lang.org/alloc/vec/struct.Drain.html#impl-DoubleEndedIterator)
 #[cfg(not(tarpaulin_include))]
-impl<T, O> DoubleEndedIterator for Drain<'_, T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator> DoubleEndedIterator
+	for in_alloc!(Drain<'_, T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -473,7 +502,8 @@ where
 
 /// [Original](https://doc.rust-lang.org/alloc/vec/struct.Drain.html#impl-ExactSizeIterator)
 #[cfg(not(tarpaulin_include))]
-impl<T, O> ExactSizeIterator for Drain<'_, T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator> ExactSizeIterator
+	for in_alloc!(Drain<'_, T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -485,7 +515,8 @@ where
 }
 
 /// [Original](https://doc.rust-lang.org/alloc/vec/struct.Drain.html#impl-FusedIterator)
-impl<T, O> FusedIterator for Drain<'_, T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator> FusedIterator
+	for in_alloc!(Drain<'_, T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -494,7 +525,8 @@ where
 
 /// [Original](https://doc.rust-lang.org/alloc/vec/struct.Drain.html#impl-Send)
 // #[allow(clippy::non_send_fields_in_send_ty)]
-unsafe impl<T, O> Send for Drain<'_, T, O>
+unsafe impl<T, O, #[cfg(feature = "allocator")] A: Allocator + Send> Send
+	for in_alloc!(Drain<'_, T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -503,7 +535,8 @@ where
 }
 
 /// [Original](https://doc.rust-lang.org/alloc/vec/struct.Drain.html#impl-Sync)
-unsafe impl<T, O> Sync for Drain<'_, T, O>
+unsafe impl<T, O, #[cfg(feature = "allocator")] A: Allocator + Sync> Sync
+	for in_alloc!(Drain<'_, T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -512,7 +545,8 @@ where
 }
 
 /// [Original](https://doc.rust-lang.org/alloc/vec/struct.Drain.html#impl-Drop)
-impl<T, O> Drop for Drain<'_, T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator> Drop
+	for in_alloc!(Drain<'_, T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -545,21 +579,26 @@ enum FillStatus {
 	EmptyInput = 1,
 }
 
-#[derive(Debug)]
 #[doc = include_str!("../../doc/vec/iter/Splice.md")]
-pub struct Splice<'a, T, O, I>
-where
+pub struct Splice<
+	'a,
+	T,
+	O,
+	I,
+	#[cfg(feature = "allocator")] A: 'a + Allocator = Global,
+> where
 	O: BitOrder,
 	T: 'a + BitStore,
 	I: Iterator<Item = bool>,
 {
 	/// The region of the bit-vector being drained.
-	drain:  Drain<'a, T, O>,
+	drain:  in_alloc!(Drain<'a, T, O>, A),
 	/// The bitstream that replaces drained bits.
 	splice: I,
 }
 
-impl<'a, T, O, I> Splice<'a, T, O, I>
+impl<'a, T, O, I, #[cfg(feature = "allocator")] A: Allocator>
+	in_alloc!(Splice<'a, T, O, I>, A)
 where
 	O: BitOrder,
 	T: 'a + BitStore,
@@ -567,7 +606,7 @@ where
 {
 	/// Constructs a splice out of a drain and a replacement source.
 	pub(super) fn new(
-		drain: Drain<'a, T, O>,
+		drain: in_alloc!(Drain<'a, T, O>, A),
 		splice: impl IntoIterator<IntoIter = I, Item = bool>,
 	) -> Self {
 		let splice = splice.into_iter();
@@ -575,7 +614,25 @@ where
 	}
 }
 
-impl<T, O, I> Iterator for Splice<'_, T, O, I>
+#[cfg(not(tarpaulin_include))]
+impl<T, O, I, #[cfg(feature = "allocator")] A: Allocator> Debug
+	for in_alloc!(Splice<'_, T, O, I>, A)
+where
+	T: BitStore,
+	O: BitOrder,
+	I: Iterator<Item = bool> + Debug,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_struct("Splice")
+			.field("drain", &self.drain)
+			.field("splice", &self.splice)
+			.finish()
+	}
+}
+
+impl<T, O, I, #[cfg(feature = "allocator")] A: Allocator> Iterator
+	for in_alloc!(Splice<'_, T, O, I>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -599,7 +656,8 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O, I> DoubleEndedIterator for Splice<'_, T, O, I>
+impl<T, O, I, #[cfg(feature = "allocator")] A: Allocator> DoubleEndedIterator
+	for in_alloc!(Splice<'_, T, O, I>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -617,7 +675,8 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O, I> ExactSizeIterator for Splice<'_, T, O, I>
+impl<T, O, I, #[cfg(feature = "allocator")] A: Allocator> ExactSizeIterator
+	for in_alloc!(Splice<'_, T, O, I>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -629,7 +688,8 @@ where
 	}
 }
 
-impl<T, O, I> FusedIterator for Splice<'_, T, O, I>
+impl<T, O, I, #[cfg(feature = "allocator")] A: Allocator> FusedIterator
+	for in_alloc!(Splice<'_, T, O, I>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -638,7 +698,8 @@ where
 }
 
 /// [Original](https://doc.rust-lang.org/alloc/vec/struct.Drain.html#impl-Drop)
-impl<T, O, I> Drop for Splice<'_, T, O, I>
+impl<T, O, I, #[cfg(feature = "allocator")] A: Allocator> Drop
+	for in_alloc!(Splice<'_, T, O, I>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -1,58 +1,61 @@
 //! Operator trait implementations for bit-vectors.
 
-use core::{
-	mem::ManuallyDrop,
-	ops::{
-		BitAnd,
-		BitAndAssign,
-		BitOr,
-		BitOrAssign,
-		BitXor,
-		BitXorAssign,
-		Deref,
-		DerefMut,
-		Index,
-		IndexMut,
-		Not,
-	},
+use core::ops::{
+	BitAnd,
+	BitAndAssign,
+	BitOr,
+	BitOrAssign,
+	BitXor,
+	BitXorAssign,
+	Deref,
+	DerefMut,
+	Index,
+	IndexMut,
+	Not,
+	Shl,
+	ShlAssign,
+	Shr,
+	ShrAssign,
 };
 
-use wyz::comu::Mut;
-
+#[cfg(feature = "allocator")]
+use super::Allocator;
 use super::BitVec;
 use crate::{
 	order::BitOrder,
-	ptr::BitSpan,
 	slice::BitSlice,
 	store::BitStore,
 };
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> BitAndAssign<BitVec<T, O>> for BitSlice<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator>
+	BitAndAssign<in_alloc!(BitVec<T, O>, A)> for BitSlice<T, O>
 where
 	T: BitStore,
 	O: BitOrder,
 {
 	#[inline]
-	fn bitand_assign(&mut self, rhs: BitVec<T, O>) {
+	fn bitand_assign(&mut self, rhs: in_alloc!(BitVec<T, O>, A)) {
 		*self &= rhs.as_bitslice()
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> BitAndAssign<&BitVec<T, O>> for BitSlice<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator>
+	BitAndAssign<&in_alloc!(BitVec<T, O>, A)> for BitSlice<T, O>
 where
 	T: BitStore,
 	O: BitOrder,
 {
 	#[inline]
-	fn bitand_assign(&mut self, rhs: &BitVec<T, O>) {
+	fn bitand_assign(&mut self, rhs: &in_alloc!(BitVec<T, O>, A)) {
 		*self &= rhs.as_bitslice()
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O, Rhs> BitAnd<Rhs> for BitVec<T, O>
+impl<T, O, Rhs, #[cfg(feature = "allocator")] A: Allocator> BitAnd<Rhs>
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -68,7 +71,8 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O, Rhs> BitAndAssign<Rhs> for BitVec<T, O>
+impl<T, O, Rhs, #[cfg(feature = "allocator")] A: Allocator> BitAndAssign<Rhs>
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -81,31 +85,34 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> BitOrAssign<BitVec<T, O>> for BitSlice<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator>
+	BitOrAssign<in_alloc!(BitVec<T, O>, A)> for BitSlice<T, O>
 where
 	T: BitStore,
 	O: BitOrder,
 {
 	#[inline]
-	fn bitor_assign(&mut self, rhs: BitVec<T, O>) {
+	fn bitor_assign(&mut self, rhs: in_alloc!(BitVec<T, O>, A)) {
 		*self |= rhs.as_bitslice()
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> BitOrAssign<&BitVec<T, O>> for BitSlice<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator>
+	BitOrAssign<&in_alloc!(BitVec<T, O>, A)> for BitSlice<T, O>
 where
 	T: BitStore,
 	O: BitOrder,
 {
 	#[inline]
-	fn bitor_assign(&mut self, rhs: &BitVec<T, O>) {
+	fn bitor_assign(&mut self, rhs: &in_alloc!(BitVec<T, O>, A)) {
 		*self |= rhs.as_bitslice()
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O, Rhs> BitOr<Rhs> for BitVec<T, O>
+impl<T, O, Rhs, #[cfg(feature = "allocator")] A: Allocator> BitOr<Rhs>
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -121,7 +128,8 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O, Rhs> BitOrAssign<Rhs> for BitVec<T, O>
+impl<T, O, Rhs, #[cfg(feature = "allocator")] A: Allocator> BitOrAssign<Rhs>
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -134,31 +142,34 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> BitXorAssign<BitVec<T, O>> for BitSlice<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator>
+	BitXorAssign<in_alloc!(BitVec<T, O>, A)> for BitSlice<T, O>
 where
 	T: BitStore,
 	O: BitOrder,
 {
 	#[inline]
-	fn bitxor_assign(&mut self, rhs: BitVec<T, O>) {
+	fn bitxor_assign(&mut self, rhs: in_alloc!(BitVec<T, O>, A)) {
 		*self ^= rhs.as_bitslice()
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> BitXorAssign<&BitVec<T, O>> for BitSlice<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator>
+	BitXorAssign<&in_alloc!(BitVec<T, O>, A)> for BitSlice<T, O>
 where
 	T: BitStore,
 	O: BitOrder,
 {
 	#[inline]
-	fn bitxor_assign(&mut self, rhs: &BitVec<T, O>) {
+	fn bitxor_assign(&mut self, rhs: &in_alloc!(BitVec<T, O>, A)) {
 		*self ^= rhs.as_bitslice()
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O, Rhs> BitXor<Rhs> for BitVec<T, O>
+impl<T, O, Rhs, #[cfg(feature = "allocator")] A: Allocator> BitXor<Rhs>
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -174,7 +185,8 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O, Rhs> BitXorAssign<Rhs> for BitVec<T, O>
+impl<T, O, Rhs, #[cfg(feature = "allocator")] A: Allocator> BitXorAssign<Rhs>
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -186,7 +198,231 @@ where
 	}
 }
 
-impl<T, O> Deref for BitVec<T, O>
+#[cfg(not(tarpaulin_include))]
+impl<T, O, Rhs, #[cfg(feature = "allocator")] A: Allocator> Shl<Rhs>
+	for in_alloc!(BitVec<T, O>, A)
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: ShlAssign<Rhs>,
+{
+	type Output = Self;
+
+	#[inline]
+	fn shl(mut self, rhs: Rhs) -> Self::Output {
+		self <<= rhs;
+		self
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O, Rhs, #[cfg(feature = "allocator")] A: Allocator> ShlAssign<Rhs>
+	for in_alloc!(BitVec<T, O>, A)
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: ShlAssign<Rhs>,
+{
+	#[inline]
+	fn shl_assign(&mut self, rhs: Rhs) {
+		*self.as_mut_bitslice() <<= rhs;
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O, Rhs, #[cfg(feature = "allocator")] A: Allocator> Shr<Rhs>
+	for in_alloc!(BitVec<T, O>, A)
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: ShrAssign<Rhs>,
+{
+	type Output = Self;
+
+	#[inline]
+	fn shr(mut self, rhs: Rhs) -> Self::Output {
+		self >>= rhs;
+		self
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O, Rhs, #[cfg(feature = "allocator")] A: Allocator> ShrAssign<Rhs>
+	for in_alloc!(BitVec<T, O>, A)
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: ShrAssign<Rhs>,
+{
+	#[inline]
+	fn shr_assign(&mut self, rhs: Rhs) {
+		*self.as_mut_bitslice() >>= rhs;
+	}
+}
+
+/** Shifts a copy of a bit-slice towards the zero-index.
+
+This copies the bit-slice into a new bit-vector, then shifts the bit-vector with
+[`<<=`], leaving the original bit-slice unmodified. The produced bit-vector has
+the same length as the bit-slice.
+
+## Examples
+
+```rust
+use bitvec::prelude::*;
+
+let bits = bits![1, 0, 1, 1];
+assert_eq!(bits << 1, bits![0, 1, 1, 0]);
+assert_eq!(bits, bits![1, 0, 1, 1]);
+```
+
+[`<<=`]: core::ops::ShlAssign
+**/
+impl<T, O> Shl<usize> for &BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Output = BitVec<T::Unalias, O>;
+
+	#[inline]
+	fn shl(self, rhs: usize) -> Self::Output {
+		self.to_bitvec() << rhs
+	}
+}
+
+/** Shifts a copy of a bit-slice away from the zero-index.
+
+This copies the bit-slice into a new bit-vector, then shifts the bit-vector with
+[`>>=`], leaving the original bit-slice unmodified. The produced bit-vector has
+the same length as the bit-slice.
+
+## Examples
+
+```rust
+use bitvec::prelude::*;
+
+let bits = bits![1, 0, 1, 1];
+assert_eq!(bits >> 1, bits![0, 1, 0, 1]);
+assert_eq!(bits, bits![1, 0, 1, 1]);
+```
+
+[`>>=`]: core::ops::ShrAssign
+**/
+impl<T, O> Shr<usize> for &BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Output = BitVec<T::Unalias, O>;
+
+	#[inline]
+	fn shr(self, rhs: usize) -> Self::Output {
+		self.to_bitvec() >> rhs
+	}
+}
+
+/** Computes the Boolean conjunction of two bit-slices into a new bit-vector.
+
+The produced bit-vector has the type parameters of the left operand, and is as
+long as the longer operand. The shorter operand is treated as if it were
+extended with `0` bits, so the produced bit-vector is `0` past its end. Slice
+both operands to the same length first to truncate instead.
+
+Both operands are loaded a processor word at a time, and the `Lsb0` and `Msb0`
+orderings load whole memory elements rather than individual bits.
+
+## Examples
+
+```rust
+use bitvec::prelude::*;
+
+let a = bits![1, 1, 0, 1];
+let b = bits![u8, Msb0; 1, 0, 1, 1, 1];
+assert_eq!(a & b, bits![1, 0, 0, 1, 0]);
+assert_eq!(&a[.. 2] & &b[.. 2], bits![1, 0]);
+```
+**/
+impl<T1, T2, O1, O2> BitAnd<&BitSlice<T2, O2>> for &BitSlice<T1, O1>
+where
+	T1: BitStore,
+	T2: BitStore,
+	O1: BitOrder,
+	O2: BitOrder,
+{
+	type Output = BitVec<T1::Unalias, O1>;
+
+	#[inline]
+	fn bitand(self, rhs: &BitSlice<T2, O2>) -> Self::Output {
+		self.bitop_to_bitvec(rhs, BitAnd::bitand)
+	}
+}
+
+/** Computes the Boolean disjunction of two bit-slices into a new bit-vector.
+
+The produced bit-vector has the type parameters of the left operand, and is as
+long as the longer operand. The shorter operand is treated as if it were
+extended with `0` bits.
+
+## Examples
+
+```rust
+use bitvec::prelude::*;
+
+let a = bits![1, 1, 0, 0];
+let b = bits![u8, Msb0; 1, 0, 1, 0, 1];
+assert_eq!(a | b, bits![1, 1, 1, 0, 1]);
+```
+**/
+impl<T1, T2, O1, O2> BitOr<&BitSlice<T2, O2>> for &BitSlice<T1, O1>
+where
+	T1: BitStore,
+	T2: BitStore,
+	O1: BitOrder,
+	O2: BitOrder,
+{
+	type Output = BitVec<T1::Unalias, O1>;
+
+	#[inline]
+	fn bitor(self, rhs: &BitSlice<T2, O2>) -> Self::Output {
+		self.bitop_to_bitvec(rhs, BitOr::bitor)
+	}
+}
+
+/** Computes the Boolean exclusive disjunction of two bit-slices into a new
+bit-vector.
+
+The produced bit-vector has the type parameters of the left operand, and is as
+long as the longer operand. The shorter operand is treated as if it were
+extended with `0` bits.
+
+## Examples
+
+```rust
+use bitvec::prelude::*;
+
+let a = bits![1, 1, 0, 0];
+let b = bits![u8, Msb0; 1, 0, 1, 0, 1];
+assert_eq!(a ^ b, bits![0, 1, 1, 0, 1]);
+```
+**/
+impl<T1, T2, O1, O2> BitXor<&BitSlice<T2, O2>> for &BitSlice<T1, O1>
+where
+	T1: BitStore,
+	T2: BitStore,
+	O1: BitOrder,
+	O2: BitOrder,
+{
+	type Output = BitVec<T1::Unalias, O1>;
+
+	#[inline]
+	fn bitxor(self, rhs: &BitSlice<T2, O2>) -> Self::Output {
+		self.bitop_to_bitvec(rhs, BitXor::bitxor)
+	}
+}
+
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator> Deref
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -199,7 +435,8 @@ where
 	}
 }
 
-impl<T, O> DerefMut for BitVec<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator> DerefMut
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -210,21 +447,21 @@ where
 	}
 }
 
-impl<T, O> Drop for BitVec<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator> Drop
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
 {
 	#[inline]
 	fn drop(&mut self) {
-		if self.bitspan != BitSpan::<Mut, T, O>::EMPTY {
-			self.with_vec(|slot| unsafe { ManuallyDrop::drop(slot) });
-		}
+		self.deallocate();
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O, Idx> Index<Idx> for BitVec<T, O>
+impl<T, O, Idx, #[cfg(feature = "allocator")] A: Allocator> Index<Idx>
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -239,7 +476,8 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O, Idx> IndexMut<Idx> for BitVec<T, O>
+impl<T, O, Idx, #[cfg(feature = "allocator")] A: Allocator> IndexMut<Idx>
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -255,7 +493,8 @@ where
 on the value of bits in the buffer that are outside the domain of
 [`BitVec::as_mut_bitslice`].
 **/
-impl<T, O> Not for BitVec<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator> Not
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -0,0 +1,722 @@
+//! Bit-vectors that keep short sequences inline.
+
+use core::{
+	cmp,
+	fmt::{
+		self,
+		Debug,
+		Display,
+		Formatter,
+	},
+	hash::{
+		Hash,
+		Hasher,
+	},
+	iter::FromIterator,
+	ops::{
+		Deref,
+		DerefMut,
+	},
+};
+
+use super::BitVec;
+use crate::{
+	array::BitArray,
+	mem::bits_of,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+	view::BitViewSized,
+};
+
+#[doc = include_str!("../../doc/vec/SmallBitVec.md")]
+pub struct SmallBitVec<A = [usize; 2], O = Lsb0>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	/// The current buffer.
+	inner: Inner<A, O>,
+}
+
+/// The two storage modes of a `SmallBitVec`.
+enum Inner<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	/// Bits are held in a bit-array, of which the first `len` are live.
+	Inline {
+		/// The inline buffer.
+		bits: BitArray<A, O>,
+		/// The number of live bits at the front of `bits`.
+		len:  usize,
+	},
+	/// Bits are held in a heap-allocated bit-vector.
+	Heap(BitVec<A::Store, O>),
+}
+
+/// Constructors.
+impl<A, O> SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	/// An empty bit-vector using its inline buffer.
+	pub const EMPTY: Self = Self {
+		inner: Inner::Inline {
+			bits: BitArray::ZERO,
+			len:  0,
+		},
+	};
+	/// The number of bits that can be stored without allocating.
+	pub const INLINE_CAPACITY: usize = bits_of::<A>();
+
+	/// Constructs a new, empty, bit-vector.
+	///
+	/// This does not allocate.
+	///
+	/// ## Original
+	///
+	/// [`Vec::new`](alloc::vec::Vec::new)
+	#[inline]
+	pub fn new() -> Self {
+		Self::EMPTY
+	}
+
+	/// Constructs a new, empty, bit-vector that can hold at least `capacity`
+	/// bits before reallocating.
+	///
+	/// This only allocates when `capacity` exceeds [`INLINE_CAPACITY`].
+	///
+	/// ## Original
+	///
+	/// [`Vec::with_capacity`](alloc::vec::Vec::with_capacity)
+	///
+	/// [`INLINE_CAPACITY`]: Self::INLINE_CAPACITY
+	#[inline]
+	pub fn with_capacity(capacity: usize) -> Self {
+		if capacity <= Self::INLINE_CAPACITY {
+			Self::EMPTY
+		}
+		else {
+			Self {
+				inner: Inner::Heap(BitVec::with_capacity(capacity)),
+			}
+		}
+	}
+
+	/// Copies the contents of a bit-slice into a new bit-vector.
+	///
+	/// This only allocates when the bit-slice is longer than
+	/// [`INLINE_CAPACITY`].
+	///
+	/// [`INLINE_CAPACITY`]: Self::INLINE_CAPACITY
+	#[inline]
+	pub fn from_bitslice<T, O2>(slice: &BitSlice<T, O2>) -> Self
+	where
+		T: BitStore,
+		O2: BitOrder,
+	{
+		let mut out = Self::with_capacity(slice.len());
+		out.extend_from_bitslice(slice);
+		out
+	}
+}
+
+/// Inspectors.
+impl<A, O> SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	/// Tests whether the bit-vector is still using its inline buffer.
+	///
+	/// Once a bit-vector has moved to the heap, it stays there until
+	/// [`shrink_to_fit`] moves it back.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::vec::SmallBitVec;
+	///
+	/// let mut sbv = SmallBitVec::<[u8; 1], Lsb0>::new();
+	/// sbv.extend_from_bitslice(bits![1; 8]);
+	/// assert!(sbv.is_inline());
+	///
+	/// sbv.push(false);
+	/// assert!(!sbv.is_inline());
+	/// ```
+	///
+	/// [`shrink_to_fit`]: Self::shrink_to_fit
+	#[inline]
+	pub fn is_inline(&self) -> bool {
+		matches!(self.inner, Inner::Inline { .. })
+	}
+
+	/// Gets the number of bits the bit-vector can hold without reallocating.
+	///
+	/// ## Original
+	///
+	/// [`Vec::capacity`](alloc::vec::Vec::capacity)
+	#[inline]
+	pub fn capacity(&self) -> usize {
+		match &self.inner {
+			Inner::Inline { .. } => Self::INLINE_CAPACITY,
+			Inner::Heap(bv) => bv.capacity(),
+		}
+	}
+
+	/// Explicitly views the bit-vector as a bit-slice.
+	#[inline]
+	pub fn as_bitslice(&self) -> &BitSlice<A::Store, O> {
+		match &self.inner {
+			Inner::Inline { bits, len } => unsafe {
+				bits.as_bitslice().get_unchecked(.. *len)
+			},
+			Inner::Heap(bv) => bv.as_bitslice(),
+		}
+	}
+
+	/// Explicitly views the bit-vector as a mutable bit-slice.
+	#[inline]
+	pub fn as_mut_bitslice(&mut self) -> &mut BitSlice<A::Store, O> {
+		match &mut self.inner {
+			Inner::Inline { bits, len } => unsafe {
+				bits.as_mut_bitslice().get_unchecked_mut(.. *len)
+			},
+			Inner::Heap(bv) => bv.as_mut_bitslice(),
+		}
+	}
+
+	/// Converts the bit-vector into an ordinary [`BitVec`], allocating if it
+	/// was inline.
+	///
+	/// [`BitVec`]: crate::vec::BitVec
+	#[inline]
+	pub fn into_bitvec(self) -> BitVec<A::Store, O> {
+		match self.inner {
+			Inner::Inline { bits, len } => BitVec::from_bitslice(&bits[.. len]),
+			Inner::Heap(bv) => bv,
+		}
+	}
+}
+
+/// Port of the `Vec<bool>` inherent API.
+impl<A, O> SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	/// Reserves capacity for at least `additional` more bits.
+	///
+	/// If the inline buffer cannot hold the requested bits, the bit-vector
+	/// moves to the heap.
+	///
+	/// ## Original
+	///
+	/// [`Vec::reserve`](alloc::vec::Vec::reserve)
+	///
+	/// ## Panics
+	///
+	/// This panics if the new capacity exceeds the bit-vector’s maximum.
+	#[inline]
+	pub fn reserve(&mut self, additional: usize) {
+		match &mut self.inner {
+			Inner::Inline { len, .. } => {
+				let total = len
+					.checked_add(additional)
+					.expect("bit-vector capacity exceeded");
+				if total > Self::INLINE_CAPACITY {
+					self.spill(total);
+				}
+			},
+			Inner::Heap(bv) => bv.reserve(additional),
+		}
+	}
+
+	/// Shrinks the capacity of the bit-vector as much as possible.
+	///
+	/// A heap-allocated bit-vector that is short enough to fit in the inline
+	/// buffer moves back into it and releases its allocation.
+	///
+	/// ## Original
+	///
+	/// [`Vec::shrink_to_fit`](alloc::vec::Vec::shrink_to_fit)
+	#[inline]
+	pub fn shrink_to_fit(&mut self) {
+		if let Inner::Heap(bv) = &mut self.inner {
+			if bv.len() <= Self::INLINE_CAPACITY {
+				let len = bv.len();
+				let mut bits = BitArray::<A, O>::ZERO;
+				bits[.. len].clone_from_bitslice(bv);
+				self.inner = Inner::Inline { bits, len };
+			}
+			else {
+				bv.shrink_to_fit();
+			}
+		}
+	}
+
+	/// Shortens the bit-vector, keeping the first `new_len` bits and
+	/// discarding the rest.
+	///
+	/// If `len` is greater than the bit-vector’s current length, this has no
+	/// effect.
+	///
+	/// ## Original
+	///
+	/// [`Vec::truncate`](alloc::vec::Vec::truncate)
+	#[inline]
+	pub fn truncate(&mut self, new_len: usize) {
+		match &mut self.inner {
+			Inner::Inline { len, .. } => *len = cmp::min(*len, new_len),
+			Inner::Heap(bv) => bv.truncate(new_len),
+		}
+	}
+
+	/// Inserts a bit at a given position, shifting all bits after it one spot
+	/// to the right.
+	///
+	/// `index` may be any value up to *and including* `self.len()`.
+	///
+	/// ## Original
+	///
+	/// [`Vec::insert`](alloc::vec::Vec::insert)
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is out of bounds (including `self.len()`).
+	#[inline]
+	pub fn insert(&mut self, index: usize, value: bool) {
+		self.assert_in_bounds(index, 0 ..= self.len());
+		self.push(value);
+		unsafe { self.get_unchecked_mut(index ..) }.rotate_right(1);
+	}
+
+	/// Removes a bit at a given position, shifting all bits after it one spot
+	/// to the left.
+	///
+	/// `index` may be any value up to, but **not** including, `self.len()`.
+	///
+	/// ## Original
+	///
+	/// [`Vec::remove`](alloc::vec::Vec::remove)
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is out of bounds (excluding `self.len()`).
+	#[inline]
+	pub fn remove(&mut self, index: usize) -> bool {
+		self.assert_in_bounds(index, 0 .. self.len());
+		unsafe { self.get_unchecked_mut(index ..) }.rotate_left(1);
+		self.pop().expect("bit-vector is not empty")
+	}
+
+	/// Appends a single bit to the bit-vector.
+	///
+	/// If the inline buffer is full, this moves the bit-vector to the heap.
+	///
+	/// ## Original
+	///
+	/// [`Vec::push`](alloc::vec::Vec::push)
+	///
+	/// ## Panics
+	///
+	/// This panics if the push would cause the bit-vector to exceed its maximum
+	/// capacity.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::vec::SmallBitVec;
+	///
+	/// let mut sbv = SmallBitVec::<u8, Msb0>::new();
+	/// for _ in 0 .. 12 {
+	///   sbv.push(true);
+	/// }
+	/// assert_eq!(sbv.len(), 12);
+	/// assert!(!sbv.is_inline());
+	/// ```
+	#[inline]
+	pub fn push(&mut self, value: bool) {
+		if let Inner::Inline { bits, len } = &mut self.inner {
+			if *len < Self::INLINE_CAPACITY {
+				unsafe {
+					bits.as_mut_bitslice().set_unchecked(*len, value);
+				}
+				*len += 1;
+				return;
+			}
+			let total = *len * 2;
+			self.spill(total);
+		}
+		if let Inner::Heap(bv) = &mut self.inner {
+			bv.push(value);
+		}
+	}
+
+	/// Attempts to remove the trailing bit from the bit-vector.
+	///
+	/// This returns `None` if the bit-vector is empty. It never moves the
+	/// bit-vector between the heap and the inline buffer.
+	///
+	/// ## Original
+	///
+	/// [`Vec::pop`](alloc::vec::Vec::pop)
+	#[inline]
+	pub fn pop(&mut self) -> Option<bool> {
+		match &mut self.inner {
+			Inner::Inline { len: 0, .. } => None,
+			Inner::Inline { bits, len } => {
+				*len -= 1;
+				Some(unsafe { *bits.as_bitslice().get_unchecked(*len) })
+			},
+			Inner::Heap(bv) => bv.pop(),
+		}
+	}
+
+	/// Empties the bit-vector.
+	///
+	/// This does not change its capacity or storage mode.
+	///
+	/// ## Original
+	///
+	/// [`Vec::clear`](alloc::vec::Vec::clear)
+	#[inline]
+	pub fn clear(&mut self) {
+		self.truncate(0);
+	}
+
+	/// Copies all bits in a bit-slice into the end of the bit-vector.
+	///
+	/// This moves the bit-vector to the heap if its inline buffer cannot hold
+	/// the combined bits.
+	///
+	/// ## Original
+	///
+	/// [`Vec::extend_from_slice`](alloc::vec::Vec::extend_from_slice)
+	#[inline]
+	pub fn extend_from_bitslice<T, O2>(&mut self, other: &BitSlice<T, O2>)
+	where
+		T: BitStore,
+		O2: BitOrder,
+	{
+		self.reserve(other.len());
+		match &mut self.inner {
+			Inner::Inline { bits, len } => {
+				let new_len = *len + other.len();
+				bits[*len .. new_len].clone_from_bitslice(other);
+				*len = new_len;
+			},
+			Inner::Heap(bv) => bv.extend_from_bitslice(other),
+		}
+	}
+
+	/// Moves the inline buffer into a heap allocation of at least `capacity`
+	/// bits.
+	#[inline]
+	fn spill(&mut self, capacity: usize) {
+		if let Inner::Inline { bits, len } = &self.inner {
+			let mut bv = BitVec::with_capacity(cmp::max(capacity, *len));
+			bv.extend_from_bitslice(&bits[.. *len]);
+			self.inner = Inner::Heap(bv);
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> Clone for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		Self {
+			inner: match &self.inner {
+				Inner::Inline { bits, len } => Inner::Inline {
+					bits: bits.clone(),
+					len:  *len,
+				},
+				Inner::Heap(bv) => Inner::Heap(bv.clone()),
+			},
+		}
+	}
+}
+
+impl<A, O> Eq for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> Ord for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn cmp(&self, other: &Self) -> cmp::Ordering {
+		self.as_bitslice().cmp(other.as_bitslice())
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O1, A, O2> PartialEq<SmallBitVec<A, O2>> for BitSlice<T, O1>
+where
+	T: BitStore,
+	O1: BitOrder,
+	A: BitViewSized,
+	O2: BitOrder,
+{
+	#[inline]
+	fn eq(&self, other: &SmallBitVec<A, O2>) -> bool {
+		self == other.as_bitslice()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O, Rhs> PartialEq<Rhs> for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+	Rhs: ?Sized,
+	BitSlice<A::Store, O>: PartialEq<Rhs>,
+{
+	#[inline]
+	fn eq(&self, other: &Rhs) -> bool {
+		self.as_bitslice() == other
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, A, O> PartialOrd<SmallBitVec<A, O>> for BitSlice<T, O>
+where
+	T: BitStore,
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn partial_cmp(&self, other: &SmallBitVec<A, O>) -> Option<cmp::Ordering> {
+		self.partial_cmp(other.as_bitslice())
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O, Rhs> PartialOrd<Rhs> for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+	Rhs: ?Sized,
+	BitSlice<A::Store, O>: PartialOrd<Rhs>,
+{
+	#[inline]
+	fn partial_cmp(&self, other: &Rhs) -> Option<cmp::Ordering> {
+		self.as_bitslice().partial_cmp(other)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> AsRef<BitSlice<A::Store, O>> for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn as_ref(&self) -> &BitSlice<A::Store, O> {
+		self.as_bitslice()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> AsMut<BitSlice<A::Store, O>> for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn as_mut(&mut self) -> &mut BitSlice<A::Store, O> {
+		self.as_mut_bitslice()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O, T> From<&BitSlice<T, O>> for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+	T: BitStore,
+{
+	#[inline]
+	fn from(slice: &BitSlice<T, O>) -> Self {
+		Self::from_bitslice(slice)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> From<BitArray<A, O>> for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn from(bits: BitArray<A, O>) -> Self {
+		Self {
+			inner: Inner::Inline {
+				bits,
+				len: Self::INLINE_CAPACITY,
+			},
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> From<BitVec<A::Store, O>> for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn from(bv: BitVec<A::Store, O>) -> Self {
+		Self {
+			inner: Inner::Heap(bv),
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> From<SmallBitVec<A, O>> for BitVec<A::Store, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn from(sbv: SmallBitVec<A, O>) -> Self {
+		sbv.into_bitvec()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> Default for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn default() -> Self {
+		Self::EMPTY
+	}
+}
+
+impl<A, O> Debug for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		self.as_bitspan().render(fmt, "SmallBitVec", &[
+			("capacity", &self.capacity() as &dyn Debug),
+			("inline", &self.is_inline() as &dyn Debug),
+		])?;
+		fmt.write_str(" ")?;
+		Display::fmt(self, fmt)
+	}
+}
+
+easy_fmt! {
+	impl Binary
+	impl Display
+	impl LowerHex
+	impl Octal
+	impl UpperHex
+	for SmallBitVec<A, O>
+}
+
+impl<A, O> Extend<bool> for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = bool> {
+		let iter = iter.into_iter();
+		self.reserve(iter.size_hint().0);
+		iter.for_each(|bit| self.push(bit));
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<'a, A, O> Extend<&'a bool> for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = &'a bool> {
+		self.extend(iter.into_iter().copied());
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O, I> FromIterator<I> for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+	Self: Extend<I>,
+{
+	#[inline]
+	fn from_iter<II>(iter: II) -> Self
+	where II: IntoIterator<Item = I> {
+		let mut out = Self::new();
+		out.extend(iter);
+		out
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> Hash for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn hash<H>(&self, hasher: &mut H)
+	where H: Hasher {
+		self.as_bitslice().hash(hasher);
+	}
+}
+
+impl<A, O> Deref for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	type Target = BitSlice<A::Store, O>;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		self.as_bitslice()
+	}
+}
+
+impl<A, O> DerefMut for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.as_mut_bitslice()
+	}
+}
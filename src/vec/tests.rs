@@ -13,6 +13,7 @@ use crate::{
 
 mod api;
 mod iter;
+mod small;
 mod traits;
 
 #[test]
@@ -79,3 +80,23 @@ fn misc() {
 
 	assert_eq!(!bitvec![0, 1], bits![1, 0]);
 }
+
+#[test]
+fn shifts() {
+	let bv = bitvec![u8, Msb0; 1, 0, 1, 1, 0, 0, 1, 0, 1];
+	assert_eq!(bv.clone() << 3, bits![1, 0, 0, 1, 0, 1, 0, 0, 0]);
+	assert_eq!(bv.clone() >> 3, bits![0, 0, 0, 1, 0, 1, 1, 0, 0]);
+	assert_eq!(bv.as_bitslice() << 9, bits![0; 9]);
+	assert_eq!(&bv[1 ..] >> 1, bits![0, 0, 1, 1, 0, 0, 1, 0]);
+
+	let mut grow = bv.clone();
+	grow.shift_right_grow(10);
+	assert_eq!(grow.len(), 19);
+	assert!(grow[.. 10].not_any());
+	assert_eq!(grow[10 ..], bv);
+
+	grow.shift_left_shrink(10);
+	assert_eq!(grow, bv);
+	grow.shift_left_shrink(20);
+	assert!(grow.is_empty());
+}
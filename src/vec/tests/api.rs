@@ -68,3 +68,231 @@ fn misc() {
 	bv.extend_from_within(2 .. 4);
 	assert_eq!(bv, bits![0, 0, 1, 1, 0, 0, 1, 1]);
 }
+
+#[test]
+fn replace_all() {
+	let mut bv = bitvec![u8, Msb0; 0, 1, 1, 0, 1, 1, 1, 0];
+	assert_eq!(bv.replace_all(bits![1, 1], bits![u16, Lsb0; 0, 0]), 2);
+	assert_eq!(bv, bits![0, 0, 0, 0, 0, 0, 1, 0]);
+
+	assert_eq!(bv.replace_all(bits![0, 0, 0], bits![1]), 2);
+	assert_eq!(bv, bits![1, 1, 1, 0]);
+
+	assert_eq!(bv.replace_all(bits![1, 0], bits![]), 1);
+	assert_eq!(bv, bits![1, 1]);
+
+	assert_eq!(bv.replace_all(bits![0], bits![1, 1, 1]), 0);
+	assert_eq!(bv, bits![1, 1]);
+
+	assert_eq!(bv.replace_all(bits![], bits![0]), 3);
+	assert_eq!(bv, bits![0, 1, 0, 1, 0]);
+
+	let mut bv = bitvec![u32, Lsb0; 0; 500];
+	bv[100 .. 164].fill(true);
+	bv[300 .. 364].fill(true);
+	assert_eq!(bv.replace_all(bits![1; 64], bits![1; 10]), 2);
+	assert_eq!(bv.len(), 392);
+	assert_eq!(bv.count_ones(), 20);
+	assert_eq!(bv.first_one(), Some(100));
+	assert_eq!(bv.last_one(), Some(255));
+}
+
+#[test]
+fn fallible() {
+	use crate::vec::TryReserveErrorKind;
+
+	let mut bv = BitVec::<u8, Msb0>::try_with_capacity(20).unwrap();
+	assert_eq!(bv.capacity(), 24);
+	let mut expected = BitVec::<u8, Msb0>::new();
+	for n in 0 .. 200 {
+		bv.try_push(n % 3 == 0).unwrap();
+		expected.push(n % 3 == 0);
+	}
+	assert_eq!(bv, expected);
+
+	bv.try_reserve_exact(8).unwrap();
+	assert!(bv.capacity() >= 208);
+
+	let err = bv.try_reserve(usize::MAX).unwrap_err();
+	assert_eq!(err.kind(), TryReserveErrorKind::CapacityOverflow);
+	let err = bv.try_reserve(BitSlice::<u8, Msb0>::MAX_BITS).unwrap_err();
+	assert_eq!(err.kind(), TryReserveErrorKind::CapacityOverflow);
+	assert_eq!(bv, expected);
+
+	bv.try_resize(250, true).unwrap();
+	assert_eq!(bv[200 ..].count_ones(), 50);
+	bv.try_resize(10, true).unwrap();
+	assert_eq!(bv, expected[.. 10]);
+
+	bv.try_extend_from_bitslice(bits![u16, Lsb0; 0, 1, 1])
+		.unwrap();
+	assert_eq!(bv[10 ..], bits![0, 1, 1]);
+
+	//  Reservations honor a bit-vector that does not begin at bit zero.
+	let mut offset = BitVec::from_bitslice(&bits![u8, Msb0; 1; 16][5 ..]);
+	offset.try_reserve(100).unwrap();
+	assert!(offset.capacity() >= 111);
+	offset.try_extend_from_bitslice(bits![0; 100]).unwrap();
+	assert_eq!(offset.count_ones(), 11);
+
+	let ones = BitVec::<u32, Lsb0>::try_repeat(true, 70).unwrap();
+	assert_eq!(ones, BitVec::<u32, Lsb0>::repeat(true, 70));
+	assert!(BitVec::<u32, Lsb0>::try_repeat(false, usize::MAX).is_err());
+
+	let bits = bits![u16, Msb0; 0, 1, 1, 0, 1];
+	assert_eq!(bits.try_to_bitvec().unwrap(), bits);
+	assert_eq!(bits.try_repeat(3).unwrap(), bits.repeat(3));
+	assert!(bits.try_repeat(usize::MAX / 2).is_err());
+
+	let bb = BitBox::try_from_bitslice(&bits[1 ..]).unwrap();
+	assert_eq!(bb, bits[1 ..]);
+}
+
+#[test]
+fn convert_order() {
+	use crate::order::HiLo;
+
+	let data = [0xA5u8, 0x3C, 0x0F, 0x96, 0x71, 0xE8, 0x5B, 0x24, 0xC3];
+	let bits = &data.view_bits::<Msb0>()[3 .. 69];
+
+	let lsb = bits.to_bitvec_with::<u16, Lsb0>();
+	assert_eq!(lsb, bits);
+	let msb = bits.to_bitvec_with::<u64, Msb0>();
+	assert_eq!(msb, bits);
+	let hilo = bits.to_bitvec_with::<u32, HiLo>();
+	assert_eq!(hilo, bits);
+
+	//  Cross-order cloning between unaligned regions.
+	let mut dest = bitvec![u32, Lsb0; 1; 80];
+	dest[7 .. 73].clone_from_bitslice(bits);
+	assert_eq!(dest[7 .. 73], bits);
+	assert!(dest[.. 7].all());
+	assert!(dest[73 ..].all());
+
+	let offset = BitVec::<u8, Msb0>::from_bitslice(bits);
+	let head = offset.as_bitptr().raw_parts().1;
+	let converted = offset.convert_order::<Lsb0>();
+	assert_eq!(converted, bits);
+	assert_eq!(converted.as_bitptr().raw_parts().1, head);
+	assert_eq!(converted.convert_order::<Msb0>(), bits);
+	assert_eq!(msb.clone().convert_order::<Msb0>(), bits);
+	assert_eq!(lsb.clone().convert_order::<HiLo>(), bits);
+
+	let flipped: BitVec<u16, Msb0> = lsb.reinterpret_order();
+	assert_eq!(flipped, bits);
+	let flipped: BitVec<u16, Lsb0> = flipped.reinterpret_order();
+	assert_eq!(flipped, bits);
+
+	let bb = msb.into_boxed_bitslice();
+	let bb: BitBox<u64, Lsb0> = bb.reinterpret_order();
+	assert_eq!(bb, bits);
+	let bb: BitBox<u64, Msb0> = bb.reinterpret_order();
+	assert_eq!(bb, bits);
+}
+
+#[test]
+#[cfg(feature = "allocator")]
+fn custom_allocator() {
+	use core::{
+		alloc::Layout,
+		cell::Cell,
+		ptr::NonNull,
+	};
+
+	use crate::vec::{
+		AllocError,
+		Allocator,
+		Global,
+	};
+
+	#[derive(Default)]
+	struct Counter {
+		live:  Cell<usize>,
+		grows: Cell<usize>,
+	}
+
+	unsafe impl Allocator for Counter {
+		fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+			self.live.set(self.live.get() + layout.size());
+			Global.allocate(layout)
+		}
+
+		unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+			self.live.set(self.live.get() - layout.size());
+			Global.deallocate(ptr, layout)
+		}
+
+		unsafe fn grow(
+			&self,
+			ptr: NonNull<u8>,
+			old_layout: Layout,
+			new_layout: Layout,
+		) -> Result<NonNull<u8>, AllocError> {
+			self.grows.set(self.grows.get() + 1);
+			self.live
+				.set(self.live.get() - old_layout.size() + new_layout.size());
+			Global.grow(ptr, old_layout, new_layout)
+		}
+	}
+
+	let counter = Counter::default();
+
+	let bv = BitVec::<u16, Msb0, _>::new_in(&counter);
+	assert_eq!(counter.live.get(), 0);
+	drop(bv);
+
+	let bv = BitVec::<u16, Msb0, _>::with_capacity_in(20, &counter);
+	assert!(bv.is_empty());
+	assert_eq!(counter.live.get(), 4);
+	drop(bv);
+	assert_eq!(counter.live.get(), 0);
+
+	let mut bv = BitVec::<_, Msb0, _>::from_vec_in(vec![0xA5u8, 0x3C], &counter);
+	assert_eq!(counter.live.get(), 2);
+	bv.set(0, false);
+	assert_eq!(bv.load_be::<u16>(), 0x253C);
+
+	let (bitptr, len, capa, alloc) = bv.into_raw_parts_with_alloc();
+	assert_eq!((len, capa), (16, 16));
+	assert_eq!(counter.live.get(), 2);
+	let bv = unsafe { BitVec::from_raw_parts_in(bitptr, len, capa, alloc) };
+	assert_eq!(bv, bits![0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 1, 1, 1, 1, 0, 0]);
+
+	let copy = BitVec::from_bitslice_in(&bv[4 ..], &counter);
+	assert_eq!(copy, bv[4 ..]);
+	assert_eq!(counter.live.get(), 4);
+	drop((bv, copy));
+	assert_eq!(counter.live.get(), 0);
+
+	//  Growth past the initial capacity goes through the same allocator.
+	let mut bv = BitVec::<u8, Lsb0, _>::with_capacity_in(8, &counter);
+	assert_eq!(counter.live.get(), 1);
+	for idx in 0 .. 40 {
+		bv.push(idx % 3 == 0);
+	}
+	assert!(counter.grows.get() > 0);
+	assert_eq!(counter.live.get() * 8, bv.capacity());
+	bv.extend_from_bitslice(bits![1; 10]);
+	bv.extend(bits![0, 1].iter().by_vals());
+	bv.resize(80, true);
+	assert_eq!(bv.len(), 80);
+	assert_eq!(bv.count_ones(), 14 + 10 + 1 + 28);
+	assert_eq!(counter.live.get() * 8, bv.capacity());
+
+	let copy = bv.clone();
+	assert_eq!(copy, bv);
+	assert_eq!(copy.to_string(), bv.to_string());
+	assert!(format!("{:?}", copy).starts_with("BitVec<u8, "));
+	assert_eq!(counter.live.get() * 8, bv.capacity() + copy.capacity());
+
+	bv.truncate(12);
+	bv.shrink_to_fit();
+	assert_eq!(bv, copy[.. 12]);
+	assert_eq!(bv.capacity(), 16);
+	bv.clear();
+	bv.shrink_to_fit();
+	assert_eq!(bv.capacity(), 0);
+	assert_eq!(counter.live.get() * 8, copy.capacity());
+	drop((bv, copy));
+	assert_eq!(counter.live.get(), 0);
+}
@@ -0,0 +1,124 @@
+#[cfg(not(feature = "std"))]
+use alloc::format;
+use core::{
+	fmt::Debug,
+	hash::Hash,
+	iter::FromIterator,
+	ops::{
+		Deref,
+		DerefMut,
+	},
+};
+
+use static_assertions::*;
+
+use crate::{
+	prelude::*,
+	vec::SmallBitVec,
+};
+
+#[test]
+fn traits() {
+	assert_impl_all!(
+		SmallBitVec: AsMut<BitSlice>,
+		AsRef<BitSlice>,
+		Clone,
+		Debug,
+		Default,
+		Deref<Target = BitSlice>,
+		DerefMut,
+		Eq,
+		Extend<bool>,
+		From<BitVec>,
+		FromIterator<bool>,
+		Hash,
+		Ord,
+		PartialEq<&'static BitSlice>,
+	);
+}
+
+#[test]
+fn spill_and_shrink() {
+	let mut sbv = SmallBitVec::<u8, Msb0>::new();
+	assert!(sbv.is_inline());
+	assert!(sbv.is_empty());
+	assert_eq!(sbv.capacity(), 8);
+
+	for n in 0 .. 8 {
+		sbv.push(n % 3 == 0);
+	}
+	assert!(sbv.is_inline());
+	assert_eq!(sbv, bits![1, 0, 0, 1, 0, 0, 1, 0]);
+
+	sbv.push(true);
+	assert!(!sbv.is_inline());
+	assert!(sbv.capacity() >= 9);
+	assert_eq!(sbv, bits![1, 0, 0, 1, 0, 0, 1, 0, 1]);
+
+	assert_eq!(sbv.pop(), Some(true));
+	assert!(!sbv.is_inline());
+	sbv.shrink_to_fit();
+	assert!(sbv.is_inline());
+	assert_eq!(sbv, bits![1, 0, 0, 1, 0, 0, 1, 0]);
+
+	sbv.clear();
+	assert!(sbv.is_empty());
+	assert_eq!(sbv.pop(), None);
+
+	let mut sbv = SmallBitVec::<u8, Lsb0>::with_capacity(100);
+	assert!(!sbv.is_inline());
+	sbv.shrink_to_fit();
+	assert!(sbv.is_inline());
+}
+
+#[test]
+fn edits() {
+	let mut sbv = SmallBitVec::<[u16; 1], Lsb0>::new();
+	sbv.extend_from_bitslice(bits![u8, Msb0; 0, 1, 0, 0, 1]);
+	sbv.insert(2, true);
+	assert_eq!(sbv, bits![0, 1, 1, 0, 0, 1]);
+	assert!(!sbv.remove(3));
+	assert_eq!(sbv, bits![0, 1, 1, 0, 1]);
+	sbv.truncate(3);
+	assert_eq!(sbv, bits![0, 1, 1]);
+
+	//  The same operations, across and after the spill to the heap.
+	sbv.extend_from_bitslice(bits![1; 14]);
+	assert!(!sbv.is_inline());
+	sbv.insert(0, false);
+	assert!(sbv.remove(17));
+	assert_eq!(sbv.len(), 17);
+	assert_eq!(sbv.count_ones(), 15);
+	assert_eq!(sbv[.. 4], bits![0, 0, 1, 1]);
+
+	let bv = sbv.clone().into_bitvec();
+	assert_eq!(bv, sbv);
+	assert_eq!(SmallBitVec::<[u16; 1], Lsb0>::from(bv.clone()), bv);
+	assert_eq!(SmallBitVec::<[u16; 1], Lsb0>::from_bitslice(&bv), bv);
+}
+
+#[test]
+fn collect_and_format() {
+	let sbv = [true, false, true]
+		.iter()
+		.collect::<SmallBitVec<[u8; 1], Lsb0>>();
+	assert!(sbv.is_inline());
+	assert_eq!(format!("{}", sbv), "[1, 0, 1]");
+	let render = format!("{:?}", sbv);
+	assert!(render.starts_with("SmallBitVec<u8, "));
+	assert!(render.contains("inline: true"));
+
+	let long = (0 .. 40)
+		.map(|n| n & 1 == 1)
+		.collect::<SmallBitVec<[u8; 1], Lsb0>>();
+	assert!(!long.is_inline());
+	assert_eq!(long.count_ones(), 20);
+
+	let inline = SmallBitVec::<[u32; 2], Lsb0>::from(long.as_bitslice());
+	let mut bv = BitVec::<u32, Lsb0>::new();
+	bv.extend_from_bitslice(&long);
+	let heap = SmallBitVec::<[u32; 2], Lsb0>::from(bv);
+	assert!(inline.is_inline());
+	assert!(!heap.is_inline());
+	assert_eq!(inline, heap);
+}
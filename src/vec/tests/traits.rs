@@ -21,13 +21,17 @@ use core::{
 		RefUnwindSafe,
 		UnwindSafe,
 	},
+	str::FromStr,
 };
 #[cfg(feature = "std")]
 use std::io::Write;
 
 use static_assertions::*;
 
-use crate::prelude::*;
+use crate::{
+	parse::ParseBitsErrorKind,
+	prelude::*,
+};
 
 #[test]
 fn alloc_impl() {
@@ -53,6 +57,7 @@ fn alloc_impl() {
 		From<BitBox<usize, Lsb0>>,
 		From<Cow<'static, BitSlice<usize, Lsb0>>>,
 		FromIterator<bool>,
+		FromStr,
 		Hash,
 		Index<usize>,
 		Index<Range<usize>>,
@@ -100,3 +105,72 @@ fn format() {
 	);
 	assert!(text.ends_with(" } [0, 1, 0, 0]"), "{}", text);
 }
+
+#[test]
+fn parse() {
+	#[cfg(not(feature = "std"))]
+	use alloc::{
+		format,
+		string::ToString,
+	};
+
+	let bv = (0 .. 43)
+		.map(|_| rand::random::<bool>())
+		.collect::<BitVec<u8, Msb0>>();
+	let debug = format!("{:#?}", bv.as_raw_slice().view_bits::<Msb0>());
+	for text in &[
+		bv.to_string(),
+		format!("{:b}", bv),
+		format!("{:#b}", bv),
+		debug[debug.rfind('}').unwrap() + 1 ..].to_string(),
+	] {
+		let parsed = text.parse::<BitVec<u8, Msb0>>().unwrap();
+		assert_eq!(parsed[.. 43], bv, "{}", text);
+	}
+	let lsb0 = bv.iter().by_vals().collect::<BitVec<u16, Lsb0>>();
+	assert_eq!(
+		format!("{:b}", lsb0).parse::<BitVec<u16, Lsb0>>().unwrap(),
+		lsb0
+	);
+
+	let bv = BitVec::<u8, Lsb0>::from_slice(&[0xA5, 0x3C]);
+	assert_eq!(
+		BitVec::<u8, Lsb0>::from_hex_str(&format!("{:x}", bv)).unwrap(),
+		bv
+	);
+	assert_eq!(
+		BitVec::<u8, Lsb0>::from_hex_str(&format!("{:#X}", bv)).unwrap(),
+		bv
+	);
+	assert_eq!(
+		format!("{:#x}", bv).parse::<BitVec<u8, Lsb0>>().unwrap(),
+		bv
+	);
+	assert_eq!(BitVec::<u8, Lsb0>::from_oct_str("0o7_0").unwrap(), bits![
+		1, 1, 1, 0, 0, 0
+	]);
+	assert_eq!(
+		BitVec::<u8, Lsb0>::from_bin_str("  [ 1_0 , 0b1, ] ").unwrap(),
+		bits![1, 0, 1]
+	);
+	assert_eq!(BitVec::<u8, Lsb0>::from_hex_str("0b1").unwrap().len(), 12);
+	assert!("[]".parse::<BitVec>().unwrap().is_empty());
+
+	for (text, pos, kind) in &[
+		("", 0, ParseBitsErrorKind::Empty),
+		("0x", 0, ParseBitsErrorKind::Empty),
+		("[1, , 0]", 4, ParseBitsErrorKind::Empty),
+		("10201", 2, ParseBitsErrorKind::InvalidDigit('2')),
+		("[0b1, 0o19]", 9, ParseBitsErrorKind::InvalidDigit('9')),
+		("[1 1]", 2, ParseBitsErrorKind::InvalidDigit(' ')),
+		("[1, 0", 5, ParseBitsErrorKind::Unclosed),
+		("[1, 0] 1", 6, ParseBitsErrorKind::Trailing),
+	] {
+		let err = text.parse::<BitVec>().unwrap_err();
+		assert_eq!((err.position(), err.kind()), (*pos, *kind), "{:?}", text);
+	}
+	assert_eq!(
+		BitVec::<u8, Lsb0>::from_bin_str("0x1").unwrap_err().kind(),
+		ParseBitsErrorKind::InvalidDigit('x'),
+	);
+}
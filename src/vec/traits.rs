@@ -25,20 +25,25 @@ use core::{
 		Hasher,
 	},
 	marker::Unpin,
+	str::FromStr,
 };
 
+#[cfg(feature = "allocator")]
+use super::Allocator;
 use super::BitVec;
 use crate::{
 	array::BitArray,
 	boxed::BitBox,
 	order::BitOrder,
+	parse::ParseBitsError,
 	slice::BitSlice,
 	store::BitStore,
 	view::BitViewSized,
 };
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> Borrow<BitSlice<T, O>> for BitVec<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator> Borrow<BitSlice<T, O>>
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -50,7 +55,8 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> BorrowMut<BitSlice<T, O>> for BitVec<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator> BorrowMut<BitSlice<T, O>>
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -62,18 +68,20 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> Clone for BitVec<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator + Clone> Clone
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
 {
 	#[inline]
 	fn clone(&self) -> Self {
-		Self::from_bitslice(self.as_bitslice())
+		self.sibling_from_bitslice(self.as_bitslice())
 	}
 }
 
-impl<T, O> Eq for BitVec<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator> Eq
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -81,7 +89,8 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> Ord for BitVec<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator> Ord
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -93,7 +102,8 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T1, T2, O1, O2> PartialEq<BitVec<T2, O2>> for BitSlice<T1, O1>
+impl<T1, T2, O1, O2, #[cfg(feature = "allocator")] A: Allocator>
+	PartialEq<in_alloc!(BitVec<T2, O2>, A)> for BitSlice<T1, O1>
 where
 	T1: BitStore,
 	T2: BitStore,
@@ -101,13 +111,14 @@ where
 	O2: BitOrder,
 {
 	#[inline]
-	fn eq(&self, other: &BitVec<T2, O2>) -> bool {
+	fn eq(&self, other: &in_alloc!(BitVec<T2, O2>, A)) -> bool {
 		self == other.as_bitslice()
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T1, T2, O1, O2> PartialEq<BitVec<T2, O2>> for &BitSlice<T1, O1>
+impl<T1, T2, O1, O2, #[cfg(feature = "allocator")] A: Allocator>
+	PartialEq<in_alloc!(BitVec<T2, O2>, A)> for &BitSlice<T1, O1>
 where
 	T1: BitStore,
 	T2: BitStore,
@@ -115,13 +126,14 @@ where
 	O2: BitOrder,
 {
 	#[inline]
-	fn eq(&self, other: &BitVec<T2, O2>) -> bool {
+	fn eq(&self, other: &in_alloc!(BitVec<T2, O2>, A)) -> bool {
 		*self == other.as_bitslice()
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T1, T2, O1, O2> PartialEq<BitVec<T2, O2>> for &mut BitSlice<T1, O1>
+impl<T1, T2, O1, O2, #[cfg(feature = "allocator")] A: Allocator>
+	PartialEq<in_alloc!(BitVec<T2, O2>, A)> for &mut BitSlice<T1, O1>
 where
 	T1: BitStore,
 	T2: BitStore,
@@ -129,13 +141,14 @@ where
 	O2: BitOrder,
 {
 	#[inline]
-	fn eq(&self, other: &BitVec<T2, O2>) -> bool {
+	fn eq(&self, other: &in_alloc!(BitVec<T2, O2>, A)) -> bool {
 		**self == other.as_bitslice()
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O, Rhs> PartialEq<Rhs> for BitVec<T, O>
+impl<T, O, Rhs, #[cfg(feature = "allocator")] A: Allocator> PartialEq<Rhs>
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -148,7 +161,8 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T1, T2, O1, O2> PartialOrd<BitVec<T2, O2>> for BitSlice<T1, O1>
+impl<T1, T2, O1, O2, #[cfg(feature = "allocator")] A: Allocator>
+	PartialOrd<in_alloc!(BitVec<T2, O2>, A)> for BitSlice<T1, O1>
 where
 	T1: BitStore,
 	T2: BitStore,
@@ -156,13 +170,17 @@ where
 	O2: BitOrder,
 {
 	#[inline]
-	fn partial_cmp(&self, other: &BitVec<T2, O2>) -> Option<cmp::Ordering> {
+	fn partial_cmp(
+		&self,
+		other: &in_alloc!(BitVec<T2, O2>, A),
+	) -> Option<cmp::Ordering> {
 		self.partial_cmp(other.as_bitslice())
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<'a, T1, T2, O1, O2> PartialOrd<BitVec<T2, O2>> for &'a BitSlice<T1, O1>
+impl<'a, T1, T2, O1, O2, #[cfg(feature = "allocator")] A: Allocator>
+	PartialOrd<in_alloc!(BitVec<T2, O2>, A)> for &'a BitSlice<T1, O1>
 where
 	T1: BitStore,
 	T2: BitStore,
@@ -170,13 +188,17 @@ where
 	O2: BitOrder,
 {
 	#[inline]
-	fn partial_cmp(&self, other: &BitVec<T2, O2>) -> Option<cmp::Ordering> {
+	fn partial_cmp(
+		&self,
+		other: &in_alloc!(BitVec<T2, O2>, A),
+	) -> Option<cmp::Ordering> {
 		self.partial_cmp(other.as_bitslice())
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<'a, T1, T2, O1, O2> PartialOrd<BitVec<T2, O2>> for &'a mut BitSlice<T1, O1>
+impl<'a, T1, T2, O1, O2, #[cfg(feature = "allocator")] A: Allocator>
+	PartialOrd<in_alloc!(BitVec<T2, O2>, A)> for &'a mut BitSlice<T1, O1>
 where
 	T1: BitStore,
 	T2: BitStore,
@@ -184,13 +206,17 @@ where
 	O2: BitOrder,
 {
 	#[inline]
-	fn partial_cmp(&self, other: &BitVec<T2, O2>) -> Option<cmp::Ordering> {
+	fn partial_cmp(
+		&self,
+		other: &in_alloc!(BitVec<T2, O2>, A),
+	) -> Option<cmp::Ordering> {
 		self.partial_cmp(other.as_bitslice())
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O, Rhs> PartialOrd<Rhs> for BitVec<T, O>
+impl<T, O, Rhs, #[cfg(feature = "allocator")] A: Allocator> PartialOrd<Rhs>
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -203,7 +229,8 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> AsRef<BitSlice<T, O>> for BitVec<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator> AsRef<BitSlice<T, O>>
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -215,7 +242,8 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> AsMut<BitSlice<T, O>> for BitVec<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator> AsMut<BitSlice<T, O>>
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -227,7 +255,8 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> AsRef<BitVec<T, O>> for BitVec<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator>
+	AsRef<in_alloc!(BitVec<T, O>, A)> for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -239,7 +268,8 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> AsMut<BitVec<T, O>> for BitVec<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator>
+	AsMut<in_alloc!(BitVec<T, O>, A)> for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -337,25 +367,27 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> Default for BitVec<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator + Default> Default
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
 {
 	#[inline]
 	fn default() -> Self {
-		Self::new()
+		Self::new_default()
 	}
 }
 
-impl<T, O> Debug for BitVec<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator> Debug
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
 {
 	#[inline]
 	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
-		self.as_bitspan().render(fmt, "Vec", &[(
+		self.as_bitspan().render(fmt, "BitVec", &[(
 			"capacity",
 			&self.capacity() as &dyn Debug,
 		)])?;
@@ -374,8 +406,40 @@ easy_fmt! {
 	for BitVec
 }
 
+/** Parses a bit-vector from text produced by its formatters.
+
+Each word in the text selects its radix with a `0b`, `0o`, or `0x` prefix, and
+unprefixed words are binary. See the [`parse`] module for the accepted grammar.
+
+## Examples
+
+```rust
+use bitvec::prelude::*;
+
+let bv = bitvec![u16, Lsb0; 1, 0, 1, 1, 0];
+let text = format!("{:#b}", bv);
+assert_eq!(text.parse::<BitVec<u16, Lsb0>>().unwrap(), bv);
+assert_eq!(bv.to_string().parse::<BitVec<u16, Lsb0>>().unwrap(), bv);
+```
+
+[`parse`]: crate::parse
+**/
+impl<T, O> FromStr for BitVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Err = ParseBitsError;
+
+	#[inline]
+	fn from_str(src: &str) -> Result<Self, Self::Err> {
+		Self::parse_radix(src, None)
+	}
+}
+
 #[cfg(not(tarpaulin_include))]
-impl<T, O> Hash for BitVec<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator> Hash
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
@@ -387,21 +451,24 @@ where
 	}
 }
 
-unsafe impl<T, O> Send for BitVec<T, O>
+unsafe impl<T, O, #[cfg(feature = "allocator")] A: Allocator + Send> Send
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
 {
 }
 
-unsafe impl<T, O> Sync for BitVec<T, O>
+unsafe impl<T, O, #[cfg(feature = "allocator")] A: Allocator + Sync> Sync
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,
 {
 }
 
-impl<T, O> Unpin for BitVec<T, O>
+impl<T, O, #[cfg(feature = "allocator")] A: Allocator + Unpin> Unpin
+	for in_alloc!(BitVec<T, O>, A)
 where
 	T: BitStore,
 	O: BitOrder,